@@ -1,35 +1,34 @@
-use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
-use std::num::ParseIntError;
-use std::result;
-
-#[derive(Debug)]
-pub enum Error {
-    Io(io::Error, String),
-    Parse(ParseIntError, String),
+use std::collections::HashSet;
+
+use aoc_error::{parse_at, Result};
+
+pub fn parse_offsets(input: &str) -> Result<Vec<i32>> {
+    input
+        .lines()
+        .map(|line| parse_at(input, line).map_err(Into::into))
+        .collect()
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::Io(e, s) => write!(f, "{}: {}", s, e),
-            Error::Parse(e, s) => write!(f, "Could not parse `{}': {}", s, e),
-        }
-    }
+/// The frequency after applying every offset once.
+pub fn part1(input: &str) -> Result<i32> {
+    Ok(parse_offsets(input)?.into_iter().sum())
 }
 
-pub type Result<T> = result::Result<T, Error>;
+/// The first frequency reached twice when applying the offsets repeatedly.
+pub fn part2(input: &str) -> Result<i32> {
+    let offsets = parse_offsets(input)?;
 
-pub fn read_offsets() -> Result<Vec<i32>> {
-    let mut f = File::open("input").map_err(|e| Error::Io(e, "Could not open input".into()))?;
+    let mut freq = 0;
+    let mut freq_counts = HashSet::new();
 
-    let mut buf = String::new();
+    for offset in offsets.into_iter().cycle() {
+        freq += offset;
 
-    f.read_to_string(&mut buf)
-        .map_err(|e| Error::Io(e, "Could not read input".into()))?;
+        if !freq_counts.insert(freq) {
+            // HashSet.insert(k) returns false when the k is already present.
+            return Ok(freq);
+        }
+    }
 
-    buf.lines()
-        .map(|line| str::parse(line).map_err(|e| Error::Parse(e, line.into())))
-        .collect()
+    unreachable!()
 }
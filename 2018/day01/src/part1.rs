@@ -1,20 +1,25 @@
-use std::ops::Add;
+use std::fs::read_to_string;
 use std::process::exit;
 
-use day01_2018::{read_offsets, Result};
+use aoc_error::{Error, Result};
+use aoc_output::Output;
+
+use day01_2018::part1;
 
 fn run() -> Result<i32> {
-    let freq = read_offsets()?.into_iter().fold(0, Add::add);
-    Ok(freq)
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part1(&input)
 }
 
 fn main() {
+    let mut output = Output::new(2018, 1);
+
     match run() {
         Err(e) => {
             eprintln!("error: {}", e);
             exit(1);
         }
 
-        Ok(result) => println!("{}", result),
+        Ok(result) => output.part(1, result),
     }
 }
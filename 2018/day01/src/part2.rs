@@ -1,33 +1,25 @@
-use std::collections::HashSet;
+use std::fs::read_to_string;
 use std::process::exit;
 
-use day01_2018::{read_offsets, Result};
+use aoc_error::{Error, Result};
+use aoc_output::Output;
 
-fn run() -> Result<i32> {
-    let offsets = read_offsets()?;
-
-    let mut freq = 0;
-    let mut freq_counts = HashSet::new();
-
-    for offset in offsets.into_iter().cycle() {
-        freq += offset;
-
-        if !freq_counts.insert(freq) {
-            // HashSet.insert(k) returns false when the k is already present.
-            return Ok(freq);
-        }
-    }
+use day01_2018::part2;
 
-    unreachable!()
+fn run() -> Result<i32> {
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part2(&input)
 }
 
 fn main() {
+    let mut output = Output::new(2018, 1);
+
     match run() {
         Err(e) => {
             eprintln!("error: {}", e);
             exit(1);
         }
 
-        Ok(result) => println!("{}", result),
+        Ok(result) => output.part(2, result),
     }
 }
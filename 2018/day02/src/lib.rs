@@ -1,45 +1,121 @@
-use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
-use std::result;
-
-#[derive(Debug)]
-pub enum Error {
-    Io(io::Error, String),
-    NonAscii(char),
-    NonAlpha(char),
-    LengthMisatch(String, String),
-    NoMatch,
-}
+use aoc_error::{Error, ParseError, Result};
+
+pub fn parse_ids(input: &str) -> Result<Vec<&str>> {
+    let mut ids: Vec<&str> = Vec::new();
+
+    for line in input.lines() {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at_span(
+                input,
+                &line[i..],
+                format!("char `{}' is not a lowercase ASCII letter", c),
+            )
+            .into());
+        }
 
-pub type Result<T> = result::Result<T, Error>;
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::Io(e, s) => write!(f, "{}: {}", s, e),
-            Error::NonAscii(c) => write!(f, "char `{}' is not ASCII", c),
-            Error::NonAlpha(c) => write!(f, "char `{}' is not alphabetical", c),
-            Error::LengthMisatch(a, b) => write!(
-                f,
-                "IDs `{}' and `{}' have differing lengths ({} and {})",
-                a,
-                b,
-                a.len(),
-                b.len()
-            ),
-            Error::NoMatch => write!(f, "no match found"),
+        if let Some(first) = ids.first() {
+            if first.len() != line.len() {
+                return Err(ParseError::at_span(
+                    input,
+                    line,
+                    format!(
+                        "ID has length {}, but previous IDs have length {}",
+                        line.len(),
+                        first.len()
+                    ),
+                )
+                .into());
+            }
         }
+
+        ids.push(line);
     }
+
+    Ok(ids)
+}
+
+#[derive(Debug, Default)]
+struct Freq {
+    pub two_of_a_kind: bool,
+    pub three_of_a_kind: bool,
+}
+
+fn count_freq(s: &str) -> Freq {
+    // IDs are validated to only contain a-z.
+    let mut freq_counts = [0; 26];
+
+    for c in s.chars() {
+        let index = c as usize - b'a' as usize;
+
+        freq_counts[index] += 1
+    }
+
+    let freq = freq_counts
+        .into_iter()
+        .fold(Default::default(), |acc, i| match i {
+            2 => Freq {
+                two_of_a_kind: true,
+                ..acc
+            },
+            3 => Freq {
+                three_of_a_kind: true,
+                ..acc
+            },
+            _ => acc,
+        });
+
+    freq
 }
 
-pub fn read_ids() -> Result<Vec<String>> {
-    let mut f =
-        File::open("input").map_err(|e| Error::Io(e, "Could not open input file".into()))?;
-    let mut buf = String::new();
+/// The checksum of the box IDs.
+pub fn part1(input: &str) -> Result<u32> {
+    let mut twos = 0;
+    let mut threes = 0;
 
-    f.read_to_string(&mut buf)
-        .map_err(|e| Error::Io(e, "Could not read input file".into()))?;
+    for id in parse_ids(input)? {
+        let freq = count_freq(id);
+
+        twos += freq.two_of_a_kind as u32;
+        threes += freq.three_of_a_kind as u32;
+    }
+
+    Ok(twos * threes)
+}
+
+/// The letters in common between the two IDs that differ by one letter.
+pub fn part2(input: &str) -> Result<String> {
+    let ids = parse_ids(input)?;
+
+    for i in 0..ids.len() - 1 {
+        let str_i = &ids[i];
+
+        'inner: for j in i + 1..ids.len() {
+            let str_j = &ids[j];
+
+            let mut difference = None;
+
+            for (pos, (a, b)) in str_i.chars().zip(str_j.chars()).enumerate() {
+                if a != b {
+                    if difference.is_some() {
+                        continue 'inner;
+                    }
+
+                    difference = Some(pos);
+                }
+            }
+
+            if let Some(pos) = difference {
+                let target_id = ids[i]
+                    .chars()
+                    .enumerate()
+                    .filter(|(k, _)| *k != pos)
+                    .map(|(_, c)| c)
+                    .collect();
+
+                return Ok(target_id);
+            }
+        }
+    }
 
-    Ok(buf.lines().map(Into::into).collect())
+    Err(Error::Other("no match found".into()))
 }
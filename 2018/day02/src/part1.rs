@@ -1,69 +1,25 @@
+use std::fs::read_to_string;
 use std::process::exit;
 
-use day02_2018::{read_ids, Error, Result};
+use aoc_error::{Error, Result};
+use aoc_output::Output;
 
-#[derive(Debug, Default)]
-struct Freq {
-    pub two_of_a_kind: bool,
-    pub three_of_a_kind: bool,
-}
-
-fn count_freq(s: &str) -> Result<Freq> {
-    // We are only concerned with a-z.
-    let mut freq_counts = [0; 26];
-
-    for c in s.chars() {
-        let c = c.to_ascii_lowercase();
-
-        if !c.is_ascii() {
-            return Err(Error::NonAscii(c));
-        } else if !c.is_ascii_alphabetic() {
-            return Err(Error::NonAlpha(c));
-        }
-
-        let index = c as usize - b'a' as usize;
-
-        freq_counts[index] += 1
-    }
-
-    let freq = freq_counts
-        .into_iter()
-        .fold(Default::default(), |acc, i| match i {
-            2 => Freq {
-                two_of_a_kind: true,
-                ..acc
-            },
-            3 => Freq {
-                three_of_a_kind: true,
-                ..acc
-            },
-            _ => acc,
-        });
-
-    Ok(freq)
-}
+use day02_2018::part1;
 
 fn run() -> Result<u32> {
-    let mut twos = 0;
-    let mut threes = 0;
-
-    for id in read_ids()? {
-        let freq = count_freq(&id)?;
-
-        twos += freq.two_of_a_kind as u32;
-        threes += freq.three_of_a_kind as u32;
-    }
-
-    Ok(twos * threes)
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part1(&input)
 }
 
 fn main() {
+    let mut output = Output::new(2018, 2);
+
     match run() {
         Err(e) => {
             eprintln!("error: {}", e);
             exit(1);
         }
 
-        Ok(result) => println!("{}", result),
+        Ok(result) => output.part(1, result),
     }
 }
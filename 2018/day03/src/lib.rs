@@ -1,49 +1,8 @@
 use std::cmp::max;
-use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
-use std::iter::Peekable;
-use std::str::FromStr;
-
-#[derive(Debug)]
-pub enum Error {
-    Io(io::Error, String),
-    Parse(ParseError),
-}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::Io(e, s) => write!(f, "{}: {}", s, e),
-            Error::Parse(e) => write!(f, "could not parse input: {}", e),
-        }
-    }
-}
+use std::collections::HashSet;
+use std::result;
 
-#[derive(Debug)]
-pub enum ParseError {
-    Expected(char, Option<char>),
-    ExpectedClass(String, Option<char>),
-}
-
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            ParseError::Expected(c, Some(recvd)) => {
-                write!(f, "expected `{}', got `{}' instead", c, recvd)
-            }
-            ParseError::Expected(c, None) => {
-                write!(f, "expected `{}', got end of input instead", c)
-            }
-            ParseError::ExpectedClass(s, Some(recvd)) => {
-                write!(f, "expected {}, got `{}' instead", s, recvd)
-            }
-            ParseError::ExpectedClass(s, None) => {
-                write!(f, "expected {}, got end of input instead", s)
-            }
-        }
-    }
-}
+use aoc_error::{parse_at, Error, ParseError, Result};
 
 #[derive(Debug, Default)]
 pub struct Rect {
@@ -71,89 +30,89 @@ impl Rect {
     }
 }
 
-impl FromStr for Rect {
-    type Err = ParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut chars = s.chars().peekable();
-
-        expect(&mut chars, '#')?;
-
-        parse_number(&mut chars)?;
-
-        expect(&mut chars, ' ')?;
-        expect(&mut chars, '@')?;
-        expect(&mut chars, ' ')?;
-
-        let left = parse_number(&mut chars)?;
-        expect(&mut chars, ',')?;
-        let top = parse_number(&mut chars)?;
+/// A cursor over a line of the input.
+struct Cursor<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
 
-        expect(&mut chars, ':')?;
-        expect(&mut chars, ' ')?;
+impl<'a> Cursor<'a> {
+    fn expect(&mut self, c: char) -> result::Result<(), ParseError> {
+        match self.rest.chars().next() {
+            Some(next) if next == c => {
+                self.rest = &self.rest[c.len_utf8()..];
+                Ok(())
+            }
+            Some(next) => Err(self.error(format!("expected `{}', got `{}' instead", c, next))),
+            None => Err(self.error(format!("expected `{}', got end of line instead", c))),
+        }
+    }
 
-        let width = parse_number(&mut chars)?;
-        expect(&mut chars, 'x')?;
-        let height = parse_number(&mut chars)?;
+    fn number(&mut self) -> result::Result<usize, ParseError> {
+        let len = self
+            .rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len());
 
-        if let Some(c) = chars.next() {
-            return Err(ParseError::ExpectedClass("end of input".into(), Some(c)));
+        if len == 0 {
+            return Err(self.error("expected digit"));
         }
 
-        Ok(Rect {
-            top,
-            left,
-            width,
-            height,
-        })
-    }
-}
+        let (digits, rest) = self.rest.split_at(len);
+        self.rest = rest;
 
-fn expect<I>(chars: &mut Peekable<I>, c: char) -> Result<char, ParseError>
-where
-    I: Iterator<Item = char>,
-{
-    match chars.next() {
-        Some(c) => Ok(c),
-        any => Err(ParseError::Expected(c, any)),
+        parse_at(self.input, digits)
     }
-}
 
-fn parse_number<I>(chars: &mut Peekable<I>) -> Result<usize, ParseError>
-where
-    I: Iterator<Item = char>,
-{
-    let mut acc: usize = 0;
-    let mut consumed = false;
-    loop {
-        if let Some(c) = chars.peek() {
-            if let Some(digit) = c.to_digit(10) {
-                acc = acc * 10 + digit as usize;
-                consumed = true;
-                chars.next();
-            } else if consumed {
-                return Ok(acc);
-            } else {
-                return Err(ParseError::ExpectedClass("digit".into(), Some(*c)));
-            }
-        } else if consumed {
-            return Ok(acc);
+    fn end(&self) -> result::Result<(), ParseError> {
+        if self.rest.is_empty() {
+            Ok(())
         } else {
-            return Err(ParseError::ExpectedClass("digit".into(), None));
+            Err(self.error("expected end of line"))
         }
     }
+
+    fn error<S: Into<String>>(&self, message: S) -> ParseError {
+        ParseError::at_span(self.input, self.rest, message)
+    }
 }
 
-pub fn read_claims() -> Result<Vec<Rect>, Error> {
-    let mut f =
-        File::open("input").map_err(|e| Error::Io(e, "Could not open input file".into()))?;
-    let mut buf = String::new();
+/// Parse a claim of the form `#1 @ 1,3: 4x4` from `line`, a line of `input`.
+fn parse_claim(input: &str, line: &str) -> result::Result<Rect, ParseError> {
+    let mut cursor = Cursor { input, rest: line };
+
+    cursor.expect('#')?;
+    cursor.number()?;
+
+    cursor.expect(' ')?;
+    cursor.expect('@')?;
+    cursor.expect(' ')?;
+
+    let left = cursor.number()?;
+    cursor.expect(',')?;
+    let top = cursor.number()?;
+
+    cursor.expect(':')?;
+    cursor.expect(' ')?;
+
+    let width = cursor.number()?;
+    cursor.expect('x')?;
+    let height = cursor.number()?;
 
-    f.read_to_string(&mut buf)
-        .map_err(|e| Error::Io(e, "Could not read input file".into()))?;
+    cursor.end()?;
 
-    buf.lines()
-        .map(|line| str::parse(line).map_err(Error::Parse))
+    Ok(Rect {
+        top,
+        left,
+        width,
+        height,
+    })
+}
+
+pub fn parse_claims(input: &str) -> Result<Vec<Rect>> {
+    input
+        .lines()
+        .map(|line| parse_claim(input, line).map_err(Into::into))
         .collect()
 }
 
@@ -165,3 +124,137 @@ pub fn compute_min_dimensions(claims: &[Rect]) -> (usize, usize) {
             (max(max_right, right), max(max_bottom, bottom))
         })
 }
+
+/// The number of square inches covered by two or more claims.
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(compute_overlap(parse_claims(input)?))
+}
+
+/// The ID of the only claim that does not overlap any other.
+pub fn part2(input: &str) -> Result<usize> {
+    find_outlier(parse_claims(input)?).ok_or_else(|| Error::Other("no (unique) solution".into()))
+}
+
+fn compute_overlap(claims: Vec<Rect>) -> usize {
+    let (width, height) = compute_min_dimensions(&claims);
+
+    let mut map: Vec<Vec<usize>> = {
+        let mut map = Vec::with_capacity(height);
+
+        for _ in 0..height {
+            map.push(vec![0; width]);
+        }
+
+        map
+    };
+
+    for claim in claims {
+        for y in claim.top..claim.bottom() {
+            for x in claim.left..claim.right() {
+                map[y][x] += 1;
+            }
+        }
+    }
+
+    let count = map
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .filter(|count| *count >= 2)
+                .map(|_| 1)
+                .fold(0, std::ops::Add::add)
+        }).fold(0, std::ops::Add::add);
+
+    count
+}
+
+fn intersects(a: &Rect, b: &Rect) -> bool {
+    a.left < b.right() && a.right() > b.left && a.top < b.bottom() && a.bottom() > b.top
+}
+
+fn find_outlier(claims: Vec<Rect>) -> Option<usize> {
+    let claims_with_ids = || claims.iter().zip(1..);
+    let mut open_ids = (1..=claims.len()).collect::<HashSet<_>>();
+
+    for (a, a_id) in claims_with_ids() {
+        for (b, b_id) in claims_with_ids() {
+            if a_id == b_id {
+                continue;
+            }
+
+            if intersects(&a, &b) {
+                open_ids.remove(&a_id);
+                open_ids.remove(&b_id);
+            }
+        }
+    }
+
+    if open_ids.len() == 1 {
+        open_ids.into_iter().next()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let claims = vec![
+            Rect::new(1, 3, 4, 4),
+            Rect::new(3, 1, 4, 4),
+            Rect::new(5, 5, 2, 2),
+        ];
+
+        assert_eq!(compute_overlap(claims), 4);
+    }
+
+    #[test]
+    fn test_parts() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
+
+        assert_eq!(part1(input).unwrap(), 4);
+        assert_eq!(part2(input).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_generated() {
+        let mut rng = aoc_generate::rng(3);
+
+        for _ in 0..20 {
+            let input = aoc_generate::y2018::claims(&mut rng, 200);
+            let claims = parse_claims(&input).unwrap();
+
+            let mut counts = HashMap::new();
+            for claim in &claims {
+                for y in claim.top..claim.bottom() {
+                    for x in claim.left..claim.right() {
+                        *counts.entry((x, y)).or_insert(0) += 1;
+                    }
+                }
+            }
+
+            let overlap = counts.values().filter(|count| **count >= 2).count();
+            assert_eq!(part1(&input).unwrap(), overlap);
+
+            let alone = (1..)
+                .zip(&claims)
+                .filter(|(_, claim)| {
+                    (claim.top..claim.bottom()).all(|y| {
+                        (claim.left..claim.right()).all(|x| counts[&(x, y)] == 1)
+                    })
+                })
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+
+            match part2(&input) {
+                Ok(id) => assert_eq!(alone, vec![id]),
+                Err(_) => assert_ne!(alone.len(), 1),
+            }
+        }
+    }
+}
@@ -1,31 +1,13 @@
 pub mod state_machine;
 
 use std::cmp::Ordering;
-use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
-use std::str::FromStr;
+use std::collections::HashMap;
 
+use aoc_error::Result;
+use aoc_parse::parse_lines;
 use chrono::naive::NaiveDateTime;
-use combine::stream::state::State;
-use combine::Parser;
 
-pub use self::parser::ParseError;
-
-#[derive(Debug)]
-pub enum Error {
-    Io(io::Error, String),
-    Parse(ParseError, usize),
-}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::Io(e, s) => write!(f, "{}: {}", s, e),
-            Error::Parse(e, n) => write!(f, "parse error on line {}: {}", n, e),
-        }
-    }
-}
+use crate::state_machine::{run_state_machine, NightSummary};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RecordKind {
@@ -52,135 +34,135 @@ impl Ord for Record {
     }
 }
 
-impl FromStr for Record {
-    type Err = parser::ParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parser::record()
-            .easy_parse(State::new(s))
-            .map_err(Into::into)
-            .map(|(output, _)| output)
-    }
+pub fn parse_records(input: &str) -> Result<Vec<Record>> {
+    Ok(parse_lines(parser::record(), input)?)
 }
 
-pub fn read_records() -> Result<Vec<Record>, Error> {
-    let mut f =
-        File::open("input").map_err(|e| Error::Io(e, "Could not open input file".into()))?;
-    let mut buf = String::new();
+/// The ID of the guard who slept the most times the minute they slept most.
+pub fn part1(input: &str) -> Result<u32> {
+    let asleep_freq = asleep_frequencies(parse_records(input)?);
 
-    f.read_to_string(&mut buf)
-        .map_err(|e| Error::Io(e, "Could not read input file".into()))?;
+    let guard_id = asleep_freq
+        .iter()
+        .map(|(id, minutes_asleep)| (id, minutes_asleep.iter().fold(0, std::ops::Add::add)))
+        .max_by(|(_, a_asleep), (_, b_asleep)| Ord::cmp(a_asleep, b_asleep))
+        .map(|(id, _)| id)
+        .expect("No guards were asleep?");
 
-    let records = buf
-        .lines()
+    let minute = asleep_freq[&guard_id]
+        .into_iter()
         .enumerate()
-        .map(|(n, s)| str::parse(s).map_err(|e| Error::Parse(e, n)))
-        .collect::<Result<_, _>>()?;
+        .max_by(|(_, a_freq), (_, b_freq)| Ord::cmp(a_freq, b_freq))
+        .map(|(min, _)| min)
+        .expect("This guard wasn't asleep?");
 
-    Ok(records)
+    Ok(*guard_id * minute as u32)
 }
 
-mod parser {
-    use std::fmt;
+/// The ID of the guard most frequently asleep on the same minute times that minute.
+pub fn part2(input: &str) -> Result<u32> {
+    let asleep_freq = asleep_frequencies(parse_records(input)?);
 
-    use combine::combinator::many1;
-    use combine::easy::Errors;
-    use combine::parser::char::{digit, string};
-    use combine::parser::item::token;
-    use combine::stream::state::{SourcePosition, State};
-    use combine::{choice, easy, eof, Parser, Stream};
+    let (guard_id, minute) = asleep_freq
+        .into_iter()
+        .map(|(id, minutes_asleep)| {
+            (
+                id,
+                minutes_asleep
+                    .into_iter()
+                    .enumerate()
+                    .max_by(|(_, a_freq), (_, b_freq)| Ord::cmp(a_freq, b_freq))
+                    .map(|(minute, freq)| (minute, *freq)),
+            )
+        })
+        .filter_map(|(id, maybe_info)| match maybe_info {
+            Some((minute, freq)) => Some((id, minute, freq)),
+            None => None,
+        })
+        .max_by(|(_, _, a_freq), (_, _, b_freq)| Ord::cmp(a_freq, b_freq))
+        .map(|(guard_id, minute, _)| (guard_id as u32, minute as u32))
+        .expect("No guard was asleep?");
+
+    Ok(guard_id * minute)
+}
 
-    use chrono::naive::{NaiveDate, NaiveDateTime};
+/// How often each guard was asleep during each minute of the midnight hour.
+fn asleep_frequencies(mut records: Vec<Record>) -> HashMap<u32, [u32; 60]> {
+    records.sort_unstable();
 
-    use super::{Record, RecordKind};
+    let mut asleep_freq = HashMap::<u32, [u32; 60]>::new();
 
-    // We are wrapping Errors<..> instead of making a type alias so we can impl
-    // From for it.
-    //
-    // Our input type is `State<&str, _>`, but `Record` does not have a handle
-    // to the underyling string, so `impl FromStr for Record` cannot contain the
-    // lifetime. Therefore, we re-map errors to allocate `String`s instead.
-    #[derive(Debug)]
-    pub struct ParseError(Errors<char, String, SourcePosition>);
-    type UpstreamParseError<'a> = easy::ParseError<State<&'a str, SourcePosition>>;
-
-    impl<'a> From<UpstreamParseError<'a>> for ParseError {
-        fn from(err: UpstreamParseError<'a>) -> Self {
-            ParseError(Errors {
-                errors: err
-                    .errors
-                    .into_iter()
-                    .map(|e| e.map_range(String::from))
-                    .collect(),
-                position: err.position,
-            })
-        }
+    for summary in run_state_machine(records) {
+        process_summary(&mut asleep_freq, summary);
     }
 
-    impl fmt::Display for ParseError {
-        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            fmt::Display::fmt(&self.0, f)
-        }
-    }
+    asleep_freq
+}
 
-    fn decimal_digit<I>() -> impl Parser<Input = I, Output = u32>
-    where
-        I: Stream<Item = char, Error = easy::ParseError<I>>,
-    {
-        digit().map(|d| d.to_digit(10).unwrap())
+fn process_summary(asleep_freq: &mut HashMap<u32, [u32; 60]>, summary: NightSummary) {
+    let minutes_asleep = asleep_freq
+        .entry(summary.guard_id)
+        .or_insert_with(|| [0; 60]);
+
+    for (i, &asleep) in summary.minutes_asleep.0.into_iter().enumerate() {
+        minutes_asleep[i] += asleep as u32;
     }
+}
 
-    pub fn datetime<I>() -> impl Parser<Input = I, Output = NaiveDateTime>
+mod parser {
+    use aoc_parse::unsigned;
+    use chrono::naive::{NaiveDate, NaiveDateTime};
+    use combine::error::StreamError;
+    use combine::parser::char::char;
+    use combine::parser::range::range;
+    use combine::stream::StreamErrorFor;
+    use combine::{choice, ParseError, Parser, RangeStream};
+
+    use super::{Record, RecordKind};
+
+    pub fn datetime<'a, I>() -> impl Parser<I, Output = NaiveDateTime> + 'a
     where
-        I: Stream<Item = char, Error = easy::ParseError<I>>,
+        I: RangeStream<Token = char, Range = &'a str> + 'a,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
-        let double_digit = || (decimal_digit(), decimal_digit()).map(|(a, b)| a * 10 + b);
-        let year = || {
-            (
-                decimal_digit(),
-                decimal_digit(),
-                decimal_digit(),
-                decimal_digit(),
-            )
-                .map(|(a, b, c, d)| a * 1000 + b * 100 + c * 10 + d)
-        };
-
         (
-            year().skip(token('-')),
-            double_digit().skip(token('-')),
-            double_digit().skip(token(' ')),
-            double_digit().skip(token(':')),
-            double_digit(),
+            unsigned().skip(char('-')),
+            unsigned().skip(char('-')),
+            unsigned().skip(char(' ')),
+            unsigned().skip(char(':')),
+            unsigned(),
         )
-            .map(|(year, month, day, hour, minute)| {
-                NaiveDate::from_ymd(year as i32, month, day).and_hms(hour, minute, 0)
+            .and_then(|(year, month, day, hour, minute)| {
+                NaiveDate::from_ymd_opt(year, month, day)
+                    .and_then(|date| date.and_hms_opt(hour, minute, 0))
+                    .ok_or_else(|| StreamErrorFor::<I>::message_static_message("invalid timestamp"))
             })
     }
 
-    pub fn record_kind<I>() -> impl Parser<Input = I, Output = RecordKind>
+    pub fn record_kind<'a, I>() -> impl Parser<I, Output = RecordKind> + 'a
     where
-        I: Stream<Item = char, Error = easy::ParseError<I>>,
+        I: RangeStream<Token = char, Range = &'a str> + 'a,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
         choice((
-            string("Guard #")
-                .with(many1::<Vec<_>, _>(decimal_digit()))
-                .skip(string(" begins shift"))
-                .map(|digits| digits.into_iter().fold(0, |hi, lo| hi * 10 + lo))
-                .map(|n| RecordKind::BeginShift(n)),
-            string("falls asleep").map(|_| RecordKind::FallAsleep),
-            string("wakes up").map(|_| RecordKind::WakeUp),
+            range("Guard #")
+                .with(unsigned())
+                .skip(range(" begins shift"))
+                .map(RecordKind::BeginShift),
+            range("falls asleep").map(|_| RecordKind::FallAsleep),
+            range("wakes up").map(|_| RecordKind::WakeUp),
         ))
     }
 
-    pub fn record<I>() -> impl Parser<Input = I, Output = Record>
+    pub fn record<'a, I>() -> impl Parser<I, Output = Record> + 'a
     where
-        I: Stream<Item = char, Error = easy::ParseError<I>>,
+        I: RangeStream<Token = char, Range = &'a str> + 'a,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
         (
-            token('[').with(datetime()).skip(string("] ")),
+            char('[').with(datetime()).skip(range("] ")),
             record_kind(),
         )
-            .skip(eof())
             .map(|(timestamp, kind)| Record { timestamp, kind })
     }
 }
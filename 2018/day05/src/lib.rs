@@ -1,7 +1,6 @@
-use std::fmt;
-use std::fs::File;
-use std::io::{self, prelude::*};
-use std::result;
+use std::collections::HashSet;
+
+use aoc_error::{ParseError, Result};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Polarity {
@@ -29,48 +28,15 @@ impl Unit {
     }
 }
 
-#[derive(Debug)]
-pub enum Error {
-    Io {
-        cause: io::Error,
-        context: &'static str,
-    },
-    InvalidUnit(char),
-}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::Io { cause, context } => write!(f, "{}: {}", context, cause),
-            Error::InvalidUnit(c) => write!(f, "invalid unit: {}", c),
-        }
-    }
-}
-
-type Result<T> = result::Result<T, Error>;
-
-pub fn read_input() -> Result<Vec<Unit>> {
-    let mut f = File::open("input").map_err(|e| Error::Io {
-        cause: e,
-        context: "could not open input file",
-    })?;
-
-    let buf = {
-        let mut buf = String::new();
-        f.read_to_string(&mut buf).map_err(|e| Error::Io {
-            cause: e,
-            context: "could not read input file",
-        })?;
+pub fn parse_polymer(input: &str) -> Result<Vec<Unit>> {
+    let mut units = Vec::with_capacity(input.len());
 
-        buf
-    };
-
-    let mut units = Vec::with_capacity(buf.len() - 1);
-
-    for c in buf.chars() {
+    for (i, c) in input.char_indices() {
         match c {
             '\n' => continue,
-            c if !c.is_ascii() => return Err(Error::InvalidUnit(c)),
+            c if !c.is_ascii_alphabetic() => {
+                return Err(ParseError::at(input, i, format!("invalid unit: {}", c)).into())
+            }
             c => units.push(Unit::new(c)),
         }
     }
@@ -98,3 +64,33 @@ pub fn react_polymer(units: &[Unit]) -> Vec<Unit> {
 
     result
 }
+
+/// The number of units left after the polymer fully reacts.
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(react_polymer(&parse_polymer(input)?).len())
+}
+
+/// The length of the shortest polymer after removing one type of unit.
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(minimize_polymer(&parse_polymer(input)?))
+}
+
+fn minimize_polymer(polymer: &[Unit]) -> usize {
+    let units_seen = polymer
+        .iter()
+        .map(|unit| unit.value)
+        .collect::<HashSet<_>>();
+
+    units_seen
+        .into_iter()
+        .map(|value| {
+            polymer
+                .iter()
+                .filter(|unit| unit.value != value)
+                .map(|&unit| unit)
+                .collect::<Vec<_>>()
+        })
+        .map(|polymer| react_polymer(&polymer).len())
+        .min()
+        .expect("No polymer present?")
+}
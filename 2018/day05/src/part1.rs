@@ -1,14 +1,25 @@
+use std::fs::read_to_string;
 use std::process::exit;
 
-use day05::{react_polymer, read_input};
+use aoc_error::{Error, Result};
+use aoc_output::Output;
+
+use day05::part1;
+
+fn run() -> Result<usize> {
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part1(&input)
+}
 
 fn main() {
-    match read_input().map(|polymer| react_polymer(&polymer)) {
+    let mut output = Output::new(2018, 5);
+
+    match run() {
         Err(e) => {
             eprintln!("error: {}", e);
             exit(1);
         }
 
-        Ok(result) => println!("{}", result.len()),
+        Ok(result) => output.part(1, result),
     }
 }
@@ -1,35 +1,25 @@
-use std::collections::HashSet;
+use std::fs::read_to_string;
 use std::process::exit;
 
-use day05::{react_polymer, read_input, Unit};
+use aoc_error::{Error, Result};
+use aoc_output::Output;
+
+use day05::part2;
+
+fn run() -> Result<usize> {
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part2(&input)
+}
 
 fn main() {
-    match read_input().map(|polymer| minimize_polymer(&polymer)) {
+    let mut output = Output::new(2018, 5);
+
+    match run() {
         Err(e) => {
             eprintln!("error: {}", e);
             exit(1);
         }
 
-        Ok(result) => println!("{}", result),
+        Ok(result) => output.part(2, result),
     }
 }
-
-fn minimize_polymer(polymer: &[Unit]) -> usize {
-    let units_seen = polymer
-        .iter()
-        .map(|unit| unit.value)
-        .collect::<HashSet<_>>();
-
-    units_seen
-        .into_iter()
-        .map(|value| {
-            polymer
-                .iter()
-                .filter(|unit| unit.value != value)
-                .map(|&unit| unit)
-                .collect::<Vec<_>>()
-        })
-        .map(|polymer| react_polymer(&polymer).len())
-        .min()
-        .expect("No polymer present?")
-}
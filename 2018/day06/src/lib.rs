@@ -1,32 +1,10 @@
-use std::fmt;
-use std::fs::File;
-use std::io::{self, prelude::*};
-use std::result;
-
-use combine::stream::state::State;
-use combine::Parser;
-
-use self::parser::{points, ParseError};
-
-#[derive(Debug)]
-pub enum Error {
-    Io {
-        cause: io::Error,
-        context: &'static str,
-    },
-    Parse(ParseError),
-}
+use std::cmp::max;
+use std::collections::HashMap;
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::Io { cause, context } => write!(f, "{}: {}", context, cause),
-            Error::Parse(e) => write!(f, "{}", e.0),
-        }
-    }
-}
+use aoc_error::Result;
+use aoc_parse::parse_lines;
 
-pub type Result<T> = result::Result<T, Error>;
+use self::parser::point;
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct Point {
@@ -44,78 +22,228 @@ impl Point {
     }
 }
 
-pub fn read_coords() -> Result<Vec<Point>> {
-    let mut f = File::open("input").map_err(|e| Error::Io {
-        cause: e,
-        context: "could not open input file",
-    })?;
-
-    let buf = {
-        let mut buf = String::new();
-        f.read_to_string(&mut buf).map_err(|e| Error::Io {
-            cause: e,
-            context: "could not read input file",
-        })?;
-        buf
-    };
+pub fn parse_coords(input: &str) -> Result<Vec<Point>> {
+    Ok(parse_lines(point(), input)?)
+}
 
-    let result = points()
-        .easy_parse(State::new(&*buf))
-        .map(|(output, _)| output)
-        .map_err(|e| Error::Parse(e.into()));
+/// The size of the largest finite area closest to a single coordinate.
+pub fn part1(input: &str) -> Result<u32> {
+    Ok(find_area(parse_coords(input)?))
+}
 
-    result
+/// The size of the region whose total distance to every coordinate is at most
+/// `MAX_DISTANCE`.
+pub fn part2(input: &str) -> Result<u32> {
+    Ok(find_region_size(parse_coords(input)?))
 }
 
-mod parser {
-    use combine::easy::{self, Errors};
-    use combine::parser::char::{digit, string};
-    use combine::parser::item::token;
-    use combine::parser::repeat::many1;
-    use combine::stream::state::{SourcePosition, State};
-    use combine::{eof, Parser, Stream};
+fn find_area(points: Vec<Point>) -> u32 {
+    let (width, height) = points
+        .iter()
+        .map(|Point { x, y }| (*x as usize, *y as usize))
+        .fold((0, 0), |(max_w, max_h), (x, y)| {
+            (max(max_w, x + 1), max(max_h, y + 1))
+        });
 
-    use super::Point;
+    let mut grid = vec![vec![None; width]; height];
 
-    // We are wrapping Errors<..> instead of making a type alias so we can impl
-    // From for it.
-    //
-    // Our input type is `State<&str, _>`, but `Record` does not have a handle
-    // to the underyling string, so `impl FromStr for Record` cannot contain the
-    // lifetime. Therefore, we re-map errors to allocate `String`s instead.
-    #[derive(Debug)]
-    pub struct ParseError(pub Errors<char, String, SourcePosition>);
-    type UpstreamParseError<'a> = easy::ParseError<State<&'a str, SourcePosition>>;
-
-    impl<'a> From<UpstreamParseError<'a>> for ParseError {
-        fn from(err: UpstreamParseError<'a>) -> Self {
-            ParseError(Errors {
-                errors: err
-                    .errors
-                    .into_iter()
-                    .map(|e| e.map_range(String::from))
-                    .collect(),
-                position: err.position,
-            })
+    for (i, point) in points.iter().enumerate() {
+        grid[point.y as usize][point.x as usize] = Some(i);
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            if grid[y][x].is_none() {
+                grid[y][x] = nearest_to(&Point::new(x as i32, y as i32), &points);
+            }
         }
     }
 
-    fn point<I>() -> impl Parser<Input = I, Output = Point>
-    where
-        I: Stream<Item = char, Error = easy::ParseError<I>>,
-    {
-        let number = || {
-            many1::<Vec<u32>, _>(digit().map(|c| c.to_digit(10).unwrap()))
-                .map(|digits| digits.into_iter().fold(0, |acc, i| acc * 10 + i))
-        };
+    let mut finite_areas = (0..points.len()).map(|i| (i, 0)).collect::<HashMap<_, _>>();
+
+    for x in 0..width {
+        if let Some(idx) = grid[0][x] {
+            finite_areas.remove(&idx);
+        }
+
+        if let Some(idx) = grid[height - 1][x] {
+            finite_areas.remove(&idx);
+        }
+    }
+
+    for y in 1..height - 1 {
+        if let Some(idx) = grid[y][0] {
+            finite_areas.remove(&idx);
+        }
+
+        if let Some(idx) = grid[y][width - 1] {
+            finite_areas.remove(&idx);
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(idx) = grid[y][x] {
+                finite_areas.entry(idx).and_modify(|area| *area += 1);
+            }
+        }
+    }
+
+    finite_areas
+        .into_iter()
+        .map(|(_, area)| area)
+        .max()
+        .expect("No areas?")
+}
+
+fn nearest_to(p: &Point, points: &[Point]) -> Option<usize> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, p2)| (p.distance_to(p2), i))
+        .fold(
+            HashMap::<u32, Option<usize>>::new(),
+            |mut distances, (dist, i)| {
+                distances
+                    .entry(dist)
+                    .and_modify(|index| *index = None)
+                    .or_insert(Some(i));
+
+                distances
+            },
+        )
+        .into_iter()
+        .min_by_key(|(dist, _)| *dist)
+        .map(|(_, i)| i)
+        .expect("No points?")
+}
+
+const MAX_DISTANCE: u32 = 9999;
+
+fn find_region_size(points: Vec<Point>) -> u32 {
+    let centre = {
+        let (sum_x, sum_y) = points
+            .iter()
+            .fold((0, 0), |(sum_x, sum_y), p| (sum_x + p.x, sum_y + p.y));
+        let n = points.len() as i32;
+
+        Point::new(sum_x / n, sum_y / n)
+    };
 
-        (number().skip(string(", ")), number()).map(|(x, y)| Point::new(x as i32, y as i32))
+    if sum_of_distances(&centre, &points) > MAX_DISTANCE {
+        return 0;
     }
 
-    pub fn points<I>() -> impl Parser<Input = I, Output = Vec<Point>>
+    let mut area = 1;
+    for offset in 1.. {
+        let mut delta_area = 0;
+
+        for x in centre.x - offset..=centre.x + offset {
+            if sum_of_distances(&Point::new(x, centre.y - offset), &points) <= MAX_DISTANCE {
+                delta_area += 1;
+            }
+
+            if sum_of_distances(&Point::new(x, centre.y + offset), &points) <= MAX_DISTANCE {
+                delta_area += 1;
+            }
+        }
+
+        for y in centre.y - offset + 1..=centre.y + offset - 1 {
+            if sum_of_distances(&Point::new(centre.x - offset, y), &points) <= MAX_DISTANCE {
+                delta_area += 1;
+            }
+
+            if sum_of_distances(&Point::new(centre.x + offset, y), &points) <= MAX_DISTANCE {
+                delta_area += 1;
+            }
+        }
+
+        if delta_area == 0 {
+            break;
+        }
+
+        area += delta_area;
+    }
+
+    area
+}
+
+fn sum_of_distances(p: &Point, points: &[Point]) -> u32 {
+    points.iter().map(|p2| p.distance_to(p2)).sum()
+}
+
+mod parser {
+    use aoc_parse::coordinates;
+    use combine::{ParseError, Parser, RangeStream};
+
+    use super::Point;
+
+    pub fn point<'a, I>() -> impl Parser<I, Output = Point> + 'a
     where
-        I: Stream<Item = char, Error = easy::ParseError<I>>,
+        I: RangeStream<Token = char, Range = &'a str> + 'a,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
-        many1::<Vec<_>, _>(point().skip(token('\n'))).skip(eof())
+        coordinates().map(|(x, y)| Point::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_input(points: &[Point]) -> String {
+        points
+            .iter()
+            .map(|p| format!("{}, {}\n", p.x, p.y))
+            .collect()
+    }
+
+    #[test]
+    fn test_generated_region() {
+        let mut rng = aoc_generate::rng(6);
+
+        for _ in 0..3 {
+            let input = aoc_generate::y2018::coordinates(&mut rng, 20);
+            let points = parse_coords(&input).unwrap();
+
+            let margin = (MAX_DISTANCE / points.len() as u32) as i32 + 1;
+            let min_x = points.iter().map(|p| p.x).min().unwrap() - margin;
+            let max_x = points.iter().map(|p| p.x).max().unwrap() + margin;
+            let min_y = points.iter().map(|p| p.y).min().unwrap() - margin;
+            let max_y = points.iter().map(|p| p.y).max().unwrap() + margin;
+
+            // The sum of distances is the sum of distances along each axis.
+            let along_x = (min_x..=max_x)
+                .map(|x| points.iter().map(|p| (p.x - x).unsigned_abs()).sum::<u32>())
+                .collect::<Vec<_>>();
+            let along_y = (min_y..=max_y)
+                .map(|y| points.iter().map(|p| (p.y - y).unsigned_abs()).sum::<u32>())
+                .collect::<Vec<_>>();
+
+            let region = along_y
+                .iter()
+                .map(|dy| along_x.iter().filter(|dx| *dx + dy <= MAX_DISTANCE).count() as u32)
+                .sum::<u32>();
+
+            assert_eq!(part2(&input).unwrap(), region);
+        }
+    }
+
+    #[test]
+    fn test_generated_moved() {
+        let mut rng = aoc_generate::rng(6);
+
+        for _ in 0..3 {
+            let input = aoc_generate::y2018::coordinates(&mut rng, 10);
+            let points = parse_coords(&input).unwrap();
+
+            // Moving every coordinate by the same amount doesn't change the areas.
+            let moved = points
+                .iter()
+                .map(|p| Point::new(p.x + 17, p.y + 5))
+                .collect::<Vec<_>>();
+
+            assert_eq!(part1(&to_input(&moved)).unwrap(), part1(&input).unwrap());
+        }
     }
 }
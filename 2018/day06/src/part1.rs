@@ -1,97 +1,25 @@
-use std::cmp::max;
-use std::collections::HashMap;
+use std::fs::read_to_string;
 use std::process::exit;
 
-use day06::{read_coords, Point};
+use aoc_error::{Error, Result};
+use aoc_output::Output;
 
-fn main() {
-    match read_coords().map(find_area) {
-        Err(e) => {
-            eprintln!("error: {}", e);
-            exit(1);
-        }
+use day06::part1;
 
-        Ok(area) => println!("{}", area),
-    }
+fn run() -> Result<u32> {
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part1(&input)
 }
 
-fn find_area(points: Vec<Point>) -> u32 {
-    let (width, height) = points
-        .iter()
-        .map(|Point { x, y }| (*x as usize, *y as usize))
-        .fold((0, 0), |(max_w, max_h), (x, y)| {
-            (max(max_w, x + 1), max(max_h, y + 1))
-        });
-
-    let mut grid = vec![vec![None; width]; height];
-
-    for (i, point) in points.iter().enumerate() {
-        grid[point.y as usize][point.x as usize] = Some(i);
-    }
-
-    for y in 0..height {
-        for x in 0..width {
-            if grid[y][x].is_none() {
-                grid[y][x] = nearest_to(&Point::new(x as i32, y as i32), &points);
-            }
-        }
-    }
-
-    let mut finite_areas = (0..points.len()).map(|i| (i, 0)).collect::<HashMap<_, _>>();
-
-    for x in 0..width {
-        if let Some(idx) = grid[0][x] {
-            finite_areas.remove(&idx);
-        }
-
-        if let Some(idx) = grid[height - 1][x] {
-            finite_areas.remove(&idx);
-        }
-    }
-
-    for y in 1..height - 1 {
-        if let Some(idx) = grid[y][0] {
-            finite_areas.remove(&idx);
-        }
+fn main() {
+    let mut output = Output::new(2018, 6);
 
-        if let Some(idx) = grid[y][width - 1] {
-            finite_areas.remove(&idx);
+    match run() {
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
         }
-    }
 
-    for y in 0..height {
-        for x in 0..width {
-            if let Some(idx) = grid[y][x] {
-                finite_areas.entry(idx).and_modify(|area| *area += 1);
-            }
-        }
+        Ok(result) => output.part(1, result),
     }
-
-    finite_areas
-        .into_iter()
-        .map(|(_, area)| area)
-        .max()
-        .expect("No areas?")
-}
-
-fn nearest_to(p: &Point, points: &[Point]) -> Option<usize> {
-    points
-        .iter()
-        .enumerate()
-        .map(|(i, p2)| (p.distance_to(p2), i))
-        .fold(
-            HashMap::<u32, Option<usize>>::new(),
-            |mut distances, (dist, i)| {
-                distances
-                    .entry(dist)
-                    .and_modify(|index| *index = None)
-                    .or_insert(Some(i));
-
-                distances
-            },
-        )
-        .into_iter()
-        .min_by_key(|(dist, _)| *dist)
-        .map(|(_, i)| i)
-        .expect("No points?")
 }
@@ -1,34 +1,6 @@
-use std::collections::{HashMap, HashSet};
-use std::fmt;
-use std::fs::File;
-use std::io::{self, prelude::*};
-use std::ops::Add;
-use std::result;
-
-use combine::stream::state::State;
-use combine::Parser;
-
-pub use self::parser::ParseError;
-
-#[derive(Debug)]
-pub enum Error {
-    Io {
-        cause: io::Error,
-        context: &'static str,
-    },
-    Parse(ParseError),
-}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::Io { cause, context } => write!(f, "{}: {}", context, cause),
-            Error::Parse(e) => write!(f, "{}", e.0),
-        }
-    }
-}
-
-pub type Result<T> = result::Result<T, Error>;
+use aoc_digraph::{Digraph, Schedule};
+use aoc_error::{Error, Result};
+use aoc_parse::parse_lines;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct Vertex(pub char);
@@ -39,126 +11,138 @@ pub struct Edge {
     pub to: Vertex,
 }
 
-#[derive(Debug)]
-pub struct Graph(pub HashMap<Vertex, HashSet<Vertex>>);
+pub type Graph = Digraph<Vertex>;
 
-impl Graph {
-    pub fn from_edges(edges: Vec<Edge>) -> Self {
-        let g = edges
-            .into_iter()
-            .fold(HashMap::<Vertex, HashSet<Vertex>>::new(), |mut g, e| {
-                g.entry(e.to).or_insert_with(|| HashSet::new());
-                g.entry(e.from)
-                    .or_insert_with(|| HashSet::new())
-                    .insert(e.to);
+pub fn graph_from_edges(edges: Vec<Edge>) -> Graph {
+    edges.into_iter().map(|e| (e.from, e.to)).collect()
+}
 
-                g
-            });
+pub fn parse_edges(input: &str) -> Result<Vec<Edge>> {
+    Ok(parse_lines(parser::edge(), input)?)
+}
 
-        Graph(g)
-    }
+/// The order in which the steps are completed by a single worker.
+pub fn part1(input: &str) -> Result<String> {
+    let order = graph_from_edges(parse_edges(input)?)
+        .toposort()
+        .ok_or_else(|| Error::Other("the steps have a cyclic dependency".into()))?;
+    Ok(order.into_iter().map(|v| v.0).collect())
+}
 
-    pub fn incoming<'a>(&'a self, v: Vertex) -> impl Iterator<Item = Vertex> + 'a {
-        self.0.iter().filter_map(move |(from, to_set)| {
-            if to_set.contains(&v) {
-                Some(*from)
-            } else {
-                None
-            }
-        })
-    }
+/// How long five workers take to complete every step.
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(solve_tasks(&graph_from_edges(parse_edges(input)?), 5, 60))
+}
 
-    pub fn roots(&self) -> Vec<Vertex> {
-        self.0
-            .iter()
-            .map(|(v, _)| *v)
-            .filter(|v| self.incoming(*v).map(|_| 1).fold(0, Add::add) == 0)
-            .collect::<Vec<Vertex>>()
+#[derive(Copy, Clone, Debug)]
+struct Task {
+    vertex: Vertex,
+    finish_at: usize,
+}
+
+impl Task {
+    fn new(vertex: Vertex, now: usize, penalty: usize) -> Task {
+        Task {
+            vertex: vertex,
+            finish_at: now + penalty + (vertex.0 as usize) - (b'A' as usize) + 1,
+        }
     }
+}
 
-    pub fn remove(&mut self, v: &Vertex) -> Vec<Vertex> {
-        assert!(self.0.contains_key(v));
+fn solve_tasks(g: &Graph, worker_count: usize, penalty: usize) -> usize {
+    let mut workers: Vec<Option<Task>> = vec![None; worker_count];
 
-        let outgoing = self.0.remove(v).unwrap();
+    let mut schedule = Schedule::new(g);
 
-        outgoing
-            .into_iter()
-            .filter(|&target| self.incoming(target).map(|_| 1).fold(0, Add::add) == 0)
-            .collect()
+    for time in 0.. {
+        for worker in &mut workers {
+            if let Some(task) = *worker {
+                if task.finish_at == time {
+                    *worker = None;
+
+                    schedule.complete(&task.vertex);
+                }
+            }
+        }
+
+        'assign: for worker in &mut workers {
+            if worker.is_none() {
+                if let Some(v) = schedule.start() {
+                    *worker = Some(Task::new(v, time, penalty));
+                } else {
+                    break 'assign;
+                }
+            }
+        }
+
+        if schedule.is_done() && workers.iter().all(Option::is_none) {
+            return time;
+        }
     }
-}
 
-pub fn read_edges() -> Result<Vec<Edge>> {
-    let mut f = File::open("input").map_err(|e| Error::Io {
-        cause: e,
-        context: "could not open input file",
-    })?;
-    let buf = {
-        let mut buf = String::new();
-        f.read_to_string(&mut buf).map_err(|e| Error::Io {
-            cause: e,
-            context: "could not read input file",
-        })?;
-
-        buf
-    };
-
-    buf.lines()
-        .map(|line| {
-            parser::edge()
-                .easy_parse(State::new(&*line))
-                .map_err(|e| Error::Parse(e.into()))
-                .map(|(edge, _)| edge)
-        })
-        .collect::<Result<_>>()
+    unreachable!()
 }
 
 mod parser {
-    use combine::easy::{self, Errors};
-    use combine::parser::char::string;
-    use combine::parser::item::satisfy;
-    use combine::stream::state::{SourcePosition, State};
-    use combine::{eof, Parser, Stream};
+    use combine::parser::char::{letter, string};
+    use combine::{ParseError, Parser, Stream};
 
     use super::{Edge, Vertex};
 
-    // We are wrapping Errors<..> instead of making a type alias so we can impl
-    // From for it.
-    //
-    // Our input type is `State<&str, _>`, but `Record` does not have a handle
-    // to the underyling string, so `impl FromStr for Record` cannot contain the
-    // lifetime. Therefore, we re-map errors to allocate `String`s instead.
-    #[derive(Debug)]
-    pub struct ParseError(pub Errors<char, String, SourcePosition>);
-    type UpstreamParseError<'a> = easy::ParseError<State<&'a str, SourcePosition>>;
-
-    impl<'a> From<UpstreamParseError<'a>> for ParseError {
-        fn from(err: UpstreamParseError<'a>) -> Self {
-            ParseError(Errors {
-                errors: err
-                    .errors
-                    .into_iter()
-                    .map(|e| e.map_range(String::from))
-                    .collect(),
-                position: err.position,
-            })
-        }
-    }
-
-    pub fn edge<I>() -> impl Parser<Input = I, Output = Edge>
+    pub fn edge<I>() -> impl Parser<I, Output = Edge>
     where
-        I: Stream<Item = char, Error = easy::ParseError<I>>,
+        I: Stream<Token = char>,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
         (
-            string("Step ")
-                .with(satisfy(char::is_alphabetic))
-                .map(Vertex),
+            string("Step ").with(letter()).map(Vertex),
             string(" must be finished before step ")
-                .with(satisfy(char::is_alphabetic))
+                .with(letter())
                 .map(Vertex),
         )
             .skip(string(" can begin."))
-            .skip(eof())
             .map(|(from, to)| Edge { from, to })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let g = graph_from_edges(vec![
+            Edge {
+                from: Vertex('C'),
+                to: Vertex('A'),
+            },
+            Edge {
+                from: Vertex('C'),
+                to: Vertex('F'),
+            },
+            Edge {
+                from: Vertex('A'),
+                to: Vertex('B'),
+            },
+            Edge {
+                from: Vertex('A'),
+                to: Vertex('D'),
+            },
+            Edge {
+                from: Vertex('B'),
+                to: Vertex('E'),
+            },
+            Edge {
+                from: Vertex('D'),
+                to: Vertex('E'),
+            },
+            Edge {
+                from: Vertex('F'),
+                to: Vertex('E'),
+            },
+        ]);
+
+        assert_eq!(g.toposort(), Some("CABDFE".chars().map(Vertex).collect()));
+        assert_eq!(solve_tasks(&g, 2, 0), 15);
+    }
+}
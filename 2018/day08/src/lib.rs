@@ -1,28 +1,4 @@
-use std::fmt;
-use std::fs::File;
-use std::io::{self, prelude::*};
-use std::num::ParseIntError;
-use std::result;
-
-#[derive(Debug)]
-pub enum Error {
-    Io {
-        cause: io::Error,
-        context: &'static str,
-    },
-    Parse(ParseIntError),
-}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::Io { cause, context } => write!(f, "{}: {}", context, cause),
-            Error::Parse(e) => fmt::Display::fmt(&e, f),
-        }
-    }
-}
-
-pub type Result<T> = result::Result<T, Error>;
+use aoc_error::{parse_at, ParseError, Result};
 
 #[derive(Debug)]
 pub struct Node {
@@ -30,52 +6,88 @@ pub struct Node {
     pub meta: Vec<u32>,
 }
 
-pub fn read_tree() -> Result<Node> {
-    let mut f = File::open("input").map_err(|e| Error::Io {
-        cause: e,
-        context: "could not open input file",
-    })?;
-
-    let buf = {
-        let mut buf = String::new();
-        f.read_to_string(&mut buf).map_err(|e| Error::Io {
-            cause: e,
-            context: "could not read input file",
-        })?;
-
-        buf
-    };
-
-    let data = buf
-        .trim_right()
-        .split(" ")
-        .map(|s| str::parse::<u32>(s).map_err(Error::Parse))
+pub fn parse_tree(input: &str) -> Result<Node> {
+    let tokens = input.split_whitespace().collect::<Vec<_>>();
+
+    let data = tokens
+        .iter()
+        .map(|s| parse_at::<u32>(input, s).map_err(Into::into))
         .collect::<Result<Vec<_>>>()?;
 
-    let (data, node) = parse_node(&data);
+    let (rest, node) = parse_node(&data)
+        .ok_or_else(|| ParseError::at(input, input.trim_end().len(), "unexpected end of input"))?;
 
-    assert_eq!(data.len(), 0);
+    if !rest.is_empty() {
+        let trailing = tokens[tokens.len() - rest.len()];
+        return Err(ParseError::at_span(input, trailing, "unexpected data after tree").into());
+    }
 
     Ok(node)
 }
 
-fn parse_node<'a>(data: &'a [u32]) -> (&'a [u32], Node) {
-    let num_children = data[0] as usize;
-    let num_meta = data[1] as usize;
+/// The sum of the metadata of every node.
+pub fn part1(input: &str) -> Result<u32> {
+    Ok(sum_meta(parse_tree(input)?))
+}
+
+/// The value of the root node.
+pub fn part2(input: &str) -> Result<u32> {
+    Ok(node_value(&parse_tree(input)?))
+}
+
+fn parse_node(data: &[u32]) -> Option<(&[u32], Node)> {
+    let num_children = *data.first()? as usize;
+    let num_meta = *data.get(1)? as usize;
 
     let mut data = &data[2..];
 
     let mut children = Vec::with_capacity(num_children);
     for _ in 0..num_children {
-        let (rest, node) = parse_node(data);
+        let (rest, node) = parse_node(data)?;
 
         data = rest;
         children.push(node);
     }
 
-    let meta = data[..num_meta].iter().map(|&v| v).collect::<Vec<_>>();
+    let meta = data.get(..num_meta)?.to_vec();
 
     data = &data[num_meta..];
 
-    (data, Node { children, meta })
+    Some((data, Node { children, meta }))
+}
+
+fn sum_meta(node: Node) -> u32 {
+    let mut stack = vec![node];
+    let mut sum = 0;
+
+    while let Some(n) = stack.pop() {
+        for meta in n.meta {
+            sum += meta;
+        }
+
+        for child in n.children {
+            stack.push(child);
+        }
+    }
+
+    sum
+}
+
+fn node_value(node: &Node) -> u32 {
+    if node.children.len() == 0 { 
+        node.meta.iter().sum::<u32>()
+    } else {
+        let mut sum = 0;
+
+        for meta in &node.meta {
+            let meta = *meta as usize;
+            if meta == 0 || meta > node.children.len() {
+                continue
+            }
+
+            sum += node_value(&node.children[meta - 1]);
+        }
+
+        sum
+    }
 }
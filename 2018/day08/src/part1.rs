@@ -1,31 +1,25 @@
+use std::fs::read_to_string;
 use std::process::exit;
 
-use day08::{read_tree, Node};
+use aoc_error::{Error, Result};
+use aoc_output::Output;
 
-fn main() {
-    match read_tree().map(sum_meta) {
-        Err(e) => {
-            eprintln!("error: {}", e);
-            exit(1)
-        }
+use day08::part1;
 
-        Ok(sum) => println!("{}", sum),
-    }
+fn run() -> Result<u32> {
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part1(&input)
 }
 
-fn sum_meta(node: Node) -> u32 {
-    let mut stack = vec![node];
-    let mut sum = 0;
+fn main() {
+    let mut output = Output::new(2018, 8);
 
-    while let Some(n) = stack.pop() {
-        for meta in n.meta {
-            sum += meta;
+    match run() {
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
         }
 
-        for child in n.children {
-            stack.push(child);
-        }
+        Ok(result) => output.part(1, result),
     }
-
-    sum
 }
@@ -1,33 +1,25 @@
+use std::fs::read_to_string;
 use std::process::exit;
 
-use day08::{read_tree, Node};
+use aoc_error::{Error, Result};
+use aoc_output::Output;
 
-fn main() {
-    match read_tree().map(|n| sum_meta(&n)) {
-        Err(e) => {
-            eprintln!("error: {}", e);
-            exit(1)
-        }
+use day08::part2;
 
-        Ok(sum) => println!("{}", sum),
-    }
+fn run() -> Result<u32> {
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part2(&input)
 }
 
-fn sum_meta(node: &Node) -> u32 {
-    if node.children.len() == 0 { 
-        node.meta.iter().sum::<u32>()
-    } else {
-        let mut sum = 0;
-
-        for meta in &node.meta {
-            let meta = *meta as usize;
-            if meta == 0 || meta > node.children.len() {
-                continue
-            }
+fn main() {
+    let mut output = Output::new(2018, 8);
 
-            sum += sum_meta(&node.children[meta - 1]);
+    match run() {
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
         }
 
-        sum
+        Ok(result) => output.part(2, result),
     }
 }
@@ -0,0 +1,93 @@
+use aoc_error::{Error, Result};
+use aoc_parse::{parse_all, unsigned};
+use aoc_ring::Ring;
+use combine::parser::range::range;
+use combine::Parser;
+
+/// Parse a game of the form `10 players; last marble is worth 1618 points`.
+pub fn parse_game(input: &str) -> Result<(usize, u32)> {
+    let game = (
+        unsigned().skip(range(" players; last marble is worth ")),
+        unsigned().skip(range(" points")),
+    );
+
+    let (player_count, highest_marble) = parse_all(game, input)?;
+    check_game(player_count, highest_marble)?;
+
+    Ok((player_count, highest_marble))
+}
+
+/// The highest marble that fits in the circle, whose capacity must be below
+/// `u32::MAX`.
+pub const MAX_MARBLE: u32 = u32::MAX - 2;
+
+/// Check that a game can be played with `run_game`.
+pub fn check_game(player_count: usize, highest_marble: u32) -> Result<()> {
+    if player_count == 0 {
+        return Err(Error::Other("there must be at least one player".into()));
+    }
+
+    if highest_marble > MAX_MARBLE {
+        return Err(Error::Other(format!(
+            "the last marble can be worth at most {} points",
+            MAX_MARBLE
+        )));
+    }
+
+    Ok(())
+}
+
+/// The winning score.
+pub fn part1(input: &str) -> Result<u64> {
+    let (player_count, highest_marble) = parse_game(input)?;
+    Ok(run_game(player_count, highest_marble))
+}
+
+/// The winning score of a game in which the last marble is `highest_marble`.
+///
+/// Panics if the game does not pass `check_game`.
+pub fn run_game(player_count: usize, highest_marble: u32) -> u64 {
+    let mut circle = Ring::from_values(highest_marble as usize + 1, vec![0]);
+    let mut scores = vec![0; player_count];
+    let mut current = 0;
+
+    let player_iter = (0..player_count).cycle();
+    let marbles_iter = 1..=highest_marble;
+
+    for (player, marble) in player_iter.zip(marbles_iter) {
+        if marble % 23 == 0 {
+            let removed = circle.nth(current, -7);
+            current = circle.next(removed);
+            circle.remove(removed);
+
+            scores[player] += u64::from(marble) + u64::from(removed);
+        } else {
+            circle.insert_after(circle.next(current), marble);
+            current = marble;
+        }
+    }
+
+    *scores.iter().max().unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("9 players; last marble is worth 25 points").unwrap(), 32);
+        assert_eq!(
+            part1("10 players; last marble is worth 1618 points\n").unwrap(),
+            8317
+        );
+    }
+
+    #[test]
+    fn test_check_game() {
+        assert!(part1("0 players; last marble is worth 25 points").is_err());
+        assert!(part1("9 players; last marble is worth 4294967295 points").is_err());
+        assert!(check_game(9, MAX_MARBLE).is_ok());
+        assert!(check_game(9, MAX_MARBLE + 1).is_err());
+    }
+}
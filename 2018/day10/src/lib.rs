@@ -1,115 +1,165 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::ops::{Add, Sub};
+use std::collections::HashSet;
+use std::io;
+use std::mem::swap;
 
-use failure::{Error, ResultExt};
+use aoc_error::{Error, Result};
+use aoc_geometry::{BoundingBox, Point};
+use aoc_parse::parse_lines;
+use aoc_visualize::{Disabled, Frame, Rgb, Visualizer};
 
-use self::parser::parse_star;
+use self::parser::star;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-pub struct Vec2 {
-    pub x: i32,
-    pub y: i32,
+#[derive(Clone, Copy, Debug)]
+pub struct Star {
+    pub position: Point,
+    pub velocity: Point,
 }
 
-impl Vec2 {
-    pub fn new(x: i32, y: i32) -> Self {
-        Vec2 { x, y }
+impl Star {
+    pub fn next(&self) -> Self {
+        Star {
+            position: self.position + self.velocity,
+            velocity: self.velocity,
+        }
     }
 }
 
-impl Add for Vec2 {
-    type Output = Self;
+pub fn parse_stars(input: &str) -> Result<Vec<Star>> {
+    Ok(parse_lines(star(), input)?)
+}
 
-    fn add(self, other: Self) -> Self {
-        Vec2 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
-    }
+/// The message in the sky, which has to be read off of the image.
+pub fn part1(input: &str) -> Result<String> {
+    let (_, sky) = find_minimum_area(parse_stars(input)?, &mut Disabled)
+        .map_err(|e| Error::Io(e, "Could not write frame".into()))?;
+
+    Ok(render_sky(&sky))
+}
+
+/// How many seconds it takes for the message to appear.
+pub fn part2(input: &str) -> Result<u32> {
+    let (t, _) = find_minimum_area(parse_stars(input)?, &mut Disabled)
+        .map_err(|e| Error::Io(e, "Could not write frame".into()))?;
+
+    Ok(t)
 }
 
-impl Sub for Vec2 {
-    type Output = Self;
+/// Run the simulation until the stars are closest together, returning the time
+/// it took and the stars at that time.
+pub fn find_minimum_area(
+    mut sky: Vec<Star>,
+    visualizer: &mut dyn Visualizer,
+) -> io::Result<(u32, Vec<Star>)> {
+    let mut next_sky = Vec::with_capacity(sky.len());
+    let mut area = compute_area(&sky);
+    let mut t = 0;
+
+    loop {
+        if visualizer.enabled() {
+            if let Some(frame) = draw_sky(&sky) {
+                visualizer.frame(&frame)?;
+            }
+        }
+
+        next_sky.extend(sky.iter().map(|star| star.next()));
+        let next_area = compute_area(&next_sky);
+
+        if next_area < area {
+            swap(&mut next_sky, &mut sky);
+            next_sky.clear();
 
-    fn sub(self, other: Self) -> Self {
-        Vec2 {
-            x: self.x - other.x,
-            y: self.y - other.y,
+            area = next_area;
+            t += 1;
+        } else {
+            break;
         }
     }
+
+    Ok((t, sky))
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct Star {
-    pub position: Vec2,
-    pub velocity: Vec2,
+fn bounds(sky: &[Star]) -> BoundingBox<2> {
+    BoundingBox::from_points(sky.iter().map(|star| star.position)).expect("No stars?")
 }
 
-impl Star {
-    pub fn next(&self) -> Self {
-        Star {
-            position: self.position + self.velocity,
-            velocity: self.velocity,
+fn compute_area(sky: &[Star]) -> u64 {
+    let bounds = bounds(sky);
+
+    bounds.width() as u64 * bounds.height() as u64
+}
+
+pub fn render_sky(sky: &[Star]) -> String {
+    let bounds = bounds(sky);
+    let points = sky
+        .iter()
+        .map(|star| star.position)
+        .collect::<HashSet<Point>>();
+
+    let mut image = String::new();
+    for p in bounds.points() {
+        if points.contains(&p) {
+            image.push('#');
+        } else {
+            image.push(' ');
+        }
+
+        if p.x() == bounds.max.x() {
+            image.push('\n');
         }
     }
+
+    image
 }
 
-pub fn read_stars() -> Result<Vec<Star>, Error> {
-    let mut f = File::open("input").context("Could not open input file")?;
-    let buf = {
-        let mut buf = String::new();
-        f.read_to_string(&mut buf)
-            .context("Could not read input file")?;
-        buf
-    };
+/// Skies wider or taller than this are not worth drawing.
+const MAX_FRAME_SIZE: usize = 200;
+
+/// Draw the sky once the stars are close enough together to make out.
+fn draw_sky(sky: &[Star]) -> Option<Frame> {
+    let bounds = BoundingBox::from_points(sky.iter().map(|star| star.position))?;
+
+    if bounds.width() > MAX_FRAME_SIZE || bounds.height() > MAX_FRAME_SIZE {
+        return None;
+    }
 
-    buf.lines().map(parse_star).collect()
+    let mut frame = Frame::new(bounds.width(), bounds.height());
+    for star in sky {
+        let p = star.position - bounds.min;
+        frame.set(p.x() as usize, p.y() as usize, '#', Rgb::YELLOW);
+    }
+
+    Some(frame)
 }
 
 mod parser {
-    use super::{Star, Vec2};
+    use super::{Point, Star};
 
-    use combine::parser::char::{digit, space, string};
-    use combine::parser::choice::optional;
-    use combine::parser::item::{eof, token};
-    use combine::parser::repeat::{many1, skip_many};
-    use combine::stream::state::State;
-    use combine::{ParseError, Parser, Stream};
-    use failure::format_err;
+    use aoc_parse::{coordinates, inline_spaces};
+    use combine::parser::char::{char, space, string};
+    use combine::{ParseError, Parser, RangeStream};
 
-    fn vec2<I>() -> impl Parser<Input = I, Output = Vec2>
+    fn vec2<'a, I>() -> impl Parser<I, Output = Point> + 'a
     where
-        I: Stream<Item = char>,
-        I::Error: ParseError<I::Item, I::Range, I::Position>,
+        I: RangeStream<Token = char, Range = &'a str> + 'a,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
-        let number = || {
-            (
-                optional(token('-')),
-                many1::<String, _>(digit()).map(|digits| str::parse::<i32>(&digits).unwrap()),
-            )
-                .map(|(sign, n)| match (sign, n) {
-                    (Some(_), n) => -n,
-                    (_, n) => n,
-                })
-        };
-
-        token('<')
-            .skip(skip_many(space()))
-            .with((number().skip(token(',')).skip(skip_many(space())), number()))
-            .skip(token('>'))
-            .map(|(x, y)| Vec2 { x, y })
+        char('<')
+            .skip(inline_spaces())
+            .with(coordinates())
+            .skip(inline_spaces())
+            .skip(char('>'))
+            .map(|(x, y)| Point::new(x, y))
     }
 
-    pub fn parse_star(s: &str) -> Result<Star, failure::Error> {
+    pub fn star<'a, I>() -> impl Parser<I, Output = Star> + 'a
+    where
+        I: RangeStream<Token = char, Range = &'a str> + 'a,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
+    {
         (
             string("position=").with(vec2()).skip(space()),
             string("velocity=").with(vec2()),
         )
-            .skip(eof())
             .map(|(position, velocity)| Star { position, velocity })
-            .easy_parse(State::new(s))
-            .map_err(|e| format_err!("Could not parse `{}': {}", s, e))
-            .map(|(result, _)| result)
     }
 }
@@ -1,3 +1,23 @@
+use std::cmp::max;
+
+use aoc_error::{parse_at, Result};
+
+/// The top left corner of the 3x3 square with the largest total power.
+pub fn part1(input: &str) -> Result<String> {
+    let (x, y) = find_maximum_3x3(parse_serial(input)?);
+    Ok(format!("{},{}", x, y))
+}
+
+/// The top left corner and size of the square with the largest total power.
+pub fn part2(input: &str) -> Result<String> {
+    let Square { x, y, size, .. } = find_maximum_square(parse_serial(input)?);
+    Ok(format!("{},{},{}", x, y, size))
+}
+
+pub fn parse_serial(input: &str) -> Result<i32> {
+    Ok(parse_at(input, input.trim())?)
+}
+
 pub fn generate_grid(serial: i32) -> Vec<Vec<i32>> {
     (1..=300)
         .map(|y| (1..=300).map(|x| power(x, y, serial)).collect::<Vec<_>>())
@@ -10,9 +30,133 @@ fn power(x: i32, y: i32, serial: i32) -> i32 {
     ((((rack_id * y + serial) * rack_id) / 100) % 10) - 5
 }
 
+
+/// The top left corner of the 3x3 square with the largest total power.
+pub fn find_maximum_3x3(serial: i32) -> (usize, usize) {
+    let grid = generate_grid(serial);
+
+    let mut max_power = None;
+    let mut x_max = None;
+    let mut y_max = None;
+
+    for y in 0..297 {
+        for x in 0..297 {
+            let power = grid[y][x]
+                + grid[y][x + 1]
+                + grid[y][x + 2]
+                + grid[y + 1][x]
+                + grid[y + 1][x + 1]
+                + grid[y + 1][x + 2]
+                + grid[y + 2][x]
+                + grid[y + 2][x + 1]
+                + grid[y + 2][x + 2];
+
+            if let Some(prev_max) = max_power {
+                if power > prev_max {
+                    max_power = Some(max(prev_max, power));
+                    y_max = Some(y);
+                    x_max = Some(x);
+                }
+            } else {
+                max_power = Some(power);
+            }
+        }
+    }
+
+    (x_max.unwrap() + 1, y_max.unwrap() + 1)
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Square {
+    pub x: usize,
+    pub y: usize,
+    pub size: usize,
+    pub power: i32,
+}
+
+/// The square of any size with the largest total power.
+pub fn find_maximum_square(serial: i32) -> Square {
+    let grid = generate_grid(serial);
+    let sums = {
+        let mut sums = vec![vec![0; 300]; 300];
+
+        // for y in 1..300 {
+        //     sums[y][0] += sums[y - 1][0];
+        // }
+
+        // for x in 1..300 {
+        //     sums[0][x] += sums[0][x - 1];
+        // }
+
+        // for y in 1..300 {
+        //     for x in 1..300 {
+        //         sums[y][x] += sums[y - 1][x] + sums[y][x - 1] - sums[y - 1][x - 1];
+        //     }
+        // }
+
+        for y in 0..300 {
+            for x in 0..300 {
+                sums[y][x] = grid[y][x]
+                    + if y > 0 { sums[y - 1][x] } else { 0 }
+                    + if x > 0 { sums[y][x - 1] } else { 0 }
+                    - if x > 0 && y > 0 {
+                        sums[y - 1][x - 1]
+                    } else {
+                        0
+                    };
+            }
+        }
+
+        sums
+    };
+
+    let total_sum = grid.iter().flat_map(|row| row.iter()).sum::<i32>();
+
+    assert_eq!(total_sum, sums[299][299]);
+
+    let mut best = Square {
+        x: 1,
+        y: 1,
+        power: grid[0][0],
+        size: 1,
+    };
+
+    for y in 0..300 {
+        for x in 0..300 {
+            let max_size = 300 - max(x, y);
+
+            for size in 0..max_size {
+                let mut power = sums[y + size][x + size];
+
+                if x > 0 && y > 0 {
+                    power += sums[y - 1][x - 1];
+                }
+
+                if x > 0 {
+                    power -= sums[y + size][x - 1];
+                }
+
+                if y > 0 {
+                    power -= sums[y - 1][x + size];
+                }
+
+                if power > best.power {
+                    best = Square {
+                        x: x + 1,
+                        y: y + 1,
+                        size: size + 1,
+                        power,
+                    };
+                }
+            }
+        }
+    }
+    best
+}
+
 #[cfg(test)]
 mod test {
-    use super::power;
+    use super::*;
 
     #[test]
     fn test_sample() {
@@ -20,4 +164,32 @@ mod test {
         assert_eq!(power(217, 196, 39), 0);
         assert_eq!(power(101, 153, 71), 4);
     }
+
+    #[test]
+    fn test_find_maximum_square() {
+        assert_eq!(
+            find_maximum_square(18),
+            Square {
+                x: 90,
+                y: 269,
+                size: 16,
+                power: 113,
+            }
+        );
+        assert_eq!(
+            find_maximum_square(42),
+            Square {
+                x: 232,
+                y: 251,
+                size: 12,
+                power: 119,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(part1("18\n").unwrap(), "33,45");
+        assert_eq!(part1("42").unwrap(), "21,61");
+    }
 }
@@ -1,8 +1,9 @@
-use std::collections::VecDeque;
-use std::fs::File;
-use std::io::prelude::*;
+use std::result;
 
-use failure::{format_err, Error, ResultExt};
+use aoc_automaton::{Automaton, Line};
+use aoc_cycle::Detector;
+use aoc_error::{Error, ParseError, Result};
+use aoc_geometry::Vector;
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Rule {
@@ -10,61 +11,72 @@ pub struct Rule {
     output: bool,
 }
 
-#[derive(Debug)]
-pub struct Garden {
-    rules: [bool; 32],
-    pub state: VecDeque<bool>,
-    pub offset: usize,
-}
+/// Whether a pot has a plant in the next generation, indexed by the `key` of
+/// the pot and the two pots either side of it.
+#[derive(Clone, Copy, Debug)]
+struct Notes([bool; 32]);
 
-impl Garden {
-    pub fn new(rules: [Rule; 32], state: Vec<bool>) -> Self {
-        let mut sorted_rules = [false; 32];
-        for rule in &rules {
-            sorted_rules[Garden::key_from_slice(&rule.input)] = rule.output;
+impl aoc_automaton::Rule<bool> for Notes {
+    fn next<I>(&self, pot: bool, mut neighbours: I) -> bool
+    where
+        I: Iterator<Item = bool>,
+    {
+        let mut pots = [pot; 5];
+        for &i in &[0, 1, 3, 4] {
+            pots[i] = neighbours.next().unwrap();
         }
 
-        let mut state = state.into_iter().collect();
-        let offset = Garden::extend(&mut state);
-
-        Garden {
-            rules: sorted_rules,
-            state,
-            offset,
-        }
+        self.0[Garden::key_from_slice(&pots)]
     }
+}
 
-    fn with_offset(rules: [Rule; 32], state: Vec<bool>, offset: usize) -> Self {
-        let mut g = Garden::new(rules, state);
-        g.offset += offset;
-        g
-    }
+#[derive(Debug)]
+pub struct Garden {
+    pots: Automaton<Line, Notes>,
+}
 
-    pub fn pots<'a>(&'a self) -> impl Iterator<Item = (i32, bool)> + 'a {
-        (-(self.offset as i32)..).zip(self.state.iter().map(|&b| b))
+impl Garden {
+    pub fn new(rules: [Rule; 32], state: Vec<bool>) -> result::Result<Self, ParseError> {
+        Garden::with_offset(rules, state, 0)
     }
 
-    // Ensure there are at least 4 empty pots on each side.
-    fn extend(state: &mut VecDeque<bool>) -> usize {
-        if let Some(n) = state.iter().rev().position(|x| *x) {
-            if n < 4 {
-                for _ in 0..(4 - n) {
-                    state.push_back(false);
-                }
-            }
+    /// A garden where the first pot in `state` is numbered `-offset`.
+    fn with_offset(
+        rules: [Rule; 32],
+        state: Vec<bool>,
+        offset: usize,
+    ) -> result::Result<Self, ParseError> {
+        let mut notes = [false; 32];
+        // The unused rules are all `..... => .`, so only the rules that grow a
+        // plant matter.
+        for rule in rules.iter().filter(|rule| rule.output) {
+            notes[Garden::key_from_slice(&rule.input)] = true;
         }
 
-        if let Some(n) = state.iter().position(|x| *x) {
-            if n < 4 {
-                for _ in 0..(4 - n) {
-                    state.push_front(false);
-                }
-
-                return 4 - n;
-            }
+        // Otherwise every one of the infinitely many empty pots would grow a
+        // plant.
+        if notes[0] {
+            return Err(ParseError::new(
+                "the rule `..... => #' would grow a plant in every empty pot",
+            ));
         }
 
-        0
+        let plants = state
+            .into_iter()
+            .enumerate()
+            .filter(|(_, pot)| *pot)
+            .map(|(i, _)| Vector([i as isize - offset as isize]));
+
+        Ok(Garden {
+            pots: Automaton::new(Line { radius: 2 }, Notes(notes), plants),
+        })
+    }
+
+    /// The numbers of the pots with plants, in order.
+    pub fn plants(&self) -> Vec<isize> {
+        let mut plants = self.pots.alive().iter().map(|p| p[0]).collect::<Vec<_>>();
+        plants.sort_unstable();
+        plants
     }
 
     fn key_from_slice(t: &[bool]) -> usize {
@@ -82,41 +94,21 @@ impl Garden {
     }
 
     pub fn next(&mut self) {
-        let mut new_state = self
-            .state
-            .iter()
-            .skip(2)
-            .take(self.state.len() - 2)
-            .scan(
-                vec![false, false, false, false, false]
-                    .into_iter()
-                    .collect::<VecDeque<_>>(),
-                |state, &pot| {
-                    state.pop_front();
-                    state.push_back(pot);
-
-                    Some(
-                        self.rules[Garden::key((state[0], state[1], state[2], state[3], state[4]))],
-                    )
-                },
-            )
-            .collect::<VecDeque<_>>();
-
-        let delta_offset = Garden::extend(&mut new_state);
-
-        self.state = new_state;
-        self.offset += delta_offset;
+        self.pots.step();
     }
 
+    /// The pots from four before the first plant to four after the last, or
+    /// nothing if there are no plants.
     pub fn to_string(&self) -> String {
-        let first = self.state.iter().position(|&p| p).unwrap() - 4;
-        let last = self.state.iter().rposition(|&p| p).unwrap() + 4;
-
-        self.state
-            .iter()
-            .skip(first)
-            .take(last - first + 1)
-            .map(|p| match p {
+        let bounds = match self.pots.bounds() {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+
+        bounds
+            .expand(4)
+            .points()
+            .map(|p| match self.pots.is_alive(p) {
                 true => '#',
                 false => '.',
             })
@@ -124,56 +116,87 @@ impl Garden {
     }
 
     pub fn score(&self) -> i32 {
-        self.pots()
-            .filter_map(|(i, pot)| if pot { Some(i) } else { None })
-            .sum()
+        self.pots.alive().iter().map(|p| p[0] as i32).sum()
     }
 }
 
-pub fn read_input() -> Result<Garden, Error> {
-    let mut f =
-        File::open("input").with_context(|e| format_err!("Could not open input file: {}", e))?;
+pub fn parse_input(input: &str) -> Result<Garden> {
+    parser::parse_input(input)
+}
+
+/// The sum of the numbers of the pots with plants after 20 generations.
+pub fn part1(input: &str) -> Result<i32> {
+    let mut garden = parse_input(input)?;
+
+    for _ in 0..20 {
+        garden.next();
+    }
+
+    Ok(garden.score())
+}
+
+/// The sum of the numbers of the pots with plants after fifty billion
+/// generations.
+pub fn part2(input: &str) -> Result<u64> {
+    const GENERATIONS: u64 = 50_000_000_000;
+
+    let mut garden = parse_input(input)?;
+
+    // By experiment, the plants eventually settle into a pattern that repeats
+    // further along the row.
+    let mut detector = Detector::new();
+    let mut scores = Vec::new();
+
+    let cycle = loop {
+        let plants = garden.plants();
+        let first = plants.first().copied().unwrap_or(0);
+        let shape = plants.iter().map(|p| p - first).collect::<Vec<_>>();
 
-    let buf = {
-        let mut buf = String::new();
-        f.read_to_string(&mut buf)
-            .with_context(|e| format_err!("Could not read input file: {}", e))?;
+        scores.push(garden.score() as i64);
 
-        buf
+        if let Some(cycle) = detector.push_at(shape, first as i64) {
+            break cycle;
+        }
+
+        garden.next();
     };
 
-    parser::parse_input(&buf)
+    // Every plant moves by the same amount each time around the cycle.
+    let plants = garden.plants().len() as i64;
+    let shift = cycle
+        .shift(GENERATIONS)
+        .ok_or_else(|| Error::Other("the plants move too far to count".into()))?;
+
+    // The reduced step is in the first time around the cycle, so it has a
+    // score.
+    Ok((scores[cycle.reduce(GENERATIONS) as usize] + plants * shift) as u64)
 }
 
 mod parser {
-    use combine::parser::char::{space, string};
-    use combine::parser::item::{eof, satisfy};
-    use combine::parser::repeat::{count_min_max, many1, skip_many1};
-    use combine::stream::state::State;
+    use aoc_error::Result;
+    use aoc_parse::{lines, parse_all};
+    use combine::parser::char::{newline, string};
+    use combine::parser::repeat::{count_min_max, many1};
+    use combine::parser::token::one_of;
     use combine::{ParseError, Parser, Stream};
-    use failure::{format_err, Error};
 
     use super::{Garden, Rule};
 
-    fn plant<I>() -> impl Parser<Input = I, Output = bool>
+    fn plant<I>() -> impl Parser<I, Output = bool>
     where
-        I: Stream<Item = char>,
-        I::Error: ParseError<I::Item, I::Range, I::Position>,
+        I: Stream<Token = char>,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
-        satisfy(|c| c == '.' || c == '#').map(|c| match c {
-            '.' => false,
-            '#' => true,
-            _ => unreachable!(),
-        })
+        one_of(".#".chars()).map(|c| c == '#')
     }
 
-    pub fn rule<I>() -> impl Parser<Input = I, Output = Rule>
+    pub fn rule<I>() -> impl Parser<I, Output = Rule>
     where
-        I: Stream<Item = char>,
-        I::Error: ParseError<I::Item, I::Range, I::Position>,
+        I: Stream<Token = char>,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
         (
-            count_min_max::<Vec<_>, _>(5, 5, plant()).skip(string(" => ")),
+            count_min_max::<Vec<_>, _, _>(5, 5, plant()).skip(string(" => ")),
             plant(),
         )
             .map(|(inputs, output)| Rule {
@@ -182,48 +205,41 @@ mod parser {
             })
     }
 
-    pub fn parse_rule(s: &str) -> Result<Rule, Error> {
-        rule()
-            .skip(eof())
-            .easy_parse(s)
-            .map(|(rule, _)| rule)
-            .map_err(|e| format_err!("Could not parse rule `{}': {}", s, e))
+    pub fn parse_rule(s: &str) -> Result<Rule> {
+        Ok(parse_all(rule(), s)?)
     }
 
-    fn state<I>() -> impl Parser<Input = I, Output = Vec<bool>>
+    fn state<I>() -> impl Parser<I, Output = Vec<bool>>
     where
-        I: Stream<Item = char>,
-        I::Error: ParseError<I::Item, I::Range, I::Position>,
+        I: Stream<Token = char>,
+        I::Error: ParseError<I::Token, I::Range, I::Position>,
     {
-        many1::<Vec<_>, _>(plant())
+        many1(plant())
     }
 
-    pub fn parse_state(s: &str) -> Result<Vec<bool>, Error> {
-        state()
-            .skip(eof())
-            .easy_parse(s)
-            .map(|(st, _)| st)
-            .map_err(|e| format_err!("Could not parse state `{}': {}", s, e))
+    pub fn parse_state(s: &str) -> Result<Vec<bool>> {
+        Ok(parse_all(state(), s)?)
     }
 
-    pub fn parse_input(s: &str) -> Result<Garden, Error> {
-        string("initial state: ")
-            .with((
-                state(),
-                skip_many1(space()),
-                many1::<Vec<_>, _>(rule().skip(space())),
-            ))
-            .skip(eof())
-            .map(|(state, (), rules_vec)| {
+    pub fn parse_input(s: &str) -> Result<Garden> {
+        let garden = (
+            string("initial state: ")
+                .with(state())
+                .skip(newline())
+                .skip(newline()),
+            lines(rule()),
+        )
+            .map(|(state, rules_vec)| {
                 let mut rules = [Rule::default(); 32];
                 for rule in rules_vec {
                     rules[Garden::key_from_slice(&rule.input)] = rule;
                 }
-                Garden::new(rules, state)
-            })
-            .easy_parse(State::new(s))
-            .map(|(r, _)| r)
-            .map_err(|e| format_err!("Could not parse input file: {}", e))
+                (rules, state)
+            });
+
+        let (rules, state) = parse_all(garden, s)?;
+
+        Ok(Garden::new(rules, state)?)
     }
 }
 
@@ -231,7 +247,7 @@ mod parser {
 mod test {
     use lazy_static::lazy_static;
 
-    use super::{parser, Garden, Rule};
+    use super::{parse_input, parser, Garden, Rule};
 
     lazy_static! {
         static ref RULES: [Rule; 32] = {
@@ -301,83 +317,38 @@ mod test {
         assert_eq!(Garden::key((true, true, true, true, true)), 31);
     }
 
-    #[test]
-    fn test_garden_extend() {
-        {
-            let mut st = vec![false, false, false, false, true, false, false, false]
-                .into_iter()
-                .collect();
-
-            assert_eq!(Garden::extend(&mut st), 0);
-            assert_eq!(
-                st,
-                vec![false, false, false, false, true, false, false, false, false]
-            );
-        }
-
-        {
-            let mut st = vec![false, false, false, true, false, false, false]
-                .into_iter()
-                .collect();
-
-            assert_eq!(Garden::extend(&mut st), 1);
-            assert_eq!(
-                st,
-                vec![false, false, false, false, true, false, false, false, false]
-            );
-        }
-
-        {
-            let mut st = vec![false, false, true, false, false].into_iter().collect();
-
-            assert_eq!(Garden::extend(&mut st), 2);
-            assert_eq!(
-                st,
-                vec![false, false, false, false, true, false, false, false, false]
-            );
-        }
-
-        {
-            let mut st = vec![false, true, true, true, false].into_iter().collect();
-            assert_eq!(Garden::extend(&mut st), 3);
-
-            assert_eq!(
-                st,
-                vec![false, false, false, false, true, true, true, false, false, false, false]
-            );
-        }
-
-        {
-            let mut st = vec![true, false, false, false, true].into_iter().collect();
-
-            assert_eq!(Garden::extend(&mut st), 4);
-
-            assert_eq!(
-                st,
-                vec![
-                    false, false, false, false, true, false, false, false, true, false, false,
-                    false, false
-                ]
-            );
-        }
-    }
-
     #[test]
     fn test_to_string() {
         assert_eq!(
-            Garden::with_offset(RULES.clone(), STATES[0].clone(), 3).to_string(),
+            Garden::with_offset(RULES.clone(), STATES[0].clone(), 3)
+                .unwrap()
+                .to_string(),
             "....#..#.#..##......###...###...."
         );
 
         assert_eq!(
-            Garden::with_offset(RULES.clone(), STATES[1].clone(), 3).to_string(),
+            Garden::with_offset(RULES.clone(), STATES[1].clone(), 3)
+                .unwrap()
+                .to_string(),
             "....#...#....#.....#..#..#..#...."
         );
     }
 
+    #[test]
+    fn test_empty() {
+        let garden = Garden::new(*RULES, vec![false; 5]).unwrap();
+        assert_eq!(garden.to_string(), "");
+
+        let e = parse_input("initial state: #..#\n\n..... => #\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "the rule `..... => #' would grow a plant in every empty pot"
+        );
+    }
+
     #[test]
     fn test_next() {
-        let mut garden = Garden::with_offset(RULES.clone(), STATES[0].clone(), 3);
+        let mut garden = Garden::with_offset(RULES.clone(), STATES[0].clone(), 3).unwrap();
 
         let expected_strs = vec![
             "....#..#.#..##......###...###....",
@@ -418,9 +389,8 @@ mod test {
 
     #[test]
     fn test_score() {
-        let garden = Garden::with_offset(RULES.clone(), STATES[20].clone(), 3);
+        let garden = Garden::with_offset(RULES.clone(), STATES[20].clone(), 3).unwrap();
 
         assert_eq!(garden.score(), 325);
     }
-
 }
@@ -1,15 +1,25 @@
-use failure::Error;
+use std::fs::read_to_string;
+use std::process::exit;
 
-use day12::read_input;
+use aoc_error::{Error, Result};
+use aoc_output::Output;
 
-fn main() -> Result<(), Error> {
-    let mut garden = read_input()?;
+use day12::part1;
 
-    for _ in 0..20 {
-        garden.next();
-    }
+fn run() -> Result<i32> {
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part1(&input)
+}
 
-    println!("{}", garden.score());
+fn main() {
+    let mut output = Output::new(2018, 12);
 
-    Ok(())
+    match run() {
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
+        }
+
+        Ok(result) => output.part(1, result),
+    }
 }
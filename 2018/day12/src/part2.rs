@@ -1,43 +1,25 @@
-use std::mem::swap;
+use std::fs::read_to_string;
+use std::process::exit;
 
-use failure::Error;
+use aoc_error::{Error, Result};
+use aoc_output::Output;
 
-use day12::{read_input, Garden};
+use day12::part2;
 
-fn main() -> Result<(), Error> {
-    let mut garden = read_input()?;
-
-    // By experiment, the automata becomes linear eventually.
-    let mut last_trimmed_pots = trim_pots(&garden).collect::<Vec<_>>();
-    let mut trimmed_pots = Vec::with_capacity(last_trimmed_pots.len());
-    garden.next();
+fn run() -> Result<u64> {
+    let input = read_to_string("input").map_err(|e| Error::Io(e, "Could not read input".into()))?;
+    part2(&input)
+}
 
-    let mut i = 1u64;
-    loop {
-        trimmed_pots.extend(trim_pots(&garden));
+fn main() {
+    let mut output = Output::new(2018, 12);
 
-        if trimmed_pots == last_trimmed_pots {
-            break;
+    match run() {
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
         }
 
-        swap(&mut last_trimmed_pots, &mut trimmed_pots);
-        trimmed_pots.clear();
-
-        garden.next();
-        i += 1;
+        Ok(result) => output.part(2, result),
     }
-
-    let last_score = garden.score() as u64;
-    garden.next();
-    let next_score = garden.score() as u64;
-
-    let score = last_score + (50000000000u64 - i) * (next_score - last_score);
-
-    println!("{}", score);
-
-    Ok(())
-}
-
-fn trim_pots(g: &Garden) -> impl Iterator<Item = bool> + '_ {
-    g.pots().map(|(_, pot)| pot).skip_while(|pot| !pot)
 }
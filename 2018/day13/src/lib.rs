@@ -0,0 +1,264 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::io;
+use std::mem::replace;
+
+use aoc_error::ParseError;
+use aoc_grid::{Grid, Point, DOWN, LEFT, RIGHT, UP};
+use aoc_visualize::{Disabled, Frame, Rgb, Visualizer};
+
+/// The location of the first crash, as `x,y`.
+pub fn part1(input: &str) -> Result<String, Box<dyn Error>> {
+    let position = first_crash(parse_input(input)?, &mut Disabled)?;
+    Ok(format!("{},{}", position.x(), position.y()))
+}
+
+/// The location of the last cart left after every other cart has crashed.
+pub fn part2(input: &str) -> Result<String, Box<dyn Error>> {
+    let position = last_cart(parse_input(input)?, &mut Disabled)?;
+    Ok(format!("{},{}", position.x(), position.y()))
+}
+
+pub fn first_crash(mut system: System, visualizer: &mut dyn Visualizer) -> io::Result<Point> {
+    loop {
+        visualizer.emit(|| system.draw())?;
+        if let Some(position) = system.tick().first() {
+            return Ok(*position);
+        }
+    }
+}
+
+pub fn last_cart(mut system: System, visualizer: &mut dyn Visualizer) -> io::Result<Point> {
+    loop {
+        visualizer.emit(|| system.draw())?;
+        system.tick();
+        if system.carts().count() == 1 {
+            return Ok(system.carts().next().unwrap().position);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Decision {
+    Left,
+    Straight,
+    Right,
+}
+
+impl Decision {
+    fn next(&mut self) -> Self {
+        let decision = *self;
+        match self {
+            Decision::Left => {
+                *self = Decision::Straight;
+            }
+            Decision::Straight => {
+                *self = Decision::Right;
+            }
+            Decision::Right => {
+                *self = Decision::Left;
+            }
+        }
+
+        decision
+    }
+}
+
+impl Default for Decision {
+    fn default() -> Self {
+        Decision::Left
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Cart {
+    pub position: Point,
+    velocity: Point,
+    next_intersection: Decision,
+}
+
+impl PartialOrd for Cart {
+    fn partial_cmp(&self, rhs: &Cart) -> Option<Ordering> {
+        self.position.partial_cmp(&rhs.position)
+    }
+}
+
+impl Ord for Cart {
+    fn cmp(&self, rhs: &Cart) -> Ordering {
+        self.position.cmp(&rhs.position)
+    }
+}
+
+impl Cart {
+    pub fn new(position: Point, velocity: Point) -> Cart {
+        Cart {
+            position,
+            velocity,
+            next_intersection: Decision::default(),
+        }
+    }
+
+    fn next(&mut self, g: &Grid<char>) {
+        self.velocity = match g[self.position] {
+            '+' => match self.next_intersection.next() {
+                Decision::Left => self.velocity.rotate_left(),
+                Decision::Right => self.velocity.rotate_right(),
+                Decision::Straight => self.velocity,
+            },
+
+            '|' | '-' => self.velocity,
+
+            '/' => match self.velocity {
+                UP | DOWN => self.velocity.rotate_right(),
+                LEFT | RIGHT => self.velocity.rotate_left(),
+                _ => unreachable!(),
+            },
+            '\\' => match self.velocity {
+                UP | DOWN => self.velocity.rotate_left(),
+                LEFT | RIGHT => self.velocity.rotate_right(),
+                _ => unreachable!(),
+            },
+            c => panic!(format!("unexpected {}", c)),
+        };
+
+        self.position += self.velocity;
+    }
+}
+
+#[derive(Clone)]
+pub struct System {
+    grid: Grid<char>,
+    carts: BTreeSet<Cart>,
+}
+
+impl System {
+    pub fn tick(&mut self) -> Vec<Point> {
+        let mut crashes = vec![];
+        let mut prev_carts = replace(&mut self.carts, BTreeSet::new());
+
+        while prev_carts.len() > 0 {
+            let mut cart = prev_carts.iter().cloned().next().unwrap();
+            prev_carts.remove(&cart);
+
+            cart.next(&self.grid);
+
+            if let Some(cart2) = prev_carts
+                .iter()
+                .cloned()
+                .find(|cart2| cart.position == cart2.position)
+            {
+                prev_carts.remove(&cart2);
+                crashes.push(cart.position);
+            } else if let Some(cart2) = self
+                .carts
+                .iter()
+                .cloned()
+                .find(|cart2| cart.position == cart2.position)
+            {
+                self.carts.remove(&cart2);
+                crashes.push(cart.position);
+            } else {
+                self.carts.insert(cart);
+            }
+        }
+
+        crashes
+    }
+
+    pub fn carts(&self) -> impl Iterator<Item = &'_ Cart> + '_ {
+        self.carts.iter()
+    }
+
+    pub fn draw(&self) -> Frame {
+        let mut frame = Frame::new(self.grid.width(), self.grid.height());
+
+        for (p, c) in self.grid.iter() {
+            frame.set(p.x() as usize, p.y() as usize, *c, Rgb::GREY);
+        }
+
+        for cart in &self.carts {
+            let glyph = match cart.velocity {
+                LEFT => '<',
+                RIGHT => '>',
+                UP => '^',
+                DOWN => 'v',
+                _ => unreachable!(),
+            };
+            frame.set(
+                cart.position.x() as usize,
+                cart.position.y() as usize,
+                glyph,
+                Rgb::RED,
+            );
+        }
+
+        frame
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<System, ParseError> {
+    let mut grid = Grid::parse(input, |c| {
+        if "-<>|v^+/\\ ".contains(c) {
+            Some(c)
+        } else {
+            None
+        }
+    })?;
+
+    let mut carts = BTreeSet::new();
+
+    for p in grid.points() {
+        let c = grid[p];
+
+        match c {
+            '-' | '<' | '>' => {
+                if c == '<' || c == '>' {
+                    carts.insert(Cart::new(p, if c == '<' { LEFT } else { RIGHT }));
+                    grid[p] = '-';
+                }
+            }
+            '|' | 'v' | '^' => {
+                if c == 'v' || c == '^' {
+                    carts.insert(Cart::new(p, if c == 'v' { DOWN } else { UP }));
+                    grid[p] = '|';
+                }
+            }
+
+            '+' | '/' | '\\' | ' ' => {}
+
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(System { grid, carts })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = concat!(
+            "/->-\\        \n",
+            "|   |  /----\\\n",
+            "| /-+--+-\\  |\n",
+            "| | |  | v  |\n",
+            "\\-+-/  \\-+--/\n",
+            "  \\------/   \n",
+        );
+        assert_eq!(part1(input).unwrap(), "7,3");
+
+        let input = concat!(
+            "/>-<\\  \n",
+            "|   |  \n",
+            "| /<+-\\\n",
+            "| | | v\n",
+            "\\>+</ |\n",
+            "  |   ^\n",
+            "  \\<->/\n",
+        );
+        assert_eq!(part2(input).unwrap(), "6,4");
+    }
+}
@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use aoc_error::{parse_at, ParseError};
+
+pub fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    input.lines().map(|line| parse_at(input, line)).collect()
+}
+
+fn calculate_fuel(mass: i32) -> i32 {
+    let fuel = mass / 3 - 2;
+    if fuel > 0 {
+        fuel + calculate_fuel(fuel)
+    } else {
+        0
+    }
+}
+
+/// The fuel required for every module, not counting the fuel itself.
+pub fn part1(input: &str) -> Result<i32, Box<dyn Error>> {
+    Ok(parse_input(input)?.iter().map(|i| i / 3 - 2).sum())
+}
+
+/// The fuel required for every module, including the fuel for the fuel.
+pub fn part2(input: &str) -> Result<i32, Box<dyn Error>> {
+    Ok(parse_input(input)?
+        .iter()
+        .map(|&i| calculate_fuel(i))
+        .sum())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(part1("12\n14\n1969\n100756\n").unwrap(), 2 + 2 + 654 + 33583);
+        assert_eq!(part2("14\n1969\n100756\n").unwrap(), 2 + 966 + 50346);
+    }
+}
@@ -1,43 +1,17 @@
 use std::error::Error;
-use std::io::BufReader;
-use std::io::prelude::*;
-use std::fs::File;
-use std::str;
+use std::fs;
 
-fn read_input() -> Result<Vec<i32>, Box<dyn Error>> {
-    let reader = BufReader::new(File::open("input.txt")?);
+use aoc_output::Output;
 
-    reader
-        .lines()
-        .map(|r| r
-            .map_err(Box::<dyn Error>::from)
-            .and_then(|s| str::parse::<i32>(&s).map_err(Into::into)
-            ))
-        .collect()
-}
-
-fn calculate_fuel(mass: i32) -> i32 {
-    let fuel = mass / 3 - 2;
-    if fuel > 0 {
-        fuel + calculate_fuel(fuel)
-    } else {
-        0
-    }
-}
+use day01_2019::{part1, part2};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let modules = read_input()?;
-    let fuel: i32 = modules.iter()
-        .map(|i| i / 3 - 2)
-        .sum();
-
-    println!("part 1: {}", fuel);
+    let mut output = Output::new(2019, 1);
 
-    let real_fuel: i32 = modules.iter()
-        .map(|&i| calculate_fuel(i))
-        .sum();
+    let input = fs::read_to_string("input.txt")?;
 
-    println!("part 2: {}", real_fuel);
+    output.part(1, part1(&input)?);
+    output.part(2, part2(&input)?);
 
     Ok(())
 }
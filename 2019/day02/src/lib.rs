@@ -0,0 +1,63 @@
+use std::error::Error;
+
+use aoc_error::ParseError;
+use aoc_parse::parse_separated;
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse_separated(input, ',')
+}
+
+/// The value left at position 0 after restoring the "1202 program alarm" state.
+pub fn part1(input: &str) -> Result<usize, Box<dyn Error>> {
+    let mut mem = parse_input(input)?;
+
+    mem[1] = 12;
+    mem[2] = 2;
+
+    process_opcodes(&mut mem);
+
+    Ok(mem[0])
+}
+
+/// `100 * noun + verb` for the noun and verb that produce 19690720.
+pub fn part2(input: &str) -> Result<usize, Box<dyn Error>> {
+    let mem = parse_input(input)?;
+
+    for i in 0..100 {
+        for j in 0..100 {
+            let mut mem = mem.clone();
+            mem[1] = i;
+            mem[2] = j;
+
+            process_opcodes(&mut mem);
+
+            if mem[0] == 19690720 {
+                return Ok(100 * mem[1] + mem[2]);
+            }
+        }
+    }
+
+    Err("no noun and verb produce 19690720".into())
+}
+
+fn process_opcodes(mem: &mut [usize]) {
+    for i in (0..mem.len()).step_by(4) {
+        let opcode = mem[i];
+        if opcode == 99 {
+            break;
+        }
+
+        let addr1 = mem[i + 1];
+        let val1 = mem[addr1];
+        let addr2 = mem[i + 2];
+        let val2 = mem[addr2];
+
+        let result_addr = mem[i + 3];
+
+        match opcode {
+            1 => mem[result_addr] = val1 + val2,
+            2 => mem[result_addr] = val1 * val2,
+            _ => panic!("invalid opcode"),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::cmp::{max, min};
+use std::error::Error;
+
+use aoc_error::{parse_at, ParseError};
+use aoc_geometry::{Point, DOWN, LEFT, RIGHT, UP};
+
+/// Parse a step of a wire, e.g., `U12`, which must be a slice of `input`.
+fn parse_step(input: &str, s: &str) -> Result<Point, ParseError> {
+    let direction = match s.chars().next() {
+        Some('U') => UP,
+        Some('D') => DOWN,
+        Some('L') => LEFT,
+        Some('R') => RIGHT,
+        Some(c) => {
+            return Err(ParseError::at_span(
+                input,
+                s,
+                format!("invalid direction `{}'; expected `U', `D', `L', or `R'", c),
+            ))
+        }
+        None => return Err(ParseError::at_span(input, s, "expected a step")),
+    };
+
+    let distance = parse_at::<u32>(input, &s[1..])?;
+
+    Ok(direction * distance as isize)
+}
+
+pub type Wire = Vec<Point>;
+
+pub fn parse_input(input: &str) -> Result<(Wire, Wire), ParseError> {
+    let wires = input
+        .lines()
+        .map(|line| line.split(',').map(|s| parse_step(input, s)).collect())
+        .collect::<Result<Vec<Wire>, _>>()?;
+
+    let mut wires = wires.into_iter();
+    match (wires.next(), wires.next(), wires.next()) {
+        (Some(first), Some(second), None) => Ok((first, second)),
+        _ => Err(ParseError::new("expected two wires")),
+    }
+}
+
+/// The Manhattan distance to the intersection closest to the central port.
+pub fn part1(input: &str) -> Result<isize, Box<dyn Error>> {
+    let (w1, w2) = parse_input(input)?;
+
+    intersections(&segments(&w1), &segments(&w2))
+        .iter()
+        .map(|(v, _)| v.manhattan())
+        .min()
+        .ok_or_else(|| "the wires do not intersect".into())
+}
+
+/// The fewest combined steps the wires take to reach an intersection.
+pub fn part2(input: &str) -> Result<isize, Box<dyn Error>> {
+    let (w1, w2) = parse_input(input)?;
+
+    intersections(&segments(&w1), &segments(&w2))
+        .iter()
+        .map(|(_, steps)| *steps)
+        .min()
+        .ok_or_else(|| "the wires do not intersect".into())
+}
+
+#[derive(Debug, Default)]
+struct Segment {
+    start: Point,
+    stop: Point,
+    distance: isize,
+}
+
+fn segments(w: &Wire) -> Vec<Segment> {
+    w.iter()
+        .scan((Point::default(), 0), |st, &v| {
+            let start = st.0;
+            let stop = start + v;
+
+            let distance = st.1;
+            let next_distance = distance + v.manhattan();
+
+            *st = (stop, next_distance);
+
+            Some(Segment {
+                start,
+                stop,
+                distance,
+            })
+        })
+        .collect()
+}
+
+fn intersections(s1: &[Segment], s2: &[Segment]) -> Vec<(Point, isize)> {
+    let mut results = vec![];
+    for us in s1 {
+        for vs in s2 {
+            if us.start.x() == us.stop.x() && vs.start.y() == vs.stop.y() {
+                let x = us.start.x();
+                let y = vs.start.y();
+
+                let min_x = min(vs.start.x(), vs.stop.x());
+                let max_x = max(vs.start.x(), vs.stop.x());
+
+                let min_y = min(us.start.y(), us.stop.y());
+                let max_y = max(us.start.y(), us.stop.y());
+
+                if min_x < x && x < max_x && min_y < y && y < max_y {
+                    results.push((
+                        Point::new(x, y),
+                        us.distance
+                            + vs.distance
+                            + (y - us.start.y()).abs()
+                            + (x - vs.start.x()).abs(),
+                    ));
+                }
+            } else if us.start.y() == us.stop.y() && vs.start.x() == vs.stop.x() {
+                let x = vs.start.x();
+                let y = us.start.y();
+
+                let min_x = min(us.start.x(), us.stop.x());
+                let max_x = max(us.start.x(), us.stop.x());
+
+                let min_y = min(vs.start.y(), vs.stop.y());
+                let max_y = max(vs.start.y(), vs.stop.y());
+
+                if min_x < x && x < max_x && min_y < y && y < max_y {
+                    results.push((
+                        Point::new(x, y),
+                        us.distance
+                            + vs.distance
+                            + (x - us.start.x()).abs()
+                            + (y - vs.start.y()).abs(),
+                    ));
+                }
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "R8,U5,L5,D3\nU7,R6,D4,L4\n";
+        assert_eq!(part1(input).unwrap(), 6);
+        assert_eq!(part2(input).unwrap(), 30);
+
+        let input = "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83\n";
+        assert_eq!(part1(input).unwrap(), 159);
+        assert_eq!(part2(input).unwrap(), 610);
+    }
+}
@@ -0,0 +1,91 @@
+use std::error::Error;
+use std::ops::RangeInclusive;
+
+use aoc_error::{parse_at, ParseError};
+
+/// Parse a range of the form `273025-767253`.
+pub fn parse_range(input: &str) -> Result<RangeInclusive<u32>, ParseError> {
+    let range = input.trim();
+    let (start, stop) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::at_span(input, range, "expected a range, e.g., `1-10'"))?;
+
+    Ok(parse_at(input, start)?..=parse_at(input, stop)?)
+}
+
+fn digits(i: u32) -> Vec<u8> {
+    i.to_string().chars().map(|c| c as u8 - b'0').collect()
+}
+
+/// How many passwords in the range never decrease and have a repeated digit.
+pub fn part1(input: &str) -> Result<usize, Box<dyn Error>> {
+    let mut results = 0;
+    'part1: for i in parse_range(input)? {
+        let digits = digits(i);
+
+        let mut same = false;
+        for j in 1..digits.len() {
+            if digits[j] < digits[j - 1] {
+                continue 'part1;
+            }
+
+            if digits[j] == digits[j - 1] {
+                same = true;
+            }
+        }
+
+        if same {
+            results += 1;
+        }
+    }
+
+    Ok(results)
+}
+
+/// How many passwords in the range never decrease and have a digit repeated
+/// exactly twice.
+pub fn part2(input: &str) -> Result<usize, Box<dyn Error>> {
+    let mut results = 0;
+    'part2: for i in parse_range(input)? {
+        let digits = digits(i);
+
+        let mut same = false;
+        let mut count = 1;
+        for j in 1..digits.len() {
+            if digits[j] < digits[j - 1] {
+                continue 'part2;
+            } else if digits[j] == digits[j - 1] {
+                count += 1;
+            } else {
+                if count == 2 {
+                    same = true;
+                }
+                count = 1;
+            }
+        }
+        if count == 2 {
+            same = true;
+        }
+
+        if same {
+            results += 1;
+        }
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(part1("111111-111111").unwrap(), 1);
+        assert_eq!(part1("223450-223450").unwrap(), 0);
+        assert_eq!(part2("112233-112233").unwrap(), 1);
+        assert_eq!(part2("123444-123444").unwrap(), 0);
+        assert_eq!(part2("111122-111122\n").unwrap(), 1);
+        assert!(parse_range("273025").is_err());
+    }
+}
@@ -0,0 +1,270 @@
+use std::error::Error;
+
+use aoc_error::ParseError;
+use aoc_parse::parse_separated;
+
+pub fn parse_input(input: &str) -> Result<Vec<isize>, ParseError> {
+    parse_separated(input, ',')
+}
+
+/// The diagnostic code for the air conditioner unit.
+pub fn part1(input: &str) -> Result<isize, Box<dyn Error>> {
+    diagnostic_code(&run(&mut parse_input(input)?, 1))
+}
+
+/// The diagnostic code for the thermal radiator controller.
+pub fn part2(input: &str) -> Result<isize, Box<dyn Error>> {
+    diagnostic_code(&run(&mut parse_input(input)?, 5))
+}
+
+/// The diagnostic code is the last output, after all the tests have passed.
+fn diagnostic_code(outputs: &[isize]) -> Result<isize, Box<dyn Error>> {
+    match outputs.split_last() {
+        Some((code, tests)) if tests.iter().all(|&t| t == 0) => Ok(*code),
+        Some(_) => Err(format!("diagnostic tests failed: {:?}", outputs).into()),
+        None => Err("no diagnostic code".into()),
+    }
+}
+
+fn run(mem: &mut [isize], input: isize) -> Vec<isize> {
+    let mut pc = 0usize;
+    let mut outputs = vec![];
+
+    loop {
+        let instr = parse_instr(&mem[pc..]);
+        match instr.execute(mem, input) {
+            Some(ExecResult::Halt) => break,
+            Some(ExecResult::Output(output)) => {
+                outputs.push(output);
+                pc += instr.len();
+            }
+            Some(ExecResult::Jump(addr)) => pc = addr,
+            None => pc += instr.len(),
+        }
+    }
+
+    outputs
+}
+
+#[derive(Debug)]
+pub enum Instr {
+    Add(Value, Value, usize),
+    Mul(Value, Value, usize),
+    Input(usize),
+    Output(Value),
+    JumpIfTrue(Value, Value),
+    JumpIfFalse(Value, Value),
+    LessThan(Value, Value, usize),
+    Equals(Value, Value, usize),
+    Halt,
+}
+
+enum ExecResult {
+    Halt,
+    Output(isize),
+    Jump(usize),
+}
+
+impl Instr {
+    fn len(&self) -> usize {
+        match self {
+            Instr::Add(..) => 4,
+            Instr::Mul(..) => 4,
+            Instr::Input(..) => 2,
+            Instr::Output(..) => 2,
+            Instr::JumpIfTrue(..) => 3,
+            Instr::JumpIfFalse(..) => 3,
+            Instr::LessThan(..) => 4,
+            Instr::Equals(..) => 4,
+            Instr::Halt => 1,
+        }
+    }
+
+    fn execute(&self, mem: &mut [isize], input: isize) -> Option<ExecResult> {
+        match self {
+            Instr::Add(v1, v2, m) => {
+                let op1 = v1.fetch(&mem);
+                let op2 = v2.fetch(&mem);
+
+                mem[*m] = op1 + op2;
+
+                None
+            }
+
+            Instr::Mul(v1, v2, m) => {
+                let op1 = v1.fetch(&mem);
+                let op2 = v2.fetch(&mem);
+
+                mem[*m] = op1 * op2;
+
+                None
+            }
+
+            Instr::Input(m) => {
+                mem[*m] = input;
+
+                None
+            }
+
+            Instr::Output(v) => Some(ExecResult::Output(v.fetch(&mem))),
+
+            Instr::JumpIfTrue(v1, v2) => {
+                if v1.fetch(&mem) != 0 {
+                    Some(ExecResult::Jump(v2.fetch(&mem) as usize))
+                } else {
+                    None
+                }
+            }
+
+            Instr::JumpIfFalse(v1, v2) => {
+                if v1.fetch(&mem) == 0 {
+                    Some(ExecResult::Jump(v2.fetch(&mem) as usize))
+                } else {
+                    None
+                }
+            }
+
+            Instr::LessThan(v1, v2, m) => {
+                let op1 = v1.fetch(&mem);
+                let op2 = v2.fetch(&mem);
+
+                mem[*m] = if op1 < op2 { 1 } else { 0 };
+                None
+            }
+
+            Instr::Equals(v1, v2, m) => {
+                let op1 = v1.fetch(&mem);
+                let op2 = v2.fetch(&mem);
+
+                mem[*m] = if op1 == op2 { 1 } else { 0 };
+                None
+            }
+
+            Instr::Halt => Some(ExecResult::Halt),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Value {
+    Immediate(isize),
+    Position(usize),
+}
+
+impl Value {
+    fn fetch(&self, mem: &[isize]) -> isize {
+        match self {
+            Value::Immediate(v) => *v,
+            Value::Position(a) => mem[*a],
+        }
+    }
+}
+
+pub fn parse_instr(mem: &[isize]) -> Instr {
+    let instr = mem[0];
+
+    let opcode = instr % 100;
+    let mode1 = (instr / 100) % 10;
+    let mode2 = (instr / 1000) % 10;
+    let mode3 = (instr / 10000) % 10;
+
+    match opcode {
+        1 => {
+            let v1 = if mode1 == 1 {
+                Value::Immediate(mem[1])
+            } else {
+                Value::Position(mem[1] as usize)
+            };
+            let v2 = if mode2 == 1 {
+                Value::Immediate(mem[2])
+            } else {
+                Value::Position(mem[2] as usize)
+            };
+
+            Instr::Add(v1, v2, mem[3] as usize)
+        }
+        2 => {
+            let v1 = if mode1 == 1 {
+                Value::Immediate(mem[1])
+            } else {
+                Value::Position(mem[1] as usize)
+            };
+            let v2 = if mode2 == 1 {
+                Value::Immediate(mem[2])
+            } else {
+                Value::Position(mem[2] as usize)
+            };
+
+            Instr::Mul(v1, v2, mem[3] as usize)
+        }
+        3 => Instr::Input(mem[1] as usize),
+        4 => {
+            let v1 = if mode1 == 1 {
+                Value::Immediate(mem[1])
+            } else {
+                Value::Position(mem[1] as usize)
+            };
+            Instr::Output(v1)
+        }
+        5 => {
+            let v1 = if mode1 == 1 {
+                Value::Immediate(mem[1])
+            } else {
+                Value::Position(mem[1] as usize)
+            };
+            let v2 = if mode2 == 1 {
+                Value::Immediate(mem[2])
+            } else {
+                Value::Position(mem[2] as usize)
+            };
+
+            Instr::JumpIfTrue(v1, v2)
+        }
+        6 => {
+            let v1 = if mode1 == 1 {
+                Value::Immediate(mem[1])
+            } else {
+                Value::Position(mem[1] as usize)
+            };
+            let v2 = if mode2 == 1 {
+                Value::Immediate(mem[2])
+            } else {
+                Value::Position(mem[2] as usize)
+            };
+            Instr::JumpIfFalse(v1, v2)
+        }
+        7 => {
+            let v1 = if mode1 == 1 {
+                Value::Immediate(mem[1])
+            } else {
+                Value::Position(mem[1] as usize)
+            };
+            let v2 = if mode2 == 1 {
+                Value::Immediate(mem[2])
+            } else {
+                Value::Position(mem[2] as usize)
+            };
+            // let v3 = if mode3 == 1 { Value::Immediate(mem[3]) } else { Value::Position(mem[3] as usize) };
+
+            Instr::LessThan(v1, v2, mem[3] as usize)
+        }
+        8 => {
+            let v1 = if mode1 == 1 {
+                Value::Immediate(mem[1])
+            } else {
+                Value::Position(mem[1] as usize)
+            };
+            let v2 = if mode2 == 1 {
+                Value::Immediate(mem[2])
+            } else {
+                Value::Position(mem[2] as usize)
+            };
+            // let v3 = if mode3 == 1 { Value::Immediate(mem[3]) } else { Value::Position(mem[3] as usize) };
+
+            Instr::Equals(v1, v2, mem[3] as usize)
+        }
+
+        99 => Instr::Halt,
+        v => unimplemented!("unknown opcode {}", v),
+    }
+}
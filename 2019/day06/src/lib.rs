@@ -0,0 +1,72 @@
+use std::error::Error;
+
+use aoc_digraph::Digraph;
+use aoc_error::ParseError;
+
+const YOU: &str = "YOU";
+const SAN: &str = "SAN";
+
+/// The orbits, with an edge from each object to the object it orbits.
+pub type Orbits = Digraph<String, usize>;
+
+pub fn parse_input(input: &str) -> Result<Orbits, ParseError> {
+    let mut result = Digraph::new();
+
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let (orbitee, orbiter) = line
+            .split_once(')')
+            .ok_or_else(|| ParseError::at_span(input, line, "expected an orbit, e.g., `COM)B'"))?;
+        result.add_edge(orbiter.into(), orbitee.into(), 1);
+    }
+
+    Ok(result)
+}
+
+/// The total number of direct and indirect orbits.
+pub fn part1(input: &str) -> Result<usize, Box<dyn Error>> {
+    let sums = parse_input(input)?
+        .path_sums()
+        .ok_or("an object indirectly orbits itself")?;
+
+    Ok(sums.values().sum())
+}
+
+/// The number of orbital transfers needed to reach the object SAN is orbiting.
+pub fn part2(input: &str) -> Result<usize, Box<dyn Error>> {
+    Ok(find_min_path(&parse_input(input)?).ok_or("no path to SAN")?)
+}
+
+fn find_min_path(orbits: &Orbits) -> Option<usize> {
+    let you = orbits.distances(&YOU.into());
+    let san = orbits.distances(&SAN.into());
+
+    // Neither YOU nor SAN count as a transfer.
+    you.iter()
+        .filter_map(|(object, d)| Some(d + san.get(object)?))
+        .min()
+        .map(|len| len - 2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\n";
+        assert_eq!(part1(input).unwrap(), 42);
+
+        let input = format!("{}K)YOU\nI)SAN\n", input);
+        assert_eq!(part2(&input).unwrap(), 4);
+
+        let input = "COM)A\nA)B\nB)A\n";
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "an object indirectly orbits itself"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+mod sif;
+
+pub use crate::sif::{Image, SifError, BLACK, TRANSPARENT, WHITE};
+
+pub const WIDTH: usize = 25;
+pub const HEIGHT: usize = 6;
+
+pub fn parse_image(input: &str) -> Result<Image, SifError> {
+    Image::parse(input, WIDTH, HEIGHT)
+}
+
+/// The number of 1 digits multiplied by the number of 2 digits on the layer
+/// with the fewest 0 digits.
+pub fn part1(input: &str) -> Result<usize, SifError> {
+    let histograms = parse_image(input)?.histograms();
+    let counts = histograms.iter().min_by_key(|counts| counts[0]).unwrap();
+
+    Ok(counts[1] * counts[2])
+}
+
+/// The decoded image.
+pub fn part2(input: &str) -> Result<String, SifError> {
+    parse_image(input)?.render()
+}
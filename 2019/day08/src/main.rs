@@ -1,64 +1,43 @@
 use std::error::Error;
-use std::fs::File;
-use std::io::prelude::*;
+use std::fs;
+use std::path::Path;
 
-const WIDTH: usize = 25;
-const HEIGHT: usize = 6;
+use aoc_output::Output;
+use aoc_visualize::ImageFormat;
 
-fn read_input() -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
-    let mut buf = String::new();
-    File::open("input")?.read_to_string(&mut buf)?;
+use day08_2019::{parse_image, part1, part2};
 
-    let layers = buf
-        .as_bytes()
-        .chunks_exact(WIDTH * HEIGHT)
-        .map(|layer| layer.iter().map(|p| p - '0' as u8).collect::<Vec<_>>())
-        .collect::<Vec<_>>();
-
-    Ok(layers)
-}
+const USAGE: &str = "Usage: day08-2019 [--export <file.pbm|file.pgm|file.ppm>]";
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let layers = read_input()?;
-
-    let layer = layers
-        .iter()
-        .map(|layer| layer.iter().filter(|p| **p == 0).collect::<Vec<_>>().len())
-        .enumerate()
-        .min_by(|(_, a_count), (_, b_count)| a_count.cmp(b_count))
-        .unwrap()
-        .0;
-
-    let mut ones = 0;
-    let mut twos = 0;
-    for p in &layers[layer] {
-        match p {
-            1 => ones += 1,
-            2 => twos += 1,
-            _ => continue,
-        }
-    }
-    println!("part 1: {:?}", ones * twos);
-
-    println!("part 2:");
-    for y in 0..HEIGHT {
-        for x in 0..WIDTH {
-            let coord = y * WIDTH + x;
-            for layer in &layers {
-                match layer[coord] {
-                    0 => {
-                        print!(" ");
-                        break;
-                    }
-                    1 => {
-                        print!("#");
-                        break;
-                    }
-                    _ => continue,
-                }
-            }
-        }
-        println!("");
+    let (format, args) = aoc_output::args();
+    let mut output = Output::with_format(2019, 8, format);
+
+    let export = match args.as_slice() {
+        [] => None,
+        [flag, path] if flag == "--export" => Some(Path::new(path)),
+        _ => return Err(USAGE.into()),
+    };
+
+    let input = fs::read_to_string("input")?;
+
+    output.part(1, part1(&input)?);
+    output.image(2, &part2(&input)?);
+
+    if let Some(path) = export {
+        let format = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(ImageFormat::from_extension)
+            .ok_or_else(|| {
+                format!(
+                    "cannot export to `{}': the file must end in .pbm, .pgm or .ppm",
+                    path.display()
+                )
+            })?;
+
+        let frame = parse_image(&input)?.frame()?;
+        fs::write(path, aoc_visualize::encode(&frame, format))?;
     }
 
     Ok(())
@@ -0,0 +1,252 @@
+//! The Space Image Format, where an image is a stack of layers of digits.
+//!
+//! Each digit is the colour of one pixel: 0 is black, 1 is white and 2 is
+//! transparent. Layers are stored one after another, top layer first, each
+//! one row after another.
+
+use std::error::Error;
+
+use aoc_error::ParseError;
+use aoc_visualize::{Frame, Rgb};
+use derive_more::Display;
+
+pub const BLACK: u8 = 0;
+pub const WHITE: u8 = 1;
+pub const TRANSPARENT: u8 = 2;
+
+#[derive(Debug, Display, Eq, PartialEq)]
+pub enum SifError {
+    #[display(
+        fmt = "Invalid dimensions {}x{}; images must be at least 1x1 and small enough to address.",
+        _0,
+        _1
+    )]
+    Dimensions(usize, usize),
+
+    #[display(fmt = "{}", _0)]
+    Parse(ParseError),
+
+    #[display(fmt = "Invalid digit {} at position {}.", _0, _1)]
+    Digit(u8, usize),
+
+    #[display(
+        fmt = "Expected a whole number of {}-pixel layers, but found {} pixels.",
+        layer_size,
+        len
+    )]
+    PartialLayer { layer_size: usize, len: usize },
+
+    #[display(fmt = "Expected at least one layer.")]
+    Empty,
+
+    #[display(fmt = "Unknown colour {} at row {}, column {}.", colour, y, x)]
+    Colour { colour: u8, y: usize, x: usize },
+}
+
+impl Error for SifError {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// Every layer, one after another.
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Parse an image of the given size, ignoring trailing whitespace.
+    pub fn parse(input: &str, width: usize, height: usize) -> Result<Self, SifError> {
+        let pixels = input
+            .trim_end()
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                    let message = format!("invalid pixel `{}'; expected a digit", c);
+                    SifError::Parse(ParseError::at(input, i, message))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Image::from_pixels(width, height, pixels)
+    }
+
+    /// An image from the digits of every layer, one after another.
+    pub fn from_pixels(width: usize, height: usize, pixels: Vec<u8>) -> Result<Self, SifError> {
+        let layer_size = width
+            .checked_mul(height)
+            .filter(|&size| size > 0)
+            .ok_or(SifError::Dimensions(width, height))?;
+
+        if let Some(i) = pixels.iter().position(|&p| p > 9) {
+            Err(SifError::Digit(pixels[i], i))
+        } else if pixels.is_empty() {
+            Err(SifError::Empty)
+        } else if !pixels.len().is_multiple_of(layer_size) {
+            Err(SifError::PartialLayer {
+                layer_size,
+                len: pixels.len(),
+            })
+        } else {
+            Ok(Image {
+                width,
+                height,
+                pixels,
+            })
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The layers of the image, from top to bottom.
+    pub fn layers(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.pixels.chunks(self.width * self.height)
+    }
+
+    /// How many times each digit appears on each layer.
+    pub fn histograms(&self) -> Vec<[usize; 10]> {
+        self.layers()
+            .map(|layer| {
+                let mut counts = [0; 10];
+                for &p in layer {
+                    counts[p as usize] += 1;
+                }
+                counts
+            })
+            .collect()
+    }
+
+    /// Flatten the image into a single layer, where each pixel is the colour
+    /// of the first layer that is not transparent there.
+    pub fn compose(&self) -> Result<Image, SifError> {
+        let layer_size = self.width * self.height;
+        let mut composed = vec![TRANSPARENT; layer_size];
+
+        for (i, pixel) in composed.iter_mut().enumerate() {
+            let colour = self
+                .layers()
+                .map(|layer| layer[i])
+                .find(|&p| p != TRANSPARENT)
+                .unwrap_or(TRANSPARENT);
+
+            if colour != BLACK && colour != WHITE && colour != TRANSPARENT {
+                return Err(SifError::Colour {
+                    colour,
+                    y: i / self.width,
+                    x: i % self.width,
+                });
+            }
+
+            *pixel = colour;
+        }
+
+        Image::from_pixels(self.width, self.height, composed)
+    }
+
+    /// The image in the Space Image Format.
+    pub fn encode(&self) -> String {
+        self.pixels.iter().map(|&p| char::from(b'0' + p)).collect()
+    }
+
+    /// The composed image as text, with `#` for white pixels.
+    pub fn render(&self) -> Result<String, SifError> {
+        let composed = self.compose()?;
+        let mut image = String::with_capacity((self.width + 1) * self.height);
+
+        for row in composed.pixels.chunks(self.width) {
+            image.extend(row.iter().map(|&p| if p == WHITE { '#' } else { ' ' }));
+            image.push('\n');
+        }
+
+        Ok(image)
+    }
+
+    /// Draw the composed image, one cell per pixel. Pixels that are
+    /// transparent on every layer are grey.
+    pub fn frame(&self) -> Result<Frame, SifError> {
+        let composed = self.compose()?;
+        let mut frame = Frame::new(self.width, self.height);
+
+        for (i, &p) in composed.pixels.iter().enumerate() {
+            let (glyph, colour) = match p {
+                BLACK => (' ', Rgb::BLACK),
+                WHITE => ('#', Rgb::WHITE),
+                _ => ('.', Rgb::GREY),
+            };
+
+            frame.set(i % self.width, i / self.width, glyph, colour);
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_visualize::{encode, ImageFormat};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let image = Image::parse("123456789012\n", 3, 2).unwrap();
+        assert_eq!(
+            image.layers().collect::<Vec<_>>(),
+            vec![&[1, 2, 3, 4, 5, 6], &[7, 8, 9, 0, 1, 2]]
+        );
+        assert_eq!(image.histograms()[1][..3], [1, 1, 1]);
+
+        assert_eq!(
+            Image::parse("1234567", 3, 2),
+            Err(SifError::PartialLayer {
+                layer_size: 6,
+                len: 7
+            })
+        );
+        match Image::parse("12x", 3, 1) {
+            Err(SifError::Parse(e)) => {
+                assert_eq!(e.message(), "invalid pixel `x'; expected a digit");
+                assert_eq!(e.location().unwrap().column, 3);
+            }
+            result => panic!("expected a parse error, got {:?}", result),
+        }
+        assert_eq!(Image::parse("", 3, 2), Err(SifError::Empty));
+        assert_eq!(Image::parse("1", 0, 1), Err(SifError::Dimensions(0, 1)));
+        assert_eq!(
+            Image::parse("1", usize::MAX, 2),
+            Err(SifError::Dimensions(usize::MAX, 2))
+        );
+        assert_eq!(
+            Image::parse("31", 2, 1).unwrap().compose(),
+            Err(SifError::Colour {
+                colour: 3,
+                y: 0,
+                x: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = "0222112222120000";
+        let image = Image::parse(input, 2, 2).unwrap();
+        assert_eq!(image.encode(), input);
+        assert_eq!(Image::parse(&image.encode(), 2, 2).unwrap(), image);
+
+        let composed = image.compose().unwrap();
+        assert_eq!(composed.encode(), "0110");
+        assert_eq!(image.render().unwrap(), " #\n# \n");
+        assert_eq!(
+            encode(&image.frame().unwrap(), ImageFormat::Pgm),
+            b"P5\n2 2\n255\n\x00\xff\xff\x00"
+        );
+
+        let decoded = Image::parse(&composed.encode(), 2, 2).unwrap();
+        assert_eq!(decoded.render().unwrap(), image.render().unwrap());
+    }
+}
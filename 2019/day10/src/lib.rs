@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::f64;
+
+use aoc_error::ParseError;
+use aoc_grid::{Grid, Point};
+use num::integer::gcd;
+
+pub type Asteroids = Grid<bool>;
+
+/// The number of asteroids visible from the best monitoring station.
+pub fn part1(input: &str) -> Result<usize, Box<dyn Error>> {
+    let asteroids = parse_asteroids(input)?;
+
+    let visible = count_visible(&asteroids);
+    let (_, count) = visible.iter().max_by_key(|(_, count)| *count).unwrap();
+
+    Ok(*count)
+}
+
+/// The 200th asteroid to be vapourized from the best monitoring station.
+pub fn part2(input: &str) -> Result<isize, Box<dyn Error>> {
+    let asteroids = parse_asteroids(input)?;
+
+    let visible = count_visible(&asteroids);
+    let (p, _) = visible.iter().max_by_key(|(_, count)| *count).unwrap();
+
+    let q = vapourize(asteroids, *p);
+
+    Ok(q.x() * 100 + q.y())
+}
+
+pub fn parse_asteroids(input: &str) -> Result<Asteroids, ParseError> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+fn count_visible(asteroids: &Asteroids) -> HashMap<Point, usize> {
+    let mut visible = HashMap::new();
+
+    for p in asteroids.points() {
+        if !asteroids[p] {
+            continue;
+        }
+
+        for q in asteroids.points() {
+            if p == q {
+                continue;
+            }
+
+            if asteroids[q] && is_visible(asteroids, p, q) {
+                *visible.entry(p).or_default() += 1;
+            }
+        }
+    }
+
+    visible
+}
+
+fn is_visible(asteroids: &Asteroids, from: Point, to: Point) -> bool {
+    assert_ne!(from, to);
+
+    let dx = to.x() - from.x();
+    let dy = to.y() - from.y();
+    let g = gcd(dx, dy);
+
+    let m = Point::new(dx / g, dy / g);
+
+    let mut p = from + m;
+
+    loop {
+        if p == to {
+            break true;
+        }
+
+        if asteroids[p] {
+            break false;
+        }
+
+        p += m;
+    }
+}
+
+fn vapourize(mut asteroids: Asteroids, p: Point) -> Point {
+    // Find all asteroids (other then the one at p) and compute their slopes and
+    // their angle to the point p.
+    let mut slopes = asteroids
+        .points()
+        .filter_map(|q| {
+            if p == q || !asteroids[q] {
+                return None;
+            }
+
+            let dx = q.x() - p.x();
+            let dy = q.y() - p.y();
+            let g = gcd(dx, dy);
+
+            let m = Point::new(dx / g, dy / g);
+
+            // Shift the angle so that PI/2 is the minimum angle. This way,
+            // sorting by angle will results in having the asteroids "up" first.
+            let mut theta = f64::atan2(dy as f64, dx as f64);
+            if theta < -f64::consts::FRAC_PI_2 {
+                theta += f64::consts::PI + f64::consts::PI;
+            }
+
+            Some((m, theta))
+        })
+        .collect::<Vec<_>>();
+
+    slopes.sort_by(|(_, alpha), (_, beta)| {
+        // f64::atan2() has a range of (-PI, PI) and will never produce a NAN.
+        PartialOrd::partial_cmp(&alpha, &beta).unwrap()
+    });
+
+    let mut last_slope = Point::new(0, 0);
+    let mut count = 0;
+    for (m, _) in slopes.iter().cycle() {
+        if last_slope == *m {
+            continue;
+        }
+
+        if let Some(q) = asteroids.ray(p, *m).find(|q| asteroids[*q]) {
+            count += 1;
+            asteroids[q] = false;
+
+            if count == 200 {
+                return q;
+            }
+        }
+
+        last_slope = *m;
+    }
+
+    unreachable!();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = ".#..#\n.....\n#####\n....#\n...##\n";
+        assert_eq!(part1(input).unwrap(), 8);
+    }
+}
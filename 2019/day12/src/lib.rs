@@ -0,0 +1,105 @@
+use std::error::Error;
+
+use aoc_cycle::brent;
+use aoc_error::ParseError;
+use aoc_geometry::Point3;
+use aoc_math::lcm;
+use aoc_parse::{parse_lines, signed};
+use combine::parser::char::{char, string};
+use combine::Parser;
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct Moon {
+    position: Point3,
+    velocity: Point3,
+}
+
+impl Moon {
+    fn energy(&self) -> isize {
+        self.position.manhattan() * self.velocity.manhattan()
+    }
+}
+
+pub fn parse_moons(input: &str) -> Result<Vec<Moon>, ParseError> {
+    let moon = (
+        string("<x=").with(signed()),
+        string(", y=").with(signed()),
+        string(", z=").with(signed()).skip(char('>')),
+    )
+        .map(|(x, y, z)| Moon {
+            position: Point3::new(x, y, z),
+            velocity: Default::default(),
+        });
+
+    parse_lines(moon, input)
+}
+
+/// The total energy in the system after 1000 steps.
+pub fn part1(input: &str) -> Result<isize, Box<dyn Error>> {
+    let mut moons = parse_moons(input)?;
+
+    for _ in 0..1000 {
+        simulate(&mut moons);
+    }
+
+    Ok(moons.iter().map(Moon::energy).sum::<isize>())
+}
+
+/// The number of steps until the system returns to a previous state.
+pub fn part2(input: &str) -> Result<u64, Box<dyn Error>> {
+    let moons = parse_moons(input)?;
+
+    // Each axis moves independently of the others and every step can be
+    // undone, so each axis repeats from the first step and the whole system
+    // repeats once every axis does at the same time.
+    let period = |axis: usize| {
+        let state = moons
+            .iter()
+            .map(|m| (m.position[axis], m.velocity[axis]))
+            .collect::<Vec<_>>();
+
+        brent(state, |state| simulate_axis(state)).length as u64
+    };
+
+    Ok(lcm(period(0), lcm(period(1), period(2))))
+}
+
+fn simulate(moons: &mut [Moon]) {
+    for i in 0..moons.len() {
+        for j in 0..moons.len() {
+            let gravity = (moons[j].position - moons[i].position).signum();
+            moons[i].velocity += gravity;
+        }
+    }
+
+    for moon in moons {
+        moon.position += moon.velocity;
+    }
+}
+
+/// Simulate a single axis, given the position and velocity of each moon along
+/// it.
+fn simulate_axis(state: &[(isize, isize)]) -> Vec<(isize, isize)> {
+    state
+        .iter()
+        .map(|&(position, velocity)| {
+            let gravity = state
+                .iter()
+                .map(|(other, _)| (other - position).signum())
+                .sum::<isize>();
+
+            (position + velocity + gravity, velocity + gravity)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part2() {
+        let input = "<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>\n";
+        assert_eq!(part2(input).unwrap(), 2772);
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::iter::once;
+
+use aoc_digraph::Digraph;
+use aoc_error::ParseError;
+use aoc_parse::{parse_lines, unsigned};
+use combine::parser::char::{char, string, upper};
+use combine::{many1, sep_by1, Parser};
+
+/// The ore required to produce one fuel.
+pub fn part1(input: &str) -> Result<i64, Box<dyn Error>> {
+    let rxns = parse_reactions(input)?;
+
+    Ok(ore_per_n_fuel(1, &rxns))
+}
+
+/// The most fuel that can be produced with a trillion ore.
+pub fn part2(input: &str) -> Result<i64, Box<dyn Error>> {
+    let rxns = parse_reactions(input)?;
+
+    let mut lower_bound = 0;
+    let mut upper_bound = 1000000000000i64;
+
+    while lower_bound < upper_bound {
+        let midpoint = (upper_bound + lower_bound + 1 ) / 2;
+
+        let cost = ore_per_n_fuel(midpoint, &rxns);
+
+        if cost < 1000000000000 {
+            lower_bound = midpoint;
+        } else {
+            upper_bound = midpoint - 1;
+        }
+
+    }
+
+    Ok(lower_bound)
+}
+
+fn ore_per_n_fuel(fuel: i64, rxns: &Reactions) -> i64 {
+    let mut required = HashMap::<&str, i64>::new();
+    required.insert("FUEL", fuel);
+
+    // Every compound that needs a compound comes before it, so all of the
+    // demand for a compound is known by the time it is reached.
+    for compound in &rxns.order {
+        let deficit = required.get(compound.as_str()).copied().unwrap_or(0);
+        if deficit <= 0 || compound == "ORE" {
+            continue;
+        }
+
+        let produced = rxns.produced[compound];
+        let mult = (deficit + produced - 1) / produced;
+
+        for (req, n) in rxns.graph.successors(compound) {
+            *required.entry(req).or_default() += mult * n;
+        }
+    }
+
+    required.get("ORE").copied().unwrap_or(0)
+}
+
+/// The reactions, with an edge from each compound to each compound needed to
+/// produce it, weighted by how many are needed.
+#[derive(Clone, Debug)]
+pub struct Reactions {
+    graph: Digraph<String, i64>,
+    /// How much of each compound a reaction produces.
+    produced: HashMap<String, i64>,
+    /// Each compound, before any compound it needs.
+    order: Vec<String>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Compound(i64, String);
+
+pub fn parse_reactions(input: &str) -> Result<Reactions, ParseError> {
+    let compound = || (unsigned().skip(char(' ')), many1(upper())).map(|(n, s)| Compound(n, s));
+    let reaction = (
+        sep_by1::<Vec<_>, _, _, _>(compound(), string(", ")).skip(string(" => ")),
+        compound(),
+    );
+
+    let mut graph = Digraph::new();
+    let mut produced = HashMap::new();
+
+    for (left_compounds, result) in parse_lines(reaction, input)? {
+        for Compound(n, req) in left_compounds {
+            graph.add_edge(result.1.clone(), req, n);
+        }
+        produced.insert(result.1, result.0);
+    }
+
+    let missing = graph
+        .vertices()
+        .map(String::as_str)
+        .chain(once("FUEL"))
+        .find(|&compound| compound != "ORE" && !produced.contains_key(compound));
+    if let Some(compound) = missing {
+        return Err(ParseError::new(format!(
+            "no reaction produces {}",
+            compound
+        )));
+    }
+
+    let order = graph
+        .toposort()
+        .ok_or_else(|| ParseError::new("the reactions have a cyclic dependency"))?;
+
+    Ok(Reactions {
+        graph,
+        produced,
+        order,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "10 ORE => 10 A\n\
+                     1 ORE => 1 B\n\
+                     7 A, 1 B => 1 C\n\
+                     7 A, 1 C => 1 D\n\
+                     7 A, 1 D => 1 E\n\
+                     7 A, 1 E => 1 FUEL\n";
+        assert_eq!(part1(input).unwrap(), 31);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "157 ORE => 5 NZVS\n\
+                     165 ORE => 6 DCFZ\n\
+                     44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL\n\
+                     12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ\n\
+                     179 ORE => 7 PSHF\n\
+                     177 ORE => 5 HKGWZ\n\
+                     7 DCFZ, 7 PSHF => 2 XJWVT\n\
+                     165 ORE => 2 GPVTF\n\
+                     3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT\n";
+        assert_eq!(part1(input).unwrap(), 13312);
+        assert_eq!(part2(input).unwrap(), 82892753);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = parse_reactions("10 ORE => 10 A\n7 A 1 B => 1 FUEL\n").unwrap_err();
+        assert_eq!(e.location().unwrap().line, 2);
+
+        let e = parse_reactions("10 ORE => 10 A\n7 A, 1 B => 1 FUEL\n").unwrap_err();
+        assert_eq!(e.message(), "no reaction produces B");
+    }
+}
@@ -0,0 +1,181 @@
+use std::error::Error;
+use std::iter::once;
+use std::mem::swap;
+use std::ops::Range;
+
+use aoc_error::ParseError;
+
+/// Parse a signal of at least eight digits.
+pub fn parse_signal(input: &str) -> Result<Vec<i32>, ParseError> {
+    let signal = input
+        .trim_end()
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as i32)
+                .ok_or_else(|| ParseError::at(input, i, format!("expected a digit, got `{}'", c)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if signal.len() < 8 {
+        return Err(ParseError::new("the signal must have at least 8 digits"));
+    }
+
+    Ok(signal)
+}
+
+/// The first eight digits after 100 phases of FFT.
+pub fn part1(input: &str) -> Result<String, Box<dyn Error>> {
+    let mut input = parse_signal(input)?;
+    let mut next = vec![0; input.len()];
+    for _ in 0..100 {
+        fft(&input, &mut next);
+        swap(&mut input, &mut next);
+    }
+
+    Ok(string_from_digits(&input[..8]))
+}
+
+/// The eight digit message embedded in the real signal.
+pub fn part2(input: &str) -> Result<String, Box<dyn Error>> {
+    let signal = parse_signal(input)?;
+
+    let mut input = Vec::with_capacity(signal.len() * 10000);
+    for _ in 0..10000 {
+        input.extend(&signal);
+    }
+
+    let mut next = vec![0; input.len()];
+
+    for _ in 0..100 {
+        fft(&input, &mut next);
+        swap(&mut input, &mut next);
+    }
+
+    let offset = signal.iter().take(7).fold(0, |acc, n| acc * 10 + n) as usize;
+
+    let msg = input
+        .get(offset..offset + 8)
+        .ok_or_else(|| format!("the message offset {} is past the end of the signal", offset))?;
+
+    Ok(string_from_digits(msg))
+}
+
+fn string_from_digits(digits: &[i32]) -> String {
+    digits
+        .iter()
+        .map(|d| (*d as u8 + '0' as u8) as char)
+        .collect()
+}
+
+struct Ranges {
+    /// Position in output vector
+    i: usize,
+
+    /// Length of output vector
+    len: usize,
+
+    /// The internal counter for which ranges we generate.
+    k: usize,
+
+    /// Whether we are generating a positive range or a negative range.
+    pos: bool,
+
+    /// The next range that we will yield.
+    range: Option<Range<usize>>,
+}
+
+impl Ranges {
+    fn new(i: usize, len: usize) -> Ranges {
+        Ranges {
+            i,
+            len,
+            k: 0,
+            pos: true,
+            range: Ranges::range(i, 0, true, len),
+        }
+    }
+
+    fn range(i: usize, k: usize, pos: bool, len: usize) -> Option<Range<usize>> {
+        let mut range = if pos {
+            ((4 * k + 1) * (i + 1) - 1)..((4 * k + 2) * (i + 1) - 1)
+        } else {
+            ((4 * k + 3) * (i + 1) - 1)..((4 * k + 4) * (i + 1) - 1)
+        };
+
+        if range.start >= len {
+            None
+        } else {
+            if range.end > len {
+                range.end = len;
+            }
+            Some(range)
+        }
+    }
+}
+
+impl Iterator for Ranges {
+    type Item = (Range<usize>, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(range) = self.range.take() {
+            let item = Some((range, if self.pos { 1 } else { -1 }));
+
+            self.pos = !self.pos;
+            if self.pos {
+                self.k += 1;
+            }
+
+            self.range = Ranges::range(self.i, self.k, self.pos, self.len);
+
+            item
+        } else {
+            None
+        }
+    }
+}
+
+fn fft(input: &[i32], output: &mut [i32]) {
+    let partial_sums: Vec<_> = once(0)
+        .chain(input.iter().scan(0, |sum, x| {
+            *sum += x;
+            Some(*sum)
+        }))
+        .collect();
+
+    for i in 0..input.len() {
+        let mut sum = 0;
+        for (range, mult) in Ranges::new(i, input.len()) {
+            let psum = partial_sums[range.end] - partial_sums[range.start];
+            sum += mult * psum;
+        }
+        output[i] = sum.abs() % 10;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::fft;
+    use std::mem::swap;
+
+    #[test]
+    fn test() {
+        let mut input = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut output = vec![0; input.len()];
+
+        fft(&input, &mut output);
+        assert_eq!(output, [4, 8, 2, 2, 6, 1, 5, 8]);
+
+        swap(&mut input, &mut output);
+        fft(&input, &mut output);
+        assert_eq!(output, [3, 4, 0, 4, 0, 4, 3, 8]);
+
+        swap(&mut input, &mut output);
+        fft(&input, &mut output);
+        assert_eq!(output, [0, 3, 4, 1, 5, 5, 1, 8]);
+
+        swap(&mut input, &mut output);
+        fft(&input, &mut output);
+        assert_eq!(output, [0, 1, 0, 2, 9, 4, 9, 8]);
+    }
+}
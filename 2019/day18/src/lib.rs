@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::ops::{Index, IndexMut};
+
+use aoc_error::ParseError;
+use aoc_grid::{Grid, Point, DOWN, LEFT, ORTHOGONAL, RIGHT, UP};
+use aoc_search::{dijkstra, distances};
+
+pub struct Map {
+    tiles: Grid<Tile>,
+    keys: HashMap<usize, Point>,
+    entrance: Vec<Point>,
+}
+
+impl Map {
+    pub fn neighbours(&self, p: Point, keys: usize) -> impl Iterator<Item = Point> + '_ {
+        self.tiles
+            .neighbours4(p)
+            .filter(move |q| self.tiles[*q].accessible(keys))
+    }
+}
+
+impl Index<Point> for Map {
+    type Output = Tile;
+
+    fn index(&self, idx: Point) -> &Self::Output {
+        &self.tiles[idx]
+    }
+}
+
+impl IndexMut<Point> for Map {
+    fn index_mut(&mut self, idx: Point) -> &mut Self::Output {
+        &mut self.tiles[idx]
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Tile {
+    Wall,
+    Floor,
+    Entrance,
+    Key(usize),
+    Door(usize),
+}
+
+impl Tile {
+    fn accessible(&self, keys: usize) -> bool {
+        match self {
+            Tile::Wall => false,
+            Tile::Door(mask) => mask & keys != 0,
+            _ => true,
+        }
+    }
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        let tile = match c {
+            '#' => Tile::Wall,
+            '.' => Tile::Floor,
+            '@' => Tile::Entrance,
+            'a'..='z' => {
+                let k = (c as u8 - b'a') as usize;
+                Tile::Key(2 << k)
+            }
+            'A'..='Z' => {
+                let k = (c as u8 - b'A') as usize;
+                Tile::Door(2 << k)
+            }
+            _ => return None,
+        };
+
+        Some(tile)
+    }
+}
+
+/// The fewest steps needed to collect every key.
+pub fn part1(input: &str) -> Result<usize, Box<dyn Error>> {
+    let map = parse_map(input)?;
+
+    find_keys(&map).ok_or_else(|| "the keys cannot all be collected".into())
+}
+
+/// The fewest steps needed to collect every key with four robots.
+pub fn part2(input: &str) -> Result<usize, Box<dyn Error>> {
+    let mut map = parse_map(input)?;
+
+    let entrance = map.entrance[0];
+    map.entrance = vec![
+        entrance + UP + LEFT,
+        entrance + UP + RIGHT,
+        entrance + DOWN + LEFT,
+        entrance + DOWN + RIGHT,
+    ];
+
+    // We don't actually need to mark the map entrances as Tile::Entrance since
+    // we keep track of them separately.
+    map[entrance] = Tile::Wall;
+    for direction in &ORTHOGONAL {
+        map[entrance + *direction] = Tile::Wall;
+    }
+
+    find_keys(&map).ok_or_else(|| "the keys cannot all be collected".into())
+}
+
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let tiles = Grid::parse(input, Tile::from_char)?;
+    let mut entrance = None;
+    let mut keys = HashMap::new();
+
+    for (p, tile) in tiles.iter() {
+        match tile {
+            Tile::Key(k) => {
+                keys.insert(*k, p);
+            }
+            Tile::Entrance => entrance = Some(p),
+            _ => (),
+        }
+    }
+
+    Ok(Map {
+        tiles,
+        keys,
+        entrance: vec![entrance.ok_or_else(|| ParseError::new("the map has no entrance"))?],
+    })
+}
+
+fn find_keys(map: &Map) -> Option<usize> {
+    let all_keys = map.keys.keys().fold(0, |all, k| all | k);
+
+    // Each step moves one robot straight to a key it has not collected yet.
+    let path = dijkstra(
+        (map.entrance.clone(), 0),
+        |(robots, keys)| {
+            let mut next = Vec::new();
+
+            for (i, robot) in robots.iter().enumerate() {
+                for (key, p, steps) in new_keys(map, *robot, *keys) {
+                    let mut robots = robots.clone();
+                    robots[i] = p;
+                    next.push(((robots, keys | key), steps));
+                }
+            }
+
+            next
+        },
+        |(_, keys)| *keys == all_keys,
+    )?;
+
+    Some(path.cost)
+}
+
+/// The keys not in `keys` that can be reached from `from` without picking up
+/// any other key on the way, along with where they are and how far away.
+fn new_keys(map: &Map, from: Point, keys: usize) -> Vec<(usize, Point, usize)> {
+    let is_new_key = |p: Point| match map[p] {
+        Tile::Key(k) if keys & k == 0 => Some(k),
+        _ => None,
+    };
+
+    distances(from, |p| {
+        if *p != from && is_new_key(*p).is_some() {
+            Vec::new()
+        } else {
+            map.neighbours(*p, keys).collect()
+        }
+    })
+    .into_iter()
+    .filter_map(|(p, steps)| is_new_key(p).map(|k| (k, p, steps)))
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("#########\n#b.A.@.a#\n#########\n").unwrap(), 8);
+        assert_eq!(
+            part1(concat!(
+                "########################\n",
+                "#...............b.C.D.f#\n",
+                "#.######################\n",
+                "#.....@.a.B.c.d.A.e.F.g#\n",
+                "########################\n",
+            ))
+            .unwrap(),
+            132
+        );
+        assert_eq!(
+            part1(concat!(
+                "########################\n",
+                "#@..............ac.GI.b#\n",
+                "###d#e#f################\n",
+                "###A#B#C################\n",
+                "###g#h#i################\n",
+                "########################\n",
+            ))
+            .unwrap(),
+            81
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(concat!(
+                "###############\n",
+                "#d.ABC.#.....a#\n",
+                "######...######\n",
+                "######.@.######\n",
+                "######...######\n",
+                "#b.....#.....c#\n",
+                "###############\n",
+            ))
+            .unwrap(),
+            24
+        );
+    }
+}
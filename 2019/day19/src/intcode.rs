@@ -2,10 +2,6 @@ use std::collections::VecDeque;
 use std::iter::repeat;
 use std::ops::{Index, IndexMut};
 
-use std::error::Error;
-use std::fs::File;
-use std::io::prelude::*;
-
 struct Mem {
     bytes: Vec<isize>,
     rel_base: isize,
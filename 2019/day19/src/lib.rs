@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::ops::Range;
+
+use aoc_error::ParseError;
+use aoc_parse::parse_separated;
+
+use crate::intcode::Vm;
+
+mod intcode;
+
+pub fn parse_input(input: &str) -> Result<Vec<isize>, ParseError> {
+    parse_separated(input, ',')
+}
+
+/// The number of points affected by the tractor beam in the 50x50 area closest
+/// to the emitter.
+pub fn part1(input: &str) -> Result<isize, Box<dyn Error>> {
+    let mem = parse_input(input)?;
+
+    let mut sum = 0;
+
+    for y in 0..50 {
+        for x in 0..50 {
+            let mut vm = Vm::new(&mem);
+            sum += vm.with_input(x).with_input(y).run().unwrap();
+        }
+    }
+
+    Ok(sum)
+}
+
+/// The closest point where Santa's ship fits entirely in the beam.
+pub fn part2(input: &str) -> Result<usize, Box<dyn Error>> {
+    let mem = parse_input(input)?;
+
+    let mut ranges: Vec<Range<usize>> = vec![];
+
+    for y in 0.. {
+        let mut start = if y == 0 { 0 } else { ranges[y-1].start };
+        loop {
+            if Vm::new(&mem).with_input(start as isize).with_input(y as isize).run().unwrap() == 1 {
+                break;
+            }
+            start += 1;
+        };
+
+        let mut end = start + 1;
+        loop {
+            if Vm::new(&mem).with_input(end as isize).with_input(y as isize).run().unwrap() == 0 {
+                break;
+            }
+
+            end += 1;
+        }
+
+        ranges.push(Range { start, end });
+
+        if y >= 99 && (ranges[y].end - ranges[y].start) >= 100 {
+            'x_search: for x in ranges[y].clone() {
+                for y in y-99..y {
+                    if !ranges[y].contains(&x) || !ranges[y].contains(&(x + 99)) {
+                        continue 'x_search;
+                    }
+                }
+
+                return Ok(x * 10000 + y - 99);
+            }
+
+        }
+    }
+
+    unreachable!()
+}
@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use aoc_error::ParseError;
+use aoc_geometry::{Point, LEFT, UP};
+use aoc_search::bfs;
+use petgraph::graphmap::GraphMap;
+use petgraph::Directed;
+
+pub type Graph = GraphMap<Point, isize, Directed>;
+
+/// The fewest steps from AA to ZZ.
+pub fn part1(input: &str) -> Result<usize, Box<dyn Error>> {
+    let (graph, start, end) = parse_maze(input)?;
+
+    let path = bfs(start, |p| graph.neighbors(*p), |p| *p == end).ok_or("no path from AA to ZZ")?;
+
+    Ok(path.cost)
+}
+
+/// The fewest steps from AA to ZZ through the recursive maze.
+pub fn part2(input: &str) -> Result<usize, Box<dyn Error>> {
+    let (graph, start, end) = parse_maze(input)?;
+
+    // Portals on the inner edge lead one level deeper and those on the outer
+    // edge lead back up. There are no outer portals on the outermost level.
+    let path = bfs(
+        (start, 0),
+        |&(p, depth)| {
+            graph
+                .edges(p)
+                .map(move |(_, q, delta)| (q, depth + delta))
+                .filter(|(_, depth)| 0 <= *depth && *depth <= 100)
+        },
+        |&state| state == (end, 0),
+    )
+    .ok_or("no path from AA to ZZ")?;
+
+    Ok(path.cost)
+}
+
+pub fn parse_maze(input: &str) -> Result<(Graph, Point, Point), ParseError> {
+    if let Some((i, c)) = input
+        .char_indices()
+        .find(|(_, c)| !matches!(c, ' ' | '#' | '.' | '\n') && !c.is_ascii_uppercase())
+    {
+        return Err(ParseError::at(
+            input,
+            i,
+            format!("invalid tile `{}'; expected ` ', `#', `.', or a label", c),
+        ));
+    }
+
+    let lines: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    let height = lines.len();
+    let width = lines.first().map_or(0, Vec::len);
+
+    if height < 5 || width < 5 || lines.iter().any(|line| line.len() != width) {
+        return Err(ParseError::new(
+            "the maze must be a rectangle at least 5 tiles wide and tall",
+        ));
+    }
+
+    let (inner_top_left, inner_bottom_right) = find_inner(&lines)?;
+    let labels = find_labels(&lines, inner_top_left, inner_bottom_right);
+
+    let mut g = Graph::new();
+
+    for y in 2..height - 2 {
+        for x in 2..width - 2 {
+            if lines[y][x] == '.' {
+                let p = Point::new(x as isize, y as isize);
+
+                g.add_node(p);
+
+                for d in &[UP, LEFT] {
+                    let q = p + *d;
+
+                    if lines[q.y() as usize][q.x() as usize] == '.' {
+                        g.add_edge(p, q, 0);
+                        g.add_edge(q, p, 0);
+                    }
+                }
+            }
+        }
+    }
+
+    for (label, points) in &labels {
+        if label == "AA" || label == "ZZ" {
+            continue;
+        }
+
+        if points.len() != 2 {
+            return Err(ParseError::new(format!(
+                "portal `{}' has {} ends; expected 2",
+                label,
+                points.len()
+            )));
+        }
+
+        g.add_edge(points[0].0, points[1].0, points[0].1);
+        g.add_edge(points[1].0, points[0].0, points[1].1);
+    }
+
+    let endpoint = |label| {
+        labels
+            .get(label)
+            .map(|points| points[0].0)
+            .ok_or_else(|| ParseError::new(format!("the maze has no `{}' portal", label)))
+    };
+
+    Ok((g, endpoint("AA")?, endpoint("ZZ")?))
+}
+
+fn find_inner(lines: &[Vec<char>]) -> Result<(Point, Point), ParseError> {
+    let height = lines.len();
+    let width = lines[0].len();
+    let no_hole = || ParseError::new("the maze has no hole in the middle");
+
+    if lines[height / 2][width / 2] != ' ' {
+        return Err(no_hole());
+    }
+
+    let mut inner_left = None;
+    for x in (0..width / 2).rev() {
+        match lines[height / 2][x] {
+            '#' | '.' => {
+                inner_left = Some(x);
+                break;
+            }
+            _ => continue,
+        }
+    }
+    let inner_left = inner_left.ok_or_else(no_hole)?;
+
+    let mut inner_right = None;
+    for x in width / 2..width {
+        match lines[height / 2][x] {
+            '#' | '.' => {
+                inner_right = Some(x);
+                break;
+            }
+            _ => continue,
+        }
+    }
+    let inner_right = inner_right.ok_or_else(no_hole)?;
+
+    let mut inner_top = None;
+    for y in (0..height / 2).rev() {
+        match lines[y][width / 2] {
+            '#' | '.' => {
+                inner_top = Some(y);
+                break;
+            }
+            _ => continue,
+        }
+    }
+    let inner_top = inner_top.ok_or_else(no_hole)?;
+
+    let mut inner_bottom = None;
+    for y in height / 2..height {
+        match lines[y][width / 2] {
+            '#' | '.' => {
+                inner_bottom = Some(y);
+                break;
+            }
+            _ => continue,
+        }
+    }
+    let inner_bottom = inner_bottom.ok_or_else(no_hole)?;
+
+    Ok((
+        Point::new(inner_left as isize, inner_top as isize),
+        Point::new(inner_right as isize, inner_bottom as isize),
+    ))
+}
+
+fn find_labels(
+    lines: &[Vec<char>],
+    inner_top_left: Point,
+    inner_bottom_right: Point,
+) -> HashMap<String, Vec<(Point, isize)>> {
+    let height = lines.len();
+    let width = lines[0].len();
+
+    let mut labels = HashMap::<String, Vec<(Point, isize)>>::new();
+
+    // Outer donut.
+    for (x, (c1, c2)) in Iterator::zip(lines[0].iter(), lines[1].iter()).enumerate() {
+        if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+            let name = format!("{}{}", c1, c2);
+            labels
+                .entry(name)
+                .or_default()
+                .push((Point::new(x as isize, 2), -1));
+        }
+    }
+
+    for (x, (c1, c2)) in
+        Iterator::zip(lines[height - 2].iter(), lines[height - 1].iter()).enumerate()
+    {
+        if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+            let name = format!("{}{}", c1, c2);
+            labels
+                .entry(name)
+                .or_default()
+                .push((Point::new(x as isize, (height - 3) as isize), -1));
+        }
+    }
+
+    for (y, (c1, c2)) in
+        Iterator::zip(lines.iter().map(|s| s[0]), lines.iter().map(|s| s[1])).enumerate()
+    {
+        if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+            let name = format!("{}{}", c1, c2);
+            labels
+                .entry(name)
+                .or_default()
+                .push((Point::new(2, y as isize), -1));
+        }
+    }
+
+    for (y, (c1, c2)) in Iterator::zip(
+        lines.iter().map(|s| s[width - 2]),
+        lines.iter().map(|s| s[width - 1]),
+    )
+    .enumerate()
+    {
+        if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+            let name = format!("{}{}", c1, c2);
+            labels
+                .entry(name)
+                .or_default()
+                .push((Point::new((width - 3) as isize, y as isize), -1));
+        }
+    }
+
+    // Inner donut portals.
+    let left = inner_top_left.x();
+    let right = inner_bottom_right.x();
+    let top = inner_top_left.y();
+    let bottom = inner_bottom_right.y();
+
+    for (x, (c1, c2)) in Iterator::zip(
+        lines[top as usize + 1].iter(),
+        lines[top as usize + 2].iter(),
+    )
+    .enumerate()
+    .take(right as usize)
+    .skip(left as usize)
+    {
+        if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+            let name = format!("{}{}", c1, c2);
+            labels
+                .entry(name)
+                .or_default()
+                .push((Point::new(x as isize, top), 1));
+        }
+    }
+
+    for (x, (c1, c2)) in Iterator::zip(
+        lines[bottom as usize - 2].iter(),
+        lines[bottom as usize - 1].iter(),
+    )
+    .enumerate()
+    .take(right as usize)
+    .skip(left as usize)
+    {
+        if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+            let name = format!("{}{}", c1, c2);
+            labels
+                .entry(name)
+                .or_default()
+                .push((Point::new(x as isize, bottom), 1));
+        }
+    }
+
+    for (y, (c1, c2)) in Iterator::zip(
+        lines.iter().map(|s| s[(left + 1) as usize]),
+        lines.iter().map(|s| s[(left + 2) as usize]),
+    )
+    .enumerate()
+    {
+        if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+            let name = format!("{}{}", c1, c2);
+            labels
+                .entry(name)
+                .or_default()
+                .push((Point::new(left, y as isize), 1));
+        }
+    }
+
+    for (y, (c1, c2)) in Iterator::zip(
+        lines.iter().map(|s| s[(right - 2) as usize]),
+        lines.iter().map(|s| s[(right - 1) as usize]),
+    )
+    .enumerate()
+    {
+        if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+            let name = format!("{}{}", c1, c2);
+            labels
+                .entry(name)
+                .or_default()
+                .push((Point::new(right as isize, y as isize), 1));
+        }
+    }
+
+    labels
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = concat!(
+            "         A           \n",
+            "         A           \n",
+            "  #######.#########  \n",
+            "  #######.........#  \n",
+            "  #######.#######.#  \n",
+            "  #######.#######.#  \n",
+            "  #######.#######.#  \n",
+            "  #####  B    ###.#  \n",
+            "BC...##  C    ###.#  \n",
+            "  ##.##       ###.#  \n",
+            "  ##...DE  F  ###.#  \n",
+            "  #####    G  ###.#  \n",
+            "  #########.#####.#  \n",
+            "DE..#######...###.#  \n",
+            "  #.#########.###.#  \n",
+            "FG..#########.....#  \n",
+            "  ###########.#####  \n",
+            "             Z       \n",
+            "             Z       \n",
+        );
+
+        assert_eq!(part1(input).unwrap(), 23);
+        assert_eq!(part2(input).unwrap(), 26);
+    }
+}
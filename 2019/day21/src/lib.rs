@@ -0,0 +1,69 @@
+use std::error::Error;
+
+use aoc_error::ParseError;
+use aoc_parse::parse_separated;
+use indoc::indoc;
+
+use crate::intcode::Vm;
+
+mod intcode;
+
+pub fn parse_input(input: &str) -> Result<Vec<isize>, ParseError> {
+    parse_separated(input, ',')
+}
+
+/// The hull damage reported by the springdroid when walking.
+pub fn part1(input: &str) -> Result<isize, Box<dyn Error>> {
+    let mem = parse_input(input)?;
+
+    let program = indoc!(
+        r#"NOT C J
+        AND D J
+        NOT A T
+        OR T J
+        WALK
+        "#);
+
+    run_springscript(&mem, program)
+}
+
+/// The hull damage reported by the springdroid when running.
+pub fn part2(input: &str) -> Result<isize, Box<dyn Error>> {
+    let mem = parse_input(input)?;
+
+    let program = indoc!(
+        r#"NOT A J
+        NOT C T
+        OR T J
+        NOT B T
+        OR T J
+        AND D J
+        NOT E T
+        NOT T T
+        OR H T
+        AND T J
+        RUN
+        "#);
+
+    run_springscript(&mem, program)
+}
+
+/// Run a springscript program on the springdroid.
+///
+/// If the droid falls into space, the error holds its last moments.
+fn run_springscript(mem: &[isize], program: &str) -> Result<isize, Box<dyn Error>> {
+    let input = program.as_bytes().iter().map(|&c| c as isize).collect::<Vec<_>>();
+
+    let mut vm = Vm::new_with_input(mem, &input);
+    let mut view = String::new();
+
+    while let Some(c) = vm.run() {
+        if 0 < c && c <= 128 {
+            view.push(c as u8 as char);
+        } else {
+            return Ok(c);
+        }
+    }
+
+    Err(view.into())
+}
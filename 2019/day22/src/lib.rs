@@ -0,0 +1,268 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::mem::swap;
+
+use aoc_error::{parse_at, ParseError};
+use aoc_math::{inverse_mod, mul_mod, pow_mod};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Cut(isize),
+    DealIncrement(usize),
+    DealIntoNew,
+}
+
+/// The position of card 2019 after shuffling a deck of 10007 cards.
+pub fn part1(input: &str) -> Result<usize, Box<dyn Error>> {
+    let instructions = parse_instructions(input)?;
+
+    let mut cards = (0..=10006).collect::<VecDeque<_>>();
+    shuffle(&mut cards, &instructions);
+
+    let pos = cards
+        .iter()
+        .enumerate()
+        .find(|(_, card)| **card == 2019)
+        .unwrap()
+        .0;
+
+    Ok(pos)
+}
+
+/// The card that ends up in position 2020 after shuffling the huge deck many
+/// times.
+pub fn part2(input: &str) -> Result<u64, Box<dyn Error>> {
+    let instructions = parse_instructions(input)?;
+
+    // Each shuffle (or reverse shuffle) can be represented as an affine
+    // transformation of the form:
+    //
+    //     f(c) = (A * c + B) (mod M)
+    //
+    // Since we care about the card in position 2020 at the end, we will
+    // compute the affine transformation of the reverse shuffle to see which
+    // card it corresponds to in the original deck.
+    //
+    // If we compute `A` and `B` for a single shuffle then we can repeatedly
+    // apply it to generate a general case formulate for `f^n(c)` (i.e.,
+    // shuffling `n` times):
+    //
+    //     f^2(c) = f(f(c))              (mod M)
+    //            = f(A * c + B)         (mod M)
+    //            = A * (A * c + B) + B  (mod M)
+    //            = A^2 * c + AB + B     (mod M)
+    //
+    //     f^3(c) = f(f(f(c))                   (mod M)
+    //            = f(A^2 * c + AB + B)         (mod M)
+    //            = A * (A^2 * C + AB + B) + B  (mod M)
+    //            = A^3 * c + A^2 B + AB + B    (mod M)
+    //
+    //     ...
+    //
+    //     f^n(c) = A^n * c + A^(n-1) * B + A^(n-2) * B + ... + AB + B  (mod M)
+    //            = A^n * c + B * (A^(n-1) + A^(n-2) + ... + A + 1)     (mod M)
+    //            = A^n * C + B * (1 + A + A^2 .. A^{n-1})              (mod M)
+    //                             ______________________
+    //
+    // We notice that the underlined term is the first `n` terms of a
+    // geometric series. In general, this sum is given by:
+    //
+    //     S(n) = (A^n - 1) / (A - 1)
+    //
+    // Since `M` is prime, `A - 1` has an inverse modulo `M` (unless `A = 1`,
+    // when the sum is just `n`), so our closed form of `f^n(c)` is:
+    //
+    //     f^n(c) = A^n * c + B * (A^n - 1) * (A - 1)^{-1}  (mod M)
+
+    const N_CARDS: u64 = 119315717514047;
+    const N_ITERS: u64 = 101741582076661;
+
+    let (a, b) = reverse_shuffle(&instructions, N_CARDS);
+
+    Ok(repeat_reverse_shuffle(a, b, N_ITERS, N_CARDS, 2020))
+}
+
+/// The affine transformation `(A, B)` of the reverse of a shuffle of `n_cards`
+/// cards, i.e., the card in position `p` after shuffling was in position
+/// `A * p + B (mod n_cards)` before it.
+fn reverse_shuffle(instructions: &[Instruction], n_cards: u64) -> (u64, u64) {
+    let mut a = 1;
+    let mut b = 0;
+
+    for instr in instructions.iter().rev() {
+        match instr {
+            Instruction::Cut(n) => {
+                b = (b + n.rem_euclid(n_cards as isize) as u64) % n_cards;
+            }
+
+            Instruction::DealIncrement(n) => {
+                let p = inverse_mod(*n as u64, n_cards)
+                    .expect("increments must be coprime to the number of cards");
+                a = mul_mod(a, p, n_cards);
+                b = mul_mod(b, p, n_cards);
+            }
+
+            Instruction::DealIntoNew => {
+                a = (n_cards - a) % n_cards;
+                b = n_cards - 1 - b;
+            }
+        }
+    }
+
+    (a, b)
+}
+
+/// The position before shuffling `n_iters` times of the card that ends up in
+/// `position`, given the reverse shuffle `(a, b)` from `reverse_shuffle`.
+///
+/// `n_cards` must be prime.
+fn repeat_reverse_shuffle(a: u64, b: u64, n_iters: u64, n_cards: u64, position: u64) -> u64 {
+    // With no scaling the geometric series is just `n_iters` copies of `b`.
+    if a == 1 {
+        return (position + mul_mod(b, n_iters % n_cards, n_cards)) % n_cards;
+    }
+
+    let a_n = pow_mod(a, n_iters, n_cards);
+    let series = mul_mod(
+        (a_n + n_cards - 1) % n_cards,
+        inverse_mod(a - 1, n_cards).unwrap(),
+        n_cards,
+    );
+
+    (mul_mod(a_n, position, n_cards) + mul_mod(b, series, n_cards)) % n_cards
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            if let Some(n) = line.strip_prefix("cut ") {
+                parse_at(input, n).map(Instruction::Cut)
+            } else if let Some(n) = line.strip_prefix("deal with increment ") {
+                parse_at(input, n).map(Instruction::DealIncrement)
+            } else if line == "deal into new stack" {
+                Ok(Instruction::DealIntoNew)
+            } else {
+                Err(ParseError::at_span(
+                    input,
+                    line,
+                    format!("unknown instruction `{}'", line),
+                ))
+            }
+        })
+        .collect()
+}
+
+fn shuffle(cards: &mut VecDeque<u32>, instructions: &[Instruction]) {
+    for instr in instructions {
+        match instr {
+            Instruction::Cut(n) => cut(cards, *n),
+            Instruction::DealIncrement(n) => deal_increment(cards, *n),
+            Instruction::DealIntoNew => deal_new(cards),
+        }
+    }
+}
+
+fn cut(cards: &mut VecDeque<u32>, n: isize) {
+    let n = n.rem_euclid(cards.len() as isize) as usize;
+
+    let mut tail = cards.split_off(n);
+    swap(cards, &mut tail);
+    cards.append(&mut tail);
+}
+
+fn deal_new(cards: &mut VecDeque<u32>) {
+    for (i, j) in Iterator::zip(0..cards.len(), (0..cards.len()).rev()) {
+        if i >= j {
+            break;
+        }
+
+        cards.swap(i, j);
+    }
+}
+
+fn deal_increment(cards: &mut VecDeque<u32>, n: usize) {
+    let mut new_deck = VecDeque::from(vec![0; cards.len()]);
+    let mut idx = 0;
+    for card in cards.drain(..) {
+        new_deck[idx] = card;
+        idx += n;
+        idx %= new_deck.len();
+    }
+
+    std::mem::swap(&mut new_deck, cards);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        {
+            let mut cards = (0..10).collect::<VecDeque<_>>();
+
+            deal_increment(&mut cards, 7);
+            deal_new(&mut cards);
+            deal_new(&mut cards);
+
+            assert_eq!(cards, &[0, 3, 6, 9, 2, 5, 8, 1, 4, 7]);
+        }
+        {
+            let mut cards = (0..10).collect::<VecDeque<_>>();
+            cut(&mut cards, 6);
+            deal_increment(&mut cards, 7);
+            deal_new(&mut cards);
+
+            assert_eq!(cards, &[3, 0, 7, 4, 1, 8, 5, 2, 9, 6]);
+        }
+        {
+            let mut cards = (0..10).collect::<VecDeque<_>>();
+            deal_increment(&mut cards, 7);
+            deal_increment(&mut cards, 9);
+            cut(&mut cards, -2);
+
+            assert_eq!(cards, &[6, 3, 0, 7, 4, 1, 8, 5, 2, 9]);
+        }
+    }
+
+    #[test]
+    fn test_generated() {
+        const N_CARDS: u64 = aoc_generate::y2019::DECK_SIZE;
+
+        let mut rng = aoc_generate::rng(22);
+
+        for size in 1..=20 {
+            let input = aoc_generate::y2019::shuffle(&mut rng, size * 5, N_CARDS);
+            let instructions = parse_instructions(&input).unwrap();
+            let (a, b) = reverse_shuffle(&instructions, N_CARDS);
+
+            let mut cards = (0..N_CARDS as u32).collect::<VecDeque<_>>();
+            for n_iters in 1..=3 {
+                shuffle(&mut cards, &instructions);
+
+                for (position, card) in cards.iter().enumerate() {
+                    let before = repeat_reverse_shuffle(a, b, n_iters, N_CARDS, position as u64);
+                    assert_eq!(before, *card as u64);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_only_cuts() {
+        let instructions = parse_instructions("cut 3\ncut -7\ncut 6").unwrap();
+        let (a, b) = reverse_shuffle(&instructions, 10);
+
+        let mut cards = (0..10).collect::<VecDeque<_>>();
+        shuffle(&mut cards, &instructions);
+        shuffle(&mut cards, &instructions);
+
+        for (position, card) in cards.iter().enumerate() {
+            assert_eq!(
+                repeat_reverse_shuffle(a, b, 2, 10, position as u64),
+                *card as u64
+            );
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::{HashSet, VecDeque};
+use std::error::Error;
+
+use aoc_error::ParseError;
+use aoc_parse::parse_separated;
+
+use crate::intcode::{RunResult, Vm};
+
+mod intcode;
+
+pub fn parse_input(input: &str) -> Result<Vec<isize>, ParseError> {
+    parse_separated(input, ',')
+}
+
+/// The Y value of the first packet sent to address 255.
+pub fn part1(input: &str) -> Result<isize, Box<dyn Error>> {
+    let mem = parse_input(input)?;
+
+    let mut vms = Vec::new();
+    let mut input: Vec<VecDeque<(isize, isize)>> = Vec::new();
+
+    for i in 0..50 {
+        input.push(VecDeque::new());
+        vms.push(Vm::new_with_input(&mem, &[i as isize]));
+    }
+
+    loop {
+        for i in 0..50 {
+            let vm = &mut vms[i];
+            match vm.run() {
+                RunResult::Halt => panic!(),
+
+                RunResult::Output(addr) => {
+                    let addr = addr as usize;
+
+                    let x = vms[i].run().as_output().unwrap();
+                    let y = vms[i].run().as_output().unwrap();
+
+                    if addr == 255 {
+                        return Ok(y);
+                    }
+
+                    vms[addr].with_input(x).with_input(y);
+                }
+
+                RunResult::InputRequired => {
+                    vm.with_input(-1);
+                }
+            }
+        }
+    }
+}
+
+/// The first Y value delivered by the NAT to address 0 twice in a row.
+pub fn part2(input: &str) -> Result<isize, Box<dyn Error>> {
+    let mem = parse_input(input)?;
+
+    let mut vms = Vec::new();
+    let mut input: Vec<VecDeque<(isize, isize)>> = Vec::new();
+    let mut nat: Option<(isize, isize)> = None;
+
+    for i in 0..50 {
+        input.push(VecDeque::new());
+        vms.push(Vm::new_with_input(&mem, &[i as isize]));
+    }
+
+    let mut seen = HashSet::new();
+
+    loop {
+        let mut idle_count = 0;
+
+        for i in 0..50 {
+            let vm = &mut vms[i];
+            match vm.run() {
+                RunResult::Halt => panic!(),
+
+                RunResult::Output(addr) => {
+                    let addr = addr as usize;
+
+                    let x = vms[i].run().as_output().unwrap();
+                    let y = vms[i].run().as_output().unwrap();
+
+                    if addr == 255 {
+                        nat = Some((x, y));
+                    } else {
+                        vms[addr].with_input(x).with_input(y);
+                    }
+                }
+
+                RunResult::InputRequired => match vm.with_input(-1).run() {
+                    RunResult::Halt => panic!(),
+                    RunResult::Output(addr) => {
+                        let addr = addr as usize;
+
+                        let x = vms[i].run().as_output().unwrap();
+                        let y = vms[i].run().as_output().unwrap();
+
+                        if addr == 255 {
+                            nat = Some((x, y));
+                        } else {
+                            vms[addr].with_input(x).with_input(y);
+                        }
+                    }
+                    RunResult::InputRequired => {
+                        idle_count += 1;
+                    }
+                },
+            }
+        }
+
+        if idle_count == 50 {
+            let (x, y) = nat.unwrap();
+            vms[0].with_input(x).with_input(y);
+
+            if seen.contains(&y) {
+                return Ok(y);
+            } else {
+                seen.insert(y);
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::error::Error;
+use std::io;
+
+use aoc_automaton::{Automaton, GridAutomaton, Life, Recursive, VonNeumann};
+use aoc_cycle::Detector;
+use aoc_error::ParseError;
+use aoc_grid::{Grid, Point};
+use aoc_visualize::{Disabled, Frame, Rgb, Visualizer};
+
+/// The biodiversity rating of the first layout that appears twice.
+pub fn part1(input: &str) -> Result<u64, Box<dyn Error>> {
+    Ok(first_repeat(parse_state(input)?, &mut Disabled)?)
+}
+
+/// The number of bugs in the recursive grids after 200 minutes.
+pub fn part2(input: &str) -> Result<u64, Box<dyn Error>> {
+    Ok(recursive_bug_count(
+        parse_state(input)?,
+        200,
+        &mut Disabled,
+    )?)
+}
+
+/// Simulate the bugs until a layout repeats, returning its biodiversity rating.
+pub fn first_repeat(input: State, visualizer: &mut dyn Visualizer) -> io::Result<u64> {
+    let mut bugs = GridAutomaton::new(VonNeumann, rule(), input.0);
+    let mut detector = Detector::new();
+
+    while detector.push(bugs.grid().clone()).is_none() {
+        visualizer.emit(|| draw(bugs.grid()))?;
+        bugs.step();
+    }
+
+    Ok(biodiversity(bugs.grid()))
+}
+
+/// The number of bugs in the recursive grids after the given number of minutes.
+pub fn recursive_bug_count(
+    input: State,
+    minutes: usize,
+    visualizer: &mut dyn Visualizer,
+) -> io::Result<u64> {
+    let bugs = input.0.iter().filter(|(_, &bug)| bug).map(|(p, _)| (0, p));
+    let mut bugs = Automaton::new(Recursive { size: 5 }, rule(), bugs);
+
+    for _ in 0..minutes {
+        visualizer.emit(|| draw_recursive(&bugs))?;
+        bugs.step();
+    }
+    visualizer.emit(|| draw_recursive(&bugs))?;
+
+    Ok(bugs.population() as u64)
+}
+
+pub fn parse_state(input: &str) -> Result<State, ParseError> {
+    let grid = Grid::parse(input, |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })?;
+
+    if grid.width() != 5 || grid.height() != 5 {
+        return Err(ParseError::new("expected a 5x5 grid"));
+    }
+
+    Ok(State(grid))
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct State(Grid<bool>);
+
+/// A bug survives with exactly one adjacent bug and an empty tile becomes
+/// infested with one or two adjacent bugs.
+fn rule() -> Life {
+    Life::new(&[1, 2], &[1])
+}
+
+fn biodiversity(grid: &Grid<bool>) -> u64 {
+    grid.cells()
+        .enumerate()
+        .filter(|(_, &bug)| bug)
+        .map(|(i, _)| 1 << i)
+        .sum()
+}
+
+/// Draw the grid into `frame` with its top left corner at `x`.
+fn draw_at(frame: &mut Frame, x: usize, bug: impl Fn(Point) -> bool) {
+    for y in 0..5 {
+        for dx in 0..5 {
+            if bug(Point::new(dx as isize, y as isize)) {
+                frame.set(x + dx, y, '#', Rgb::GREEN);
+            } else {
+                frame.set(x + dx, y, '.', Rgb::GREY);
+            }
+        }
+    }
+}
+
+fn draw(grid: &Grid<bool>) -> Frame {
+    let mut frame = Frame::new(5, 5);
+    draw_at(&mut frame, 0, |p| grid[p]);
+    frame
+}
+
+/// Draw every level side by side, from the outermost to the innermost.
+fn draw_recursive(bugs: &Automaton<Recursive, Life>) -> Frame {
+    let min = bugs
+        .alive()
+        .iter()
+        .map(|(level, _)| *level)
+        .min()
+        .unwrap_or(0);
+    let max = bugs
+        .alive()
+        .iter()
+        .map(|(level, _)| *level)
+        .max()
+        .unwrap_or(0);
+    let levels = (max - min + 1) as usize;
+
+    let mut frame = Frame::new(levels * 6 - 1, 5);
+    for (i, level) in (min..=max).enumerate() {
+        draw_at(&mut frame, i * 6, |p| bugs.is_alive((level, p)));
+        frame.set(i * 6 + 2, 2, '?', Rgb::BLUE);
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = parse_state("....#\n#..#.\n#..##\n..#..\n#....\n").unwrap();
+
+        assert_eq!(first_repeat(input.clone(), &mut Disabled).unwrap(), 2129920);
+        assert_eq!(recursive_bug_count(input, 10, &mut Disabled).unwrap(), 99);
+    }
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+
+use aoc_error::ParseError;
+use aoc_parse::parse_separated;
+
+use crate::intcode::{RunResult, Vm};
+
+mod intcode;
+
+pub fn parse_input(input: &str) -> Result<Vec<isize>, ParseError> {
+    parse_separated(input, ',')
+}
+
+/// The droid exploring Santa's ship, driven by text commands.
+pub struct Droid {
+    vm: Vm,
+    halted: bool,
+}
+
+impl Droid {
+    pub fn new(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Droid {
+            vm: Vm::new(&parse_input(input)?),
+            halted: false,
+        })
+    }
+
+    /// Run the droid until it asks for a command or halts, returning what it
+    /// printed.
+    pub fn run(&mut self) -> String {
+        let mut output = String::new();
+
+        loop {
+            match self.vm.run() {
+                RunResult::Halt => {
+                    self.halted = true;
+                    break;
+                }
+                RunResult::InputRequired => break,
+                RunResult::Output(c) => {
+                    if 0 < c && c < 255 {
+                        output.push(c as u8 as char);
+                    } else {
+                        panic!("non-ascii output")
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Queue a command for the droid.
+    pub fn command(&mut self, command: &str) {
+        for c in command.trim_end().chars() {
+            self.vm.with_input(c as u8 as isize);
+        }
+        self.vm.with_input(b'\n' as isize);
+    }
+
+    pub fn halted(&self) -> bool {
+        self.halted
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::{anyhow, Result};
+use aoc_error::{parse_at, ParseError};
+
+/// The product of the two expenses that sum to 2020.
+pub fn part1(input: &str) -> Result<i32> {
+    pair_product(&parse_expenses(input)?).ok_or_else(|| anyhow!("no two expenses sum to 2020"))
+}
+
+/// The product of the three expenses that sum to 2020.
+pub fn part2(input: &str) -> Result<i32> {
+    triple_product(&parse_expenses(input)?).ok_or_else(|| anyhow!("no three expenses sum to 2020"))
+}
+
+pub fn parse_expenses(input: &str) -> Result<Vec<i32>, ParseError> {
+    input.lines().map(|line| parse_at(input, line)).collect()
+}
+
+fn pair_product(expenses: &[i32]) -> Option<i32> {
+    for (i, expense_a) in expenses.iter().enumerate() {
+        for (j, expense_b) in expenses.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+
+            if expense_a + expense_b == 2020 {
+                return Some(expense_a * expense_b);
+            }
+        }
+    }
+
+    None
+}
+
+fn triple_product(expenses: &[i32]) -> Option<i32> {
+    for (i, expense_a) in expenses.iter().enumerate() {
+        for (j, expense_b) in expenses.iter().enumerate() {
+            for (k, expense_c) in expenses.iter().enumerate() {
+                if i == j || j == k || i == k {
+                    continue;
+                }
+
+                if expense_a + expense_b + expense_c== 2020 {
+                    return Some(expense_a * expense_b * expense_c);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "1721\n979\n366\n299\n675\n1456\n";
+        assert_eq!(part1(input).unwrap(), 514579);
+        assert_eq!(part2(input).unwrap(), 241861950);
+    }
+}
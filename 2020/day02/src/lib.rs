@@ -0,0 +1,93 @@
+use anyhow::Result;
+use aoc_error::ParseError;
+use aoc_parse::{parse_lines, unsigned};
+use combine::parser::char::{char, lower, string};
+use combine::{many1, Parser};
+
+pub struct Rule {
+    i: usize,
+    j: usize,
+    letter: char,
+}
+
+/// The number of passwords that are valid under the sled rental policy.
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_in_range(&parse_passwords(input)?))
+}
+
+/// The number of passwords that are valid under the toboggan policy.
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(count_in_position(&parse_passwords(input)?))
+}
+
+pub fn parse_passwords(input: &str) -> Result<Vec<(Rule, String)>, ParseError> {
+    let rule = (
+        unsigned().skip(char('-')),
+        unsigned().skip(char(' ')),
+        lower().skip(string(": ")),
+        many1(lower()),
+    )
+        .map(|(i, j, letter, password)| (Rule { i, j, letter }, password));
+
+    parse_lines(rule, input)
+}
+
+fn count_in_range(input: &[(Rule, String)]) -> usize {
+    input
+        .iter()
+        .map(|(rule, password)| {
+            let freq = password
+                .chars()
+                .filter(|&c| c == rule.letter)
+                .map(|_| 1)
+                .sum::<usize>();
+            (rule.i..=rule.j).contains(&freq) as usize
+        })
+        .sum::<usize>()
+}
+
+fn count_in_position(input: &[(Rule, String)]) -> usize {
+    input
+        .iter()
+        .map(|(rule, password)| {
+            let a = password.chars().nth(rule.i - 1).unwrap();
+            let b = password.chars().nth(rule.j - 1).unwrap();
+            ((a == rule.letter) ^ (b == rule.letter)) as usize
+        })
+        .sum::<usize>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        let mut rng = aoc_generate::rng(2);
+
+        for _ in 0..20 {
+            let input = aoc_generate::y2020::passwords(&mut rng, 100);
+
+            let (mut in_range, mut in_position) = (0, 0);
+            for line in input.lines() {
+                let fields = line.split(&['-', ' ', ':'][..]).collect::<Vec<_>>();
+                let i = fields[0].parse::<usize>().unwrap();
+                let j = fields[1].parse::<usize>().unwrap();
+                let letter = fields[2].as_bytes()[0];
+                let password = fields[4].as_bytes();
+
+                let count = password.iter().filter(|c| **c == letter).count();
+                if i <= count && count <= j {
+                    in_range += 1;
+                }
+
+                if (password[i - 1] == letter) != (password[j - 1] == letter) {
+                    in_position += 1;
+                }
+            }
+
+            assert_eq!(part1(&input).unwrap(), in_range);
+            assert_eq!(part2(&input).unwrap(), in_position);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use aoc_error::ParseError;
+use aoc_grid::{Grid, Point};
+
+pub type Map = Grid<bool>;
+
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+fn trees_for_slope(map: &Map, dx: isize, dy: isize) -> usize {
+    // The map repeats to the right forever.
+    (0..)
+        .map(|i| Point::new(i * dx, i * dy))
+        .take_while(|p| (p.y() as usize) < map.height())
+        .filter(|p| *map.get_wrapping(*p))
+        .count()
+}
+
+/// The number of trees encountered going right 3 and down 1.
+pub fn part1(input: &str) -> Result<usize> {
+    let map = parse_map(input)?;
+
+    Ok(trees_for_slope(&map, 3, 1))
+}
+
+/// The product of the number of trees encountered on each slope.
+pub fn part2(input: &str) -> Result<usize> {
+    let map = parse_map(input)?;
+
+    Ok(trees_for_slope(&map, 1, 1)
+        * trees_for_slope(&map, 3, 1)
+        * trees_for_slope(&map, 5, 1)
+        * trees_for_slope(&map, 7, 1)
+        * trees_for_slope(&map, 1, 2))
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use aoc_error::ParseError;
+
+/// The highest seat ID on a boarding pass.
+pub fn part1(input: &str) -> Result<u32> {
+    parse_seat_ids(input)?
+        .into_iter()
+        .max()
+        .ok_or_else(|| anyhow!("no boarding passes"))
+}
+
+/// The ID of the one empty seat.
+pub fn part2(input: &str) -> Result<u32> {
+    let ids = parse_seat_ids(input)?.into_iter().collect::<HashSet<_>>();
+
+    // Our seat is missing, but the seats with the IDs on either side of it are
+    // not, even if they are in another row.
+    (1..1023)
+        .find(|id| !ids.contains(id) && ids.contains(&(id - 1)) && ids.contains(&(id + 1)))
+        .ok_or_else(|| anyhow!("no empty seat between two boarding passes"))
+}
+
+/// The seat ID of each boarding pass.
+pub fn parse_seat_ids(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
+        .lines()
+        .map(|line| Ticket::from_bsp(input, line).map(|ticket| ticket.id()))
+        .collect()
+}
+
+#[derive(Hash, Eq, Debug, PartialEq)]
+struct Ticket {
+    row: u32,
+    column: u32,
+}
+
+impl Ticket {
+    fn from_bsp(input: &str, s: &str) -> Result<Ticket, ParseError> {
+        if s.len() != 10 {
+            return Err(ParseError::at_span(
+                input,
+                s,
+                "expected a boarding pass, e.g., `FBFBBFFRLR'",
+            ));
+        }
+
+        let mut clues = Vec::with_capacity(10);
+        for (i, c) in s.char_indices() {
+            // The first 7 characters pick the row and the last 3 the column.
+            let (clue, expected) = if i < 7 {
+                (Clue::from_fb(c), "`F' or `B'")
+            } else {
+                (Clue::from_lr(c), "`L' or `R'")
+            };
+
+            clues.push(clue.ok_or_else(|| {
+                ParseError::at_span(
+                    input,
+                    &s[i..i + c.len_utf8()],
+                    format!("expected {}, got `{}'", expected, c),
+                )
+            })?);
+        }
+
+        let row = binary_search(0, 128, clues[..7].iter().copied());
+        let column = binary_search(0, 8, clues[7..].iter().copied());
+
+        Ok(Ticket { row, column })
+    }
+
+    fn id(&self) -> u32 {
+        self.row * 8 + self.column
+    }
+}
+
+fn binary_search(min: u32, max: u32, clues: impl Iterator<Item = Clue>) -> u32 {
+    let mut min = min;
+    let mut max = max;
+    for clue in clues {
+        let midpoint = (min + max) / 2;
+        match clue {
+            Clue::Lower => max = midpoint,
+            Clue::Higher => min = midpoint,
+        }
+    }
+
+    min
+}
+
+#[derive(Clone, Copy)]
+enum Clue {
+    Lower,
+    Higher,
+}
+
+impl Clue {
+    fn from_lr(c: char) -> Option<Clue> {
+        match c {
+            'L' => Some(Clue::Lower),
+            'R' => Some(Clue::Higher),
+            _ => None,
+        }
+    }
+
+    fn from_fb(c: char) -> Option<Clue> {
+        match c {
+            'F' => Some(Clue::Lower),
+            'B' => Some(Clue::Higher),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        let mut rng = aoc_generate::rng(5);
+
+        for size in (2..1022).step_by(17) {
+            let input = aoc_generate::y2020::boarding_passes(&mut rng, size);
+
+            // Seat IDs are just the boarding passes read as binary numbers.
+            let ids = input
+                .lines()
+                .map(|line| {
+                    let bits = line
+                        .replace(&['F', 'L'][..], "0")
+                        .replace(&['B', 'R'][..], "1");
+                    u32::from_str_radix(&bits, 2).unwrap()
+                })
+                .collect::<HashSet<_>>();
+
+            let min = *ids.iter().min().unwrap();
+            let max = *ids.iter().max().unwrap();
+            let missing = (min..max).find(|id| !ids.contains(id)).unwrap();
+
+            assert_eq!(part1(&input).unwrap(), max);
+            assert_eq!(part2(&input).unwrap(), missing);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let e = parse_seat_ids("FBFBBFFRLR\nFBFBXFFRLR\n").unwrap_err();
+        assert_eq!(e.message(), "expected `F' or `B', got `X'");
+
+        let location = e.location().unwrap();
+        assert_eq!((location.line, location.column), (2, 5));
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use anyhow::Result;
+use aoc_error::ParseError;
+
+pub fn parse_groups(input: &str) -> Result<Vec<Vec<HashSet<char>>>, ParseError> {
+    let mut group = Vec::new();
+    let mut groups = Vec::new();
+    for line in input.lines() {
+        if line.len() == 0 {
+            groups.push(group);
+            group = Vec::new();
+        } else {
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+                return Err(ParseError::at_span(
+                    input,
+                    &line[i..i + c.len_utf8()],
+                    format!("expected a question from `a' to `z', got `{}'", c),
+                ));
+            }
+
+            group.push(line.chars().collect());
+        }
+    }
+
+    if group.len() > 0 {
+        groups.push(group);
+    }
+
+    Ok(groups)
+}
+
+/// The sum of the number of questions anyone in each group answered yes to.
+pub fn part1(input: &str) -> Result<usize> {
+    let groups = parse_groups(input)?;
+
+    Ok(groups
+        .iter()
+        .map(|v: &Vec<HashSet<char>>| {
+            let mut q = HashSet::<char>::new();
+
+            for u in v {
+                q.extend(u.iter());
+            }
+
+            q.len()
+        })
+        .sum::<usize>())
+}
+
+/// The sum of the number of questions everyone in each group answered yes to.
+pub fn part2(input: &str) -> Result<usize> {
+    let groups = parse_groups(input)?;
+
+    Ok(groups
+        .iter()
+        .map(|v: &Vec<HashSet<char>>| {
+            let mut q = HashMap::<char, usize>::new();
+
+            for u in v {
+                for c in u.iter() {
+                    q.entry(*c).and_modify(|e| *e += 1).or_insert(1);
+                }
+            }
+
+            q.iter()
+                .map(|(c, n)| (*n == v.len()) as usize)
+                .sum::<usize>()
+        })
+        .sum::<usize>())
+}
@@ -0,0 +1,90 @@
+use anyhow::{anyhow, Result};
+use aoc_digraph::Digraph;
+use aoc_error::ParseError;
+use aoc_parse::{parse_lines, unsigned};
+use combine::parser::char::{char, letter, string};
+use combine::parser::range::recognize;
+use combine::{optional, sep_by1, skip_many1, Parser};
+
+const SHINY_GOLD: &str = "shiny gold";
+
+/// The rules for bags, with an edge from each bag to each bag it contains
+/// weighted by how many it contains.
+pub type Bags = Digraph<String, usize>;
+pub type BagContents = Vec<(String, usize)>;
+
+pub fn parse_bags(input: &str) -> Result<Bags, ParseError> {
+    let name =
+        || recognize((skip_many1(letter()), char(' '), skip_many1(letter()))).map(String::from);
+    let content = (
+        unsigned().skip(char(' ')),
+        name().skip(string(" bag")).skip(optional(char('s'))),
+    )
+        .map(|(n, bag)| (bag, n));
+    let contents =
+        sep_by1(content, string(", ")).or(string("no other bags").map(|_| BagContents::new()));
+
+    let rule = (
+        name().skip(string(" bags contain ")),
+        contents.skip(char('.')),
+    );
+
+    let mut bags = Bags::new();
+    for (bag, contents) in parse_lines(rule, input)? {
+        bags.add_vertex(bag.clone());
+
+        for (inner, n) in contents {
+            bags.add_edge(bag.clone(), inner, n);
+        }
+    }
+
+    Ok(bags)
+}
+
+/// The number of bags that can eventually contain a shiny gold bag.
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_bags(input)?.ancestors(&SHINY_GOLD.into()).len())
+}
+
+/// The number of bags inside a shiny gold bag.
+pub fn part2(input: &str) -> Result<usize> {
+    parse_bags(input)?
+        .path_sum(&SHINY_GOLD.into())
+        .ok_or_else(|| anyhow!("a bag inside a shiny gold bag contains itself"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+        assert_eq!(part1(input).unwrap(), 4);
+        assert_eq!(part2(input).unwrap(), 32);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let input = "shiny gold bags contain 1 dark olive bag.
+dark olive bags contain 1 vibrant plum bag.
+vibrant plum bags contain 1 dark olive bag.
+";
+
+        assert_eq!(part1(input).unwrap(), 0);
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "a bag inside a shiny gold bag contains itself"
+        );
+    }
+}
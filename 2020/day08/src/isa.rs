@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use aoc_error::ParseError;
+use aoc_parse::{parse_lines, signed};
+use combine::parser::char::{char, letter};
+use combine::parser::range::recognize;
+use combine::parser::repeat::skip_many1;
+use combine::Parser;
+
+/// The index of an instruction in an `InstructionSet`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Opcode(usize);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub value: isize,
+}
+
+/// What an instruction does with its argument.
+///
+/// Where an instruction jumps may only depend on its argument, so that the
+/// control flow of a program can be found without running it.
+#[derive(Clone, Copy, Debug)]
+pub struct Operation {
+    /// The new value of the accumulator, given the old value and the argument,
+    /// or `None` if it overflows.
+    pub accumulate: fn(isize, isize) -> Option<isize>,
+    /// The offset of the next instruction, given the argument.
+    pub jump: fn(isize) -> isize,
+}
+
+/// The instructions a program may use, by mnemonic.
+#[derive(Clone, Debug, Default)]
+pub struct InstructionSet {
+    mnemonics: Vec<String>,
+    operations: Vec<Operation>,
+    opcodes: HashMap<String, Opcode>,
+    /// The instruction that each instruction may have been corrupted from.
+    swaps: HashMap<Opcode, Opcode>,
+}
+
+impl InstructionSet {
+    /// A set without any instructions.
+    pub fn new() -> Self {
+        InstructionSet::default()
+    }
+
+    /// The handheld console's instructions, where a `jmp` may have been
+    /// corrupted into a `nop` or the other way around.
+    pub fn handheld() -> Self {
+        InstructionSet::new()
+            .with(
+                "acc",
+                Operation {
+                    accumulate: |acc, value| acc.checked_add(value),
+                    jump: |_| 1,
+                },
+            )
+            .with(
+                "jmp",
+                Operation {
+                    accumulate: |acc, _| Some(acc),
+                    jump: |value| value,
+                },
+            )
+            .with(
+                "nop",
+                Operation {
+                    accumulate: |acc, _| Some(acc),
+                    jump: |_| 1,
+                },
+            )
+            .swap("jmp", "nop")
+    }
+
+    /// Add an instruction, replacing the operation of any instruction that
+    /// already has the same mnemonic.
+    pub fn with(mut self, mnemonic: &str, operation: Operation) -> Self {
+        match self.opcodes.get(mnemonic) {
+            Some(&Opcode(i)) => self.operations[i] = operation,
+            None => {
+                self.opcodes
+                    .insert(mnemonic.into(), Opcode(self.operations.len()));
+                self.mnemonics.push(mnemonic.into());
+                self.operations.push(operation);
+            }
+        }
+
+        self
+    }
+
+    /// Allow either of two instructions to have been corrupted into the
+    /// other.
+    ///
+    /// Panics if either instruction is not in the set.
+    pub fn swap(mut self, a: &str, b: &str) -> Self {
+        let a = self.opcodes[a];
+        let b = self.opcodes[b];
+
+        self.swaps.insert(a, b);
+        self.swaps.insert(b, a);
+        self
+    }
+
+    pub fn opcode(&self, mnemonic: &str) -> Option<Opcode> {
+        self.opcodes.get(mnemonic).copied()
+    }
+
+    pub fn mnemonic(&self, opcode: Opcode) -> &str {
+        &self.mnemonics[opcode.0]
+    }
+
+    pub fn operation(&self, opcode: Opcode) -> Operation {
+        self.operations[opcode.0]
+    }
+
+    /// The instruction that `opcode` may have been corrupted from, if any.
+    pub fn swapped(&self, opcode: Opcode) -> Option<Opcode> {
+        self.swaps.get(&opcode).copied()
+    }
+
+    /// Format an instruction the way it is written in a program.
+    pub fn describe(&self, instr: &Instruction) -> String {
+        format!("{} {:+}", self.mnemonic(instr.opcode), instr.value)
+    }
+
+    /// Parse a program with an instruction on each line.
+    pub fn parse(&self, input: &str) -> Result<Vec<Instruction>, ParseError> {
+        let instruction = (recognize(skip_many1(letter())).skip(char(' ')), signed());
+
+        parse_lines(instruction, input)?
+            .into_iter()
+            .map(|(mnemonic, value): (&str, isize)| {
+                let opcode = self.opcode(mnemonic).ok_or_else(|| {
+                    ParseError::at_span(
+                        input,
+                        mnemonic,
+                        format!("unknown instruction `{}'", mnemonic),
+                    )
+                })?;
+
+                Ok(Instruction { opcode, value })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let isa = InstructionSet::handheld();
+        let program = isa.parse("nop +0\nacc -99\n").unwrap();
+
+        assert_eq!(program.len(), 2);
+        assert_eq!(isa.mnemonic(program[1].opcode), "acc");
+        assert_eq!(isa.describe(&program[0]), "nop +0");
+        assert_eq!(isa.swapped(program[0].opcode), isa.opcode("jmp"));
+        assert_eq!(isa.swapped(program[1].opcode), None);
+
+        let e = isa.parse("nop +0\nhcf +1\n").unwrap_err();
+        assert_eq!(e.message(), "unknown instruction `hcf'");
+        assert_eq!(e.location().unwrap().line, 2);
+
+        let isa = isa.with(
+            "mul",
+            Operation {
+                accumulate: |acc, value| acc.checked_mul(value),
+                jump: |_| 1,
+            },
+        );
+        let program = isa.parse("mul -2").unwrap();
+        let mul = isa.operation(program[0].opcode);
+        assert_eq!((mul.accumulate)(3, -2), Some(-6));
+        assert_eq!((mul.accumulate)(isize::MAX, -2), None);
+    }
+}
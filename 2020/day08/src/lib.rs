@@ -0,0 +1,41 @@
+mod isa;
+mod repair;
+mod vm;
+
+use anyhow::Result;
+
+pub use crate::isa::{Instruction, InstructionSet, Opcode, Operation};
+pub use crate::repair::{repair, Repair};
+pub use crate::vm::{Exit, Trace, Vm};
+
+/// The value of the accumulator just before any instruction runs twice.
+pub fn part1(input: &str) -> Result<isize> {
+    let isa = InstructionSet::handheld();
+    let program = isa.parse(input)?;
+
+    Ok(Vm::new(&isa, &program).trace()?.acc)
+}
+
+/// The value of the accumulator after the repaired program terminates.
+pub fn part2(input: &str) -> Result<isize> {
+    let isa = InstructionSet::handheld();
+    let mut program = isa.parse(input)?;
+
+    let fix = repair(&isa, &program)?;
+    program[fix.pc] = fix.instruction;
+
+    Ok(Vm::new(&isa, &program).trace()?.acc)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n";
+
+        assert_eq!(part1(input).unwrap(), 5);
+        assert_eq!(part2(input).unwrap(), 8);
+    }
+}
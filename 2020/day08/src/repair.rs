@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use aoc_digraph::Digraph;
+
+use crate::isa::{Instruction, InstructionSet};
+use crate::vm::next_pc;
+
+/// An instruction that makes a program terminate when it replaces the one at
+/// `pc`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Repair {
+    pub pc: usize,
+    pub instruction: Instruction,
+}
+
+/// Find the one corrupted instruction in `program`.
+///
+/// Only an instruction that runs before the program loops can be corrupted,
+/// and swapping it fixes the program when its new target can already reach
+/// the end, so this only has to look at each instruction a constant number of
+/// times.
+pub fn repair(isa: &InstructionSet, program: &[Instruction]) -> Result<Repair> {
+    let end = program.len();
+
+    let mut flow = Digraph::<usize>::new();
+    flow.add_vertex(end);
+    for (pc, instr) in program.iter().enumerate() {
+        flow.add_vertex(pc);
+        if let Ok(target) = next_pc(isa, end, pc, instr) {
+            flow.add_edge(pc, target, ());
+        }
+    }
+
+    let mut terminating = flow.ancestors(&end);
+    terminating.insert(end);
+
+    if terminating.contains(&0) {
+        return Err(anyhow!("the program already terminates"));
+    }
+
+    let mut pc = 0;
+    let mut visited = HashSet::new();
+
+    while visited.insert(pc) {
+        let instr = program[pc];
+
+        if let Some(opcode) = isa.swapped(instr.opcode) {
+            let instruction = Instruction { opcode, ..instr };
+
+            if let Ok(target) = next_pc(isa, end, pc, &instruction) {
+                if terminating.contains(&target) {
+                    return Ok(Repair { pc, instruction });
+                }
+            }
+        }
+
+        // The original program never reaches the end, so it either loops or
+        // jumps out of the program.
+        match next_pc(isa, end, pc, &instr) {
+            Ok(target) => pc = target,
+            Err(_) => break,
+        }
+    }
+
+    Err(anyhow!(
+        "no single instruction change makes the program terminate"
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_repair() {
+        let isa = InstructionSet::handheld();
+
+        let program = isa
+            .parse("nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n")
+            .unwrap();
+        let fix = repair(&isa, &program).unwrap();
+        assert_eq!(fix.pc, 7);
+        assert_eq!(isa.describe(&fix.instruction), "nop -4");
+
+        // Only the jump out of the program can be fixed.
+        let program = isa.parse("acc +1\njmp -7\nacc +1\n").unwrap();
+        let fix = repair(&isa, &program).unwrap();
+        assert_eq!(fix.pc, 1);
+
+        let program = isa.parse("nop +0\nacc +1\n").unwrap();
+        assert!(repair(&isa, &program).is_err());
+
+        let program = isa.parse("acc +1\njmp +0\nacc +1\njmp -1\n").unwrap();
+        assert_eq!(
+            repair(&isa, &program).unwrap_err().to_string(),
+            "no single instruction change makes the program terminate"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+
+use crate::isa::{Instruction, InstructionSet};
+
+/// Why a program stopped running.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Exit {
+    /// It ran past its last instruction.
+    Terminated,
+    /// It was about to run an instruction for a second time.
+    Looped,
+}
+
+/// The instructions that a program ran.
+#[derive(Clone, Debug)]
+pub struct Trace {
+    pub exit: Exit,
+    /// The accumulator when the program stopped.
+    pub acc: isize,
+    /// The address of each instruction, in the order that they ran.
+    pub path: Vec<usize>,
+    pub visited: HashSet<usize>,
+}
+
+pub struct Vm<'a> {
+    isa: &'a InstructionSet,
+    program: &'a [Instruction],
+    pc: usize,
+    acc: isize,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(isa: &'a InstructionSet, program: &'a [Instruction]) -> Self {
+        Vm {
+            isa,
+            program,
+            pc: 0,
+            acc: 0,
+        }
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn acc(&self) -> isize {
+        self.acc
+    }
+
+    pub fn is_terminated(&self) -> bool {
+        self.pc == self.program.len()
+    }
+
+    /// Run the next instruction.
+    pub fn step(&mut self) -> Result<()> {
+        let instr = self
+            .program
+            .get(self.pc)
+            .ok_or_else(|| anyhow!("the program has already terminated"))?;
+
+        let pc = next_pc(self.isa, self.program.len(), self.pc, instr)?;
+        let operation = self.isa.operation(instr.opcode);
+        let acc = (operation.accumulate)(self.acc, instr.value).ok_or_else(|| {
+            anyhow!(
+                "instruction {} (`{}') overflows the accumulator",
+                self.pc,
+                self.isa.describe(instr)
+            )
+        })?;
+
+        self.pc = pc;
+        self.acc = acc;
+
+        Ok(())
+    }
+
+    /// Run until the program terminates or is about to run an instruction for
+    /// a second time.
+    pub fn trace(&mut self) -> Result<Trace> {
+        let mut path = vec![];
+        let mut visited = HashSet::new();
+
+        let exit = loop {
+            if self.is_terminated() {
+                break Exit::Terminated;
+            }
+
+            if !visited.insert(self.pc) {
+                break Exit::Looped;
+            }
+
+            path.push(self.pc);
+            self.step()?;
+        };
+
+        Ok(Trace {
+            exit,
+            acc: self.acc,
+            path,
+            visited,
+        })
+    }
+}
+
+/// The address of the instruction that runs after `instr` at address `pc`,
+/// which is `len` if the program terminates.
+pub(crate) fn next_pc(
+    isa: &InstructionSet,
+    len: usize,
+    pc: usize,
+    instr: &Instruction,
+) -> Result<usize> {
+    let jump = (isa.operation(instr.opcode).jump)(instr.value);
+
+    (pc as isize)
+        .checked_add(jump)
+        .filter(|target| (0..=len as isize).contains(target))
+        .map(|target| target as usize)
+        .ok_or_else(|| {
+            // The target might not fit in an `isize`.
+            anyhow!(
+                "instruction {} (`{}') jumps to {}, outside of the program",
+                pc,
+                isa.describe(instr),
+                pc as i128 + jump as i128
+            )
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_trace() {
+        let isa = InstructionSet::handheld();
+
+        let program = isa.parse("acc +2\njmp +2\nacc +5\njmp -3\n").unwrap();
+        let trace = Vm::new(&isa, &program).trace().unwrap();
+        assert_eq!(trace.exit, Exit::Looped);
+        assert_eq!(trace.acc, 2);
+        assert_eq!(trace.path, vec![0, 1, 3]);
+        assert_eq!(trace.visited, [0, 1, 3].iter().copied().collect());
+
+        let program = isa.parse("acc +2\njmp +2\nacc +5\nacc +1\n").unwrap();
+        let mut vm = Vm::new(&isa, &program);
+        let trace = vm.trace().unwrap();
+        assert_eq!(trace.exit, Exit::Terminated);
+        assert_eq!(trace.acc, 3);
+        assert!(vm.step().is_err());
+
+        let program = isa.parse("nop +0\njmp -2\n").unwrap();
+        let e = Vm::new(&isa, &program).trace().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "instruction 1 (`jmp -2') jumps to -1, outside of the program"
+        );
+
+        let program = isa.parse("nop +0\njmp +9223372036854775807\n").unwrap();
+        let e = Vm::new(&isa, &program).trace().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "instruction 1 (`jmp +9223372036854775807') jumps to 9223372036854775808, \
+             outside of the program"
+        );
+
+        let program = isa.parse("acc +9223372036854775807\nacc +1\n").unwrap();
+        let e = Vm::new(&isa, &program).trace().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "instruction 1 (`acc +1') overflows the accumulator"
+        );
+    }
+}
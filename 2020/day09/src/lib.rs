@@ -0,0 +1,71 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::Result;
+use aoc_error::{parse_at, ParseError};
+
+/// The first number that is not the sum of two of the 25 numbers before it.
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(first_invalid(&parse_numbers(input)?))
+}
+
+/// The sum of the smallest and largest numbers in the contiguous range that
+/// sums to the invalid number.
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(encryption_weakness(&parse_numbers(input)?))
+}
+
+pub fn parse_numbers(input: &str) -> Result<Vec<usize>, ParseError> {
+    input.lines().map(|line| parse_at(input, line)).collect()
+}
+
+fn first_invalid(input: &[usize]) -> usize {
+    for i in 25..(input.len()) {
+        let sums = sums(&input[i - 25..i]);
+        if !sums.contains(&input[i]) {
+            return input[i];
+        }
+    }
+
+    unreachable!()
+}
+
+fn sums(input: &[usize]) -> HashSet<usize> {
+    let mut sums = HashSet::new();
+    for (i, x) in input.iter().enumerate() {
+        for (j, y) in input.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            sums.insert(x + y);
+        }
+    }
+    sums
+}
+
+fn encryption_weakness(input: &[usize]) -> usize {
+    let invalid = first_invalid(input);
+    let mut parts = VecDeque::new();
+    let mut sum = 0;
+
+    'outer: for val in input {
+        if sum == invalid {
+            break;
+        }
+        while sum + val > invalid {
+            if sum == 0 {
+                continue 'outer;
+            }
+
+            sum -= parts.pop_front().unwrap();
+        }
+
+        sum += val;
+        parts.push_back(val);
+    }
+
+    if sum == invalid {
+        **parts.iter().min().unwrap() + **parts.iter().max().unwrap()
+    } else {
+        unreachable!()
+    }
+}
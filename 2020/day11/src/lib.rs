@@ -0,0 +1,162 @@
+use std::io;
+
+use anyhow::Result;
+use aoc_automaton::{GridAutomaton, Life, Moore, Rule, Topology};
+use aoc_error::ParseError;
+use aoc_grid::{Grid, Point, ADJACENT};
+use aoc_visualize::{Disabled, Frame, Rgb, Visualizer};
+
+/// The number of occupied seats once people stop moving, considering adjacent
+/// seats.
+pub fn part1(input: &str) -> Result<usize> {
+    let mut seats = adjacent(parse_grid(input)?);
+    steady_state(&mut seats, &mut Disabled)?;
+
+    Ok(occupied_count(seats.grid()))
+}
+
+/// The number of occupied seats once people stop moving, considering visible
+/// seats.
+pub fn part2(input: &str) -> Result<usize> {
+    let mut seats = visible(parse_grid(input)?);
+    steady_state(&mut seats, &mut Disabled)?;
+
+    Ok(occupied_count(seats.grid()))
+}
+
+pub fn parse_grid(input: &str) -> Result<Grid<Cell>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(Cell::Occupied),
+        'L' => Some(Cell::Empty),
+        '.' => Some(Cell::Floor),
+        _ => None,
+    })
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cell {
+    Floor,
+    Occupied,
+    Empty,
+}
+
+pub fn occupied_count(grid: &Grid<Cell>) -> usize {
+    grid.cells().filter(|&&c| c == Cell::Occupied).count()
+}
+
+fn draw(grid: &Grid<Cell>) -> Frame {
+    let mut frame = Frame::new(grid.width(), grid.height());
+    for (p, cell) in grid.iter() {
+        let (x, y) = (p.x() as usize, p.y() as usize);
+        match cell {
+            Cell::Floor => frame.set(x, y, '.', Rgb::GREY),
+            Cell::Empty => frame.set(x, y, 'L', Rgb::GREEN),
+            Cell::Occupied => frame.set(x, y, '#', Rgb::RED),
+        }
+    }
+    frame
+}
+
+/// People sit in empty seats with no occupied seats next to them and leave
+/// occupied seats next to four or more occupied seats.
+pub fn adjacent(grid: Grid<Cell>) -> GridAutomaton<Moore<2>, Seating, Cell> {
+    GridAutomaton::new(Moore, Seating(Life::new(&[0], &[0, 1, 2, 3])), grid)
+}
+
+/// People sit in empty seats with no occupied seats in sight and leave
+/// occupied seats with five or more occupied seats in sight.
+pub fn visible(grid: Grid<Cell>) -> GridAutomaton<Sightlines, Seating, Cell> {
+    GridAutomaton::new(
+        Sightlines::new(&grid),
+        Seating(Life::new(&[0], &[0, 1, 2, 3, 4])),
+        grid,
+    )
+}
+
+/// Apply the rule until the seating stops changing.
+pub fn steady_state<T>(
+    seats: &mut GridAutomaton<T, Seating, Cell>,
+    visualizer: &mut dyn Visualizer,
+) -> io::Result<()>
+where
+    T: Topology<Cell = Point>,
+{
+    loop {
+        visualizer.emit(|| draw(seats.grid()))?;
+
+        if !seats.step() {
+            return Ok(());
+        }
+    }
+}
+
+/// A rule for occupied seats, which leaves the floor alone.
+pub struct Seating(Life);
+
+impl Rule<Cell> for Seating {
+    fn next<I>(&self, cell: Cell, neighbours: I) -> Cell
+    where
+        I: Iterator<Item = Cell>,
+    {
+        let occupied = self.0.next(
+            cell == Cell::Occupied,
+            neighbours.map(|n| n == Cell::Occupied),
+        );
+
+        match cell {
+            Cell::Floor => Cell::Floor,
+            _ if occupied => Cell::Occupied,
+            _ => Cell::Empty,
+        }
+    }
+}
+
+/// Seats neighbour the first seat that can be seen in each direction.
+pub struct Sightlines(Grid<Vec<Point>>);
+
+impl Sightlines {
+    fn new(grid: &Grid<Cell>) -> Self {
+        let mut sightlines = Grid::new(grid.width(), grid.height(), vec![]);
+
+        for p in grid.points() {
+            sightlines[p] = ADJACENT
+                .iter()
+                .filter_map(|d| grid.ray(p, *d).find(|q| grid[*q] != Cell::Floor))
+                .collect();
+        }
+
+        Sightlines(sightlines)
+    }
+}
+
+impl Topology for Sightlines {
+    type Cell = Point;
+
+    fn neighbours(&self, p: Point) -> impl Iterator<Item = Point> {
+        self.0[p].iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+";
+
+        assert_eq!(part1(input).unwrap(), 37);
+        assert_eq!(part2(input).unwrap(), 26);
+    }
+}
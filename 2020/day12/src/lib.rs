@@ -0,0 +1,88 @@
+use anyhow::Result;
+use aoc_error::{parse_at, ParseError};
+use aoc_geometry::{Heading, Point};
+
+pub type Direction = (char, isize);
+
+pub fn parse_directions(input: &str) -> Result<Vec<Direction>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let action = line
+                .chars()
+                .next()
+                .filter(|c| "NESWLRF".contains(*c))
+                .ok_or_else(|| {
+                    ParseError::at_span(input, line, "expected a direction, e.g., `F10'")
+                })?;
+            let value = parse_at(input, &line[1..])?;
+
+            if (action == 'L' || action == 'R') && value % 90 != 0 {
+                return Err(ParseError::at_span(
+                    input,
+                    line,
+                    format!(
+                        "cannot turn by {} degrees; expected a multiple of 90",
+                        value
+                    ),
+                ));
+            }
+
+            Ok((action, value))
+        })
+        .collect()
+}
+
+/// The Manhattan distance travelled by the ship following the directions.
+pub fn part1(input: &str) -> Result<isize> {
+    Ok(move_ship(&parse_directions(input)?))
+}
+
+/// The Manhattan distance travelled by the ship following the waypoint.
+pub fn part2(input: &str) -> Result<isize> {
+    Ok(move_waypoint(&parse_directions(input)?))
+}
+
+fn move_ship(input: &[Direction]) -> isize {
+    let mut facing = Heading::East;
+    let mut pos = Point::new(0, 0);
+
+    for &(c, dist) in input {
+        match c {
+            'L' => facing = facing.turn(-dist),
+            'R' => facing = facing.turn(dist),
+            'F' => pos += facing.vector() * dist,
+            _ => pos += Heading::from_char(c).unwrap().vector() * dist,
+        }
+    }
+
+    pos.manhattan()
+}
+
+fn move_waypoint(input: &[Direction]) -> isize {
+    let mut waypoint_offset = Heading::East.vector() * 10 + Heading::North.vector();
+    let mut ship = Point::new(0, 0);
+
+    for &(c, dist) in input {
+        match c {
+            'L' => waypoint_offset = waypoint_offset.rotate(-dist),
+            'R' => waypoint_offset = waypoint_offset.rotate(dist),
+            'F' => ship += waypoint_offset * dist,
+            _ => waypoint_offset += Heading::from_char(c).unwrap().vector() * dist,
+        }
+    }
+
+    ship.manhattan()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "F10\nN3\nF7\nR90\nF11\n";
+        assert_eq!(part1(input).unwrap(), 25);
+        assert_eq!(part2(input).unwrap(), 286);
+    }
+}
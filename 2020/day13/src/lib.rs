@@ -0,0 +1,94 @@
+use anyhow::{anyhow, Result};
+use aoc_error::{parse_at, ParseError};
+use aoc_math::crt;
+
+/// The ID of the earliest bus multiplied by the time to wait for it.
+pub fn part1(input: &str) -> Result<isize> {
+    Ok(earliest_bus(&parse_notes(input)?))
+}
+
+/// The earliest timestamp at which each bus departs at its offset.
+pub fn part2(input: &str) -> Result<u64> {
+    earliest_timestamp(&parse_notes(input)?.1)
+}
+
+pub fn parse_notes(input: &str) -> Result<(isize, Vec<Option<isize>>), ParseError> {
+    let mut lines = input.lines();
+
+    let ts = parse_at(
+        input,
+        lines
+            .next()
+            .ok_or_else(|| ParseError::new("missing timestamp"))?,
+    )?;
+
+    let ids = lines
+        .next()
+        .ok_or_else(|| ParseError::new("missing bus IDs"))?
+        .split(",")
+        .map(|s| {
+            if s == "x" {
+                Ok(None)
+            } else {
+                Ok(Some(parse_at(input, s)?))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((ts, ids))
+}
+
+fn earliest_bus(input: &(isize, Vec<Option<isize>>)) -> isize {
+    let ts = input.0;
+    let ids = &(input.1);
+
+    let ids = ids.iter().filter_map(|x| *x).collect::<Vec<_>>();
+
+    let (id, next) = ids
+        .iter()
+        .map(|id| {
+            let last = (ts / id);
+            let mut next = last * id;
+            while next < ts {
+                next += id;
+            }
+            (id, next)
+        })
+        .min_by_key(|(_, next)| *next)
+        .unwrap();
+
+    (next - ts) * id
+}
+
+fn earliest_timestamp(input: &[Option<isize>]) -> Result<u64> {
+    // Bus `id` departs `offset` minutes after `t` when `t = -offset (mod id)`.
+    let congruences = input
+        .iter()
+        .enumerate()
+        .filter_map(|(offset, id)| {
+            id.map(|id| {
+                let id = id as u64;
+                ((id - offset as u64 % id) % id, id)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    crt(&congruences)
+        .map(|(t, _)| t)
+        .ok_or_else(|| anyhow!("the buses never depart at their offsets"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "939\n7,13,x,x,59,x,31,19\n";
+        assert_eq!(part1(input).unwrap(), 295);
+        assert_eq!(part2(input).unwrap(), 1068781);
+
+        assert_eq!(part2("0\n17,x,13,19\n").unwrap(), 3417);
+        assert_eq!(part2("0\n1789,37,47,1889\n").unwrap(), 1202161486);
+    }
+}
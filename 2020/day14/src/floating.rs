@@ -0,0 +1,166 @@
+/// A set of addresses where some bits are fixed and the rest may be either
+/// value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pattern {
+    /// The value of each fixed bit. Floating bits are always zero.
+    fixed: u64,
+    floating: u64,
+}
+
+impl Pattern {
+    pub fn new(fixed: u64, floating: u64) -> Self {
+        Pattern {
+            fixed: fixed & !floating,
+            floating,
+        }
+    }
+
+    /// The pattern that only matches `addr`.
+    pub fn exact(addr: u64) -> Self {
+        Pattern::new(addr, 0)
+    }
+
+    /// The number of addresses in the pattern.
+    pub fn count(&self) -> u128 {
+        1 << self.floating.count_ones()
+    }
+
+    pub fn contains(&self, addr: u64) -> bool {
+        addr & !self.floating == self.fixed
+    }
+
+    /// The addresses in both patterns, if there are any.
+    pub fn intersection(&self, other: &Pattern) -> Option<Pattern> {
+        let fixed = !self.floating & !other.floating;
+
+        if (self.fixed ^ other.fixed) & fixed != 0 {
+            None
+        } else {
+            Some(Pattern {
+                fixed: self.fixed | other.fixed,
+                floating: self.floating & other.floating,
+            })
+        }
+    }
+
+    /// The addresses in this pattern but not `other`, as disjoint patterns.
+    pub fn difference(&self, other: &Pattern) -> Vec<Pattern> {
+        if self.intersection(other).is_none() {
+            return vec![*self];
+        }
+
+        // Fix each bit that floats here but not in `other` one at a time. The
+        // half that disagrees with `other` is outside of it and the half that
+        // agrees carries on to the next bit.
+        let mut rest = *self;
+        let mut pieces = vec![];
+        let mut bits = self.floating & !other.floating;
+
+        while bits != 0 {
+            let bit = bits & bits.wrapping_neg();
+            bits &= !bit;
+
+            rest.floating &= !bit;
+            pieces.push(Pattern {
+                fixed: rest.fixed | (!other.fixed & bit),
+                floating: rest.floating,
+            });
+            rest.fixed |= other.fixed & bit;
+        }
+
+        pieces
+    }
+}
+
+/// Memory where each write goes to every address in a pattern.
+///
+/// Writes are kept as disjoint patterns, so memory can hold far more
+/// addresses than there are writes.
+#[derive(Clone, Debug, Default)]
+pub struct FloatingMemory {
+    writes: Vec<(Pattern, u64)>,
+}
+
+impl FloatingMemory {
+    pub fn new() -> Self {
+        FloatingMemory::default()
+    }
+
+    /// Write `value` to every address in `pattern`, replacing what was there.
+    pub fn write(&mut self, pattern: Pattern, value: u64) {
+        let mut writes = Vec::with_capacity(self.writes.len() + 1);
+
+        for (old, old_value) in self.writes.drain(..) {
+            writes.extend(old.difference(&pattern).into_iter().map(|p| (p, old_value)));
+        }
+
+        writes.push((pattern, value));
+        self.writes = writes;
+    }
+
+    pub fn get(&self, addr: u64) -> Option<u64> {
+        self.writes
+            .iter()
+            .find(|(pattern, _)| pattern.contains(addr))
+            .map(|&(_, value)| value)
+    }
+
+    /// The number of addresses that have been written to.
+    pub fn len(&self) -> u128 {
+        self.writes.iter().map(|(pattern, _)| pattern.count()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    /// The sum of the values at every address.
+    pub fn sum(&self) -> u128 {
+        self.writes
+            .iter()
+            .map(|&(pattern, value)| pattern.count() * value as u128)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_difference() {
+        let a = Pattern::new(0b0000, 0b1111);
+        let b = Pattern::new(0b1010, 0b0101);
+        let c = Pattern::new(0b0001, 0b1000);
+
+        assert_eq!(a.intersection(&b), Some(b));
+        assert_eq!(b.intersection(&c), None);
+        assert_eq!(a.intersection(&c), Some(c));
+
+        for (x, y) in [(a, b), (a, c), (b, a), (b, c), (c, b)] {
+            let pieces = x.difference(&y);
+
+            for addr in 0..16 {
+                let found = pieces.iter().filter(|p| p.contains(addr)).count();
+                let expected = (x.contains(addr) && !y.contains(addr)) as usize;
+                assert_eq!(found, expected, "{:?} - {:?} at {}", x, y, addr);
+            }
+        }
+    }
+
+    #[test]
+    fn test_memory() {
+        let all = (1 << 36) - 1;
+
+        let mut mem = FloatingMemory::new();
+        mem.write(Pattern::new(0, all), 3);
+        mem.write(Pattern::new(1, all - 1), 5);
+        mem.write(Pattern::exact(2), 7);
+
+        assert_eq!(mem.len(), 1 << 36);
+        assert_eq!(mem.sum(), (1 << 35) * 3 + (1 << 35) * 5 + 7 - 3);
+        assert_eq!(mem.get(2), Some(7));
+        assert_eq!(mem.get(all), Some(5));
+        assert_eq!(mem.get(1 << 36), None);
+    }
+}
@@ -0,0 +1,200 @@
+mod floating;
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc_error::ParseError;
+use aoc_parse::{parse_lines, unsigned};
+use combine::parser::char::string;
+use combine::{attempt, count_min_max, one_of, Parser};
+
+pub use crate::floating::{FloatingMemory, Pattern};
+
+pub enum Instruction {
+    Mask(Mask),
+    Update(u64, u64),
+}
+
+/// A mask as and/or bitmasks.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Mask {
+    /// The `1` bits.
+    ones: u64,
+    /// The `X` bits.
+    floating: u64,
+}
+
+impl Mask {
+    /// Build a mask from a string of `0`, `1` and `X`, most significant bit
+    /// first.
+    ///
+    /// Panics if there are any other characters in `bits`.
+    pub fn new(bits: &str) -> Self {
+        bits.chars().fold(Mask::default(), |mask, c| {
+            let (one, floating) = match c {
+                '0' => (0, 0),
+                '1' => (1, 0),
+                'X' => (0, 1),
+                _ => panic!("invalid mask bit `{}'", c),
+            };
+
+            Mask {
+                ones: mask.ones << 1 | one,
+                floating: mask.floating << 1 | floating,
+            }
+        })
+    }
+
+    /// Overwrite the bits of `x` that are not floating.
+    pub fn apply(&self, x: u64) -> u64 {
+        (x & self.floating) | self.ones
+    }
+
+    /// The addresses that `addr` decodes to.
+    pub fn decode(&self, addr: u64) -> Pattern {
+        Pattern::new(addr | self.ones, self.floating)
+    }
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mask = attempt(string("mask = "))
+        .with(count_min_max::<String, _, _>(36, 36, one_of("01X".chars())))
+        .map(|bits| Instruction::Mask(Mask::new(&bits)));
+    let update = (
+        string("mem[").with(unsigned()),
+        string("] = ").with(unsigned()),
+    )
+        .map(|(addr, val)| Instruction::Update(addr, val));
+
+    parse_lines(mask.or(update), input)
+}
+
+/// The sum of the values in memory after running the program with value masks.
+pub fn part1(input: &str) -> Result<u64> {
+    let mut mask = Mask::default();
+    let mut mem = HashMap::<u64, u64>::new();
+
+    for instruction in parse_instructions(input)? {
+        match instruction {
+            Instruction::Mask(m) => mask = m,
+            Instruction::Update(addr, val) => {
+                mem.insert(addr, mask.apply(val));
+            }
+        }
+    }
+
+    Ok(mem.values().sum::<u64>())
+}
+
+/// The sum of the values in memory after running the program with address
+/// masks.
+pub fn part2(input: &str) -> Result<u128> {
+    let mut mask = Mask::default();
+    let mut mem = FloatingMemory::new();
+
+    for instruction in parse_instructions(input)? {
+        match instruction {
+            Instruction::Mask(m) => mask = m,
+            Instruction::Update(addr, val) => mem.write(mask.decode(addr), val),
+        }
+    }
+
+    Ok(mem.sum())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_apply_mask() {
+        let mask = Mask::new("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X");
+        assert_eq!(mask.apply(11), 73);
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut mem = FloatingMemory::new();
+        let mask = Mask::new(&"1".repeat(36));
+
+        mem.write(mask.decode(42), 100);
+        assert_eq!(mem.get(0b111111111111111111111111111111111111), Some(100));
+        assert_eq!(mem.len(), 1);
+
+        let mask = Mask::new(&"X".repeat(36));
+        mem.write(mask.decode(42), 1);
+        assert_eq!(mem.len(), 1 << 36);
+        assert_eq!(mem.sum(), 1 << 36);
+    }
+
+    #[test]
+    fn test_part2_sample() {
+        let mut mem = FloatingMemory::new();
+        mem.write(
+            Mask::new("000000000000000000000000000000X1001X").decode(42),
+            100,
+        );
+
+        for addr in [26, 27, 58, 59] {
+            assert_eq!(mem.get(addr), Some(100));
+        }
+        assert_eq!(mem.len(), 4);
+
+        mem.write(
+            Mask::new("00000000000000000000000000000000X0XX").decode(26),
+            1,
+        );
+
+        for addr in [16, 17, 18, 19, 24, 25, 26, 27] {
+            assert_eq!(mem.get(addr), Some(1));
+        }
+        assert_eq!(mem.get(58), Some(100));
+        assert_eq!(mem.get(59), Some(100));
+        assert_eq!(mem.len(), 10);
+        assert_eq!(mem.sum(), 208);
+    }
+
+    #[test]
+    fn test_generated() {
+        let mut rng = aoc_generate::rng(14);
+
+        for _ in 0..20 {
+            let input = aoc_generate::y2020::bitmask_program(&mut rng, 100);
+
+            let (mut ones, mut floating) = (0, 0);
+            let mut values = HashMap::new();
+            let mut addresses = HashMap::new();
+
+            for line in input.lines() {
+                if let Some(mask) = line.strip_prefix("mask = ") {
+                    ones = u64::from_str_radix(&mask.replace('X', "0"), 2).unwrap();
+                    floating =
+                        u64::from_str_radix(&mask.replace('1', "0").replace('X', "1"), 2).unwrap();
+                } else {
+                    let (addr, val) = line["mem[".len()..].split_once("] = ").unwrap();
+                    let addr = addr.parse::<u64>().unwrap();
+                    let val = val.parse::<u64>().unwrap();
+
+                    values.insert(addr, (val & floating) | ones);
+
+                    // Visit every subset of the floating bits.
+                    let base = (addr | ones) & !floating;
+                    let mut bits = floating;
+                    loop {
+                        addresses.insert(base | bits, val);
+                        if bits == 0 {
+                            break;
+                        }
+                        bits = (bits - 1) & floating;
+                    }
+                }
+            }
+
+            assert_eq!(part1(&input).unwrap(), values.values().sum::<u64>());
+            assert_eq!(
+                part2(&input).unwrap(),
+                addresses.values().sum::<u64>() as u128
+            );
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc_error::ParseError;
+use aoc_parse::parse_separated;
+
+/// The 2020th number spoken.
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(run(&parse_numbers(input)?, 2020))
+}
+
+/// The 30000000th number spoken.
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(run(&parse_numbers(input)?, 30000000))
+}
+
+pub fn parse_numbers(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse_separated(input.trim(), ',')
+}
+
+/// The `target`th number spoken, starting with the given numbers.
+pub fn run(input: &[usize], target: usize) -> usize {
+    // Keep track of terms said previously, *except* when that term is the most
+    // recent. Since we always know the most recent term is at index `i - 1`,
+    // we only need to store a single entry for each number to know when it was
+    // previously said.
+    //
+    // If we stored the *all* entries here (including `most_recent =
+    // input[input.len() - 1]`) and `most_recent` appeared previously in the
+    // input (i.e., before `input.len() - 1`) we would get an incorrect result
+    // due to not knowing the previous time that it was said.
+    let mut last_spoken = input
+        .iter()
+        .take(input.len() - 1)
+        .enumerate()
+        .map(|(i, v)| (*v, i))
+        .collect::<HashMap<usize, usize>>();
+
+    let mut most_recent = input[input.len() - 1];
+    for i in input.len()..target {
+        most_recent = match last_spoken.entry(most_recent) {
+            Entry::Occupied(mut e) => {
+                let next = i - 1 - e.get();
+                *e.get_mut() = i - 1;
+
+                next
+            }
+
+            Entry::Vacant(e) => {
+                e.insert(i - 1);
+                0
+            }
+        };
+    }
+    most_recent
+}
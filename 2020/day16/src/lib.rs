@@ -0,0 +1,156 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use anyhow::Result;
+use aoc_error::ParseError;
+use aoc_parse::{lines, parse_all, section, unsigned};
+use combine::parser::char::{char, newline, string};
+use combine::parser::range::recognize;
+use combine::{none_of, sep_by1, skip_many1, Parser};
+
+#[derive(Debug)]
+pub struct Rule {
+    range1: RangeInclusive<usize>,
+    range2: RangeInclusive<usize>,
+}
+
+impl Rule {
+    fn valid(&self, value: usize) -> bool {
+        self.range1.contains(&value) || self.range2.contains(&value)
+    }
+}
+
+pub type RuleSet = HashMap<String, Rule>;
+
+#[derive(Clone, Debug)]
+pub struct Ticket(Vec<usize>);
+
+impl Ticket {
+    fn valid(&self, ruleset: &RuleSet) -> bool {
+        'value: for value in &self.0 {
+            for rule in ruleset.values() {
+                if rule.valid(*value) {
+                    continue 'value;
+                }
+            }
+
+            return false;
+        }
+
+        true
+    }
+}
+
+/// The ticket scanning error rate of the nearby tickets.
+pub fn part1(input: &str) -> Result<usize> {
+    let (ruleset, ticket, nearby_tickets) = parse_notes(input)?;
+
+    Ok(error_rate(&ruleset, &ticket, &nearby_tickets))
+}
+
+/// The product of the departure fields on your ticket.
+pub fn part2(input: &str) -> Result<usize> {
+    let (ruleset, ticket, nearby_tickets) = parse_notes(input)?;
+
+    Ok(departure_product(&ruleset, &ticket, &nearby_tickets))
+}
+
+pub fn parse_notes(input: &str) -> Result<(RuleSet, Ticket, Vec<Ticket>), ParseError> {
+    let range = || (unsigned(), char('-').with(unsigned())).map(|(a, b)| a..=b);
+    let rule = (
+        recognize(skip_many1(none_of(":\n".chars()))).map(String::from),
+        string(": ").with(range()),
+        string(" or ").with(range()),
+    )
+        .map(|(name, range1, range2)| (name, Rule { range1, range2 }));
+    let ticket = || sep_by1(unsigned::<usize, _>(), char(',')).map(Ticket);
+
+    let notes = (
+        lines(rule).skip(newline()),
+        section(string("your ticket"), ticket().skip(newline())).skip(newline()),
+        section(string("nearby tickets"), lines(ticket())),
+    )
+        .map(|(rules, (_, ticket), (_, nearby))| (rules.into_iter().collect(), ticket, nearby));
+
+    parse_all(notes, input)
+}
+
+fn error_rate(ruleset: &RuleSet, _ticket: &Ticket, nearby_tickets: &[Ticket]) -> usize {
+    let mut errors = 0;
+    for ticket in nearby_tickets {
+        'value: for value in &ticket.0 {
+            for rule in ruleset.values() {
+                if rule.valid(*value) {
+                    continue 'value;
+                }
+            }
+
+            errors += value;
+        }
+    }
+
+    errors
+}
+
+fn departure_product(ruleset: &RuleSet, ticket: &Ticket, nearby_tickets: &[Ticket]) -> usize {
+    let nearby_tickets = nearby_tickets
+        .iter()
+        .filter(|t| t.valid(ruleset))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let fields = {
+        let mut fields = ruleset.keys().map(String::from).collect::<Vec<_>>();
+        fields.sort();
+        fields
+    };
+
+    let domains: Vec<Vec<&str>> = (0..ticket.0.len())
+        .map(|i| {
+            fields
+                .iter()
+                .filter_map(|field| {
+                    let rule = ruleset.get(field).unwrap();
+                    for ticket in &nearby_tickets {
+                        if !rule.valid(ticket.0[i]) {
+                            return None;
+                        }
+                    }
+                    Some(field.as_str())
+                })
+                .collect()
+        })
+        .collect();
+
+    let most_constrained: Vec<usize> = {
+        let mut indices = (0..ticket.0.len()).collect::<Vec<_>>();
+        indices.sort_by_key(|idx| domains[*idx].len());
+        indices
+    };
+
+    let mut assignment = HashMap::<&str, usize>::new();
+
+    for i in &most_constrained {
+        for field in domains[*i].iter() {
+            match assignment.entry(field) {
+                Entry::Occupied(..) => continue,
+                Entry::Vacant(e) => {
+                    e.insert(*i);
+                    break;
+                }
+            }
+        }
+    }
+
+    assignment
+        .iter()
+        .filter_map(|(field, index)| {
+            if field.starts_with("departure") {
+                Some(ticket.0[*index])
+            } else {
+                None
+            }
+        })
+        .product()
+}
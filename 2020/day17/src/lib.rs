@@ -0,0 +1,119 @@
+use std::io;
+
+use anyhow::Result;
+use aoc_automaton::{Life, MirroredLife};
+use aoc_error::ParseError;
+use aoc_geometry::{BoundingBox, Vector};
+use aoc_visualize::{Disabled, Frame, Rgb, Visualizer};
+
+/// The number of active cubes after six cycles in three dimensions.
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(boot::<3>(&parse_coords(input)?, &mut Disabled)?)
+}
+
+/// The number of active cubes after six cycles in four dimensions.
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(boot::<4>(&parse_coords(input)?, &mut Disabled)?)
+}
+
+/// Run the six cycle boot process in `N` dimensions, returning the number of
+/// active cubes.
+///
+/// The cubes start on the plane where every axis past the first two is zero,
+/// so they stay symmetric under reflection in each of those axes.
+pub fn boot<const N: usize>(
+    input: &[(isize, isize)],
+    visualizer: &mut dyn Visualizer,
+) -> io::Result<usize> {
+    let extra_axes: Vec<usize> = (2..N).collect();
+    let mut cubes = MirroredLife::new(
+        Life::new(&[3], &[2, 3]),
+        &extra_axes,
+        input.iter().map(|&(x, y)| {
+            let mut p = Vector::<N>::ZERO;
+            p[0] = x;
+            p[1] = y;
+            p
+        }),
+    );
+
+    for _ in 0..6 {
+        visualizer.emit(|| draw(&cubes))?;
+        cubes.step();
+    }
+    visualizer.emit(|| draw(&cubes))?;
+
+    Ok(cubes.population())
+}
+
+/// The coordinates of the initially active cubes.
+pub fn parse_coords(input: &str) -> Result<Vec<(isize, isize)>, ParseError> {
+    let mut coords = vec![];
+    for (y, line) in input.lines().enumerate() {
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => coords.push((x as isize, y as isize)),
+                '.' => {}
+                _ => {
+                    return Err(ParseError::at_span(
+                        input,
+                        &line[i..i + c.len_utf8()],
+                        format!("expected `#' or `.', got `{}'", c),
+                    ))
+                }
+            }
+        }
+    }
+    Ok(coords)
+}
+
+/// Draw each xy-slice of the cubes, with z increasing to the right and w
+/// increasing downwards.
+fn draw<const N: usize>(cubes: &MirroredLife<N>) -> Frame {
+    let bounds = cubes
+        .bounds()
+        .unwrap_or_else(|| BoundingBox::new(Vector::ZERO));
+    let size = bounds.size();
+
+    // The axes past the last dimension only have one slice.
+    let len = |axis: usize| if axis < N { size[axis] as usize } else { 1 };
+    let offset = |p: Vector<N>, axis: usize| {
+        if axis < N {
+            (p[axis] - bounds.min[axis]) as usize
+        } else {
+            0
+        }
+    };
+
+    let (width, height) = (len(0), len(1));
+    let mut frame = Frame::new(len(2) * (width + 1) - 1, len(3) * (height + 1) - 1);
+
+    for p in bounds.points() {
+        let fx = offset(p, 2) * (width + 1) + offset(p, 0);
+        let fy = offset(p, 3) * (height + 1) + offset(p, 1);
+
+        if cubes.is_alive(p) {
+            frame.set(fx, fy, '#', Rgb::GREEN);
+        } else {
+            frame.set(fx, fy, '.', Rgb::GREY);
+        }
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_boot() {
+        let input = parse_coords(".#.\n..#\n###\n").unwrap();
+
+        assert_eq!(boot::<2>(&input, &mut Disabled).unwrap(), 5);
+        assert_eq!(boot::<3>(&input, &mut Disabled).unwrap(), 112);
+        assert_eq!(boot::<4>(&input, &mut Disabled).unwrap(), 848);
+        assert_eq!(boot::<5>(&input, &mut Disabled).unwrap(), 5760);
+        assert_eq!(boot::<6>(&input, &mut Disabled).unwrap(), 35936);
+    }
+}
@@ -0,0 +1,172 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use aoc_error::ParseError;
+
+use crate::table::OperatorTable;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+impl BinaryOp {
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Some(match symbol {
+            "+" => BinaryOp::Add,
+            "-" => BinaryOp::Sub,
+            "*" => BinaryOp::Mul,
+            "/" => BinaryOp::Div,
+            "^" => BinaryOp::Pow,
+            _ => return None,
+        })
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Pow => "^",
+        }
+    }
+
+    fn apply(self, lhs: i64, rhs: i64) -> Result<i64, String> {
+        let result = match self {
+            BinaryOp::Add => lhs.checked_add(rhs),
+            BinaryOp::Sub => lhs.checked_sub(rhs),
+            BinaryOp::Mul => lhs.checked_mul(rhs),
+            BinaryOp::Div if rhs == 0 => return Err("division by zero".into()),
+            BinaryOp::Div => lhs.checked_div(rhs),
+            BinaryOp::Pow if rhs < 0 => {
+                return Err(format!("negative exponent in {} ^ {}", lhs, rhs))
+            }
+            BinaryOp::Pow => u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_pow(rhs)),
+        };
+
+        result.ok_or_else(|| format!("overflow in {} {} {}", lhs, self.symbol(), rhs))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UnaryOp {
+    Neg,
+}
+
+impl UnaryOp {
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "-" => Some(UnaryOp::Neg),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            UnaryOp::Neg => "-",
+        }
+    }
+
+    fn apply(self, operand: i64) -> Result<i64, String> {
+        match self {
+            UnaryOp::Neg => operand
+                .checked_neg()
+                .ok_or_else(|| format!("overflow in -{}", operand)),
+        }
+    }
+}
+
+/// A parsed expression. Parentheses only affect the shape of the tree.
+///
+/// Operators keep the byte offset of their symbol in the input, so errors
+/// while evaluating them can point at it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expr {
+    Number(i64),
+    Unary(UnaryOp, usize, Box<Expr>),
+    Binary(BinaryOp, usize, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Evaluate the expression that was parsed from `input`.
+    pub fn eval(&self, input: &str) -> Result<i64, ParseError> {
+        match self {
+            Expr::Number(n) => Ok(*n),
+            Expr::Unary(op, offset, operand) => op
+                .apply(operand.eval(input)?)
+                .map_err(|e| ParseError::at(input, *offset, e)),
+            Expr::Binary(op, offset, lhs, rhs) => op
+                .apply(lhs.eval(input)?, rhs.eval(input)?)
+                .map_err(|e| ParseError::at(input, *offset, e)),
+        }
+    }
+
+    /// Display the expression with only the parentheses needed for it to
+    /// parse the same way with `table`, which must have every operator in it.
+    pub fn pretty<'a>(&'a self, table: &'a OperatorTable) -> Pretty<'a> {
+        Pretty { expr: self, table }
+    }
+}
+
+/// An expression displayed with the fewest parentheses.
+pub struct Pretty<'a> {
+    expr: &'a Expr,
+    table: &'a OperatorTable,
+}
+
+impl Pretty<'_> {
+    /// Write `expr` between operators that bind to it with `left` and `right`
+    /// power, or zero if there is no operator on that side.
+    fn write(&self, f: &mut fmt::Formatter, expr: &Expr, left: u32, right: u32) -> fmt::Result {
+        match expr {
+            Expr::Number(n) => write!(f, "{}", n),
+
+            Expr::Unary(op, _, operand) => {
+                let power = self.table.prefix_power(*op).unwrap();
+
+                // The operator on the right must not take the operand.
+                if right >= power {
+                    write!(f, "({}", op.symbol())?;
+                    self.write(f, operand, power, 0)?;
+                    f.write_str(")")
+                } else {
+                    f.write_str(op.symbol())?;
+                    self.write(f, operand, power, right)
+                }
+            }
+
+            Expr::Binary(op, _, lhs, rhs) => {
+                let (lhs_power, rhs_power) = self.table.infix_power(*op).unwrap();
+
+                // Neither neighbouring operator may take an operand.
+                let parens = lhs_power < left || right >= rhs_power;
+                let (left, right) = if parens { (0, 0) } else { (left, right) };
+
+                if parens {
+                    f.write_str("(")?;
+                }
+
+                self.write(f, lhs, left, lhs_power)?;
+                write!(f, " {} ", op.symbol())?;
+                self.write(f, rhs, rhs_power, right)?;
+
+                if parens {
+                    f.write_str(")")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for Pretty<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, self.expr, 0, 0)
+    }
+}
@@ -0,0 +1,135 @@
+//! An expression language with configurable operator precedence.
+
+mod expr;
+mod parser;
+mod table;
+
+use anyhow::Result;
+
+pub use crate::expr::{BinaryOp, Expr, Pretty, UnaryOp};
+pub use crate::parser::{parse, parse_lines};
+pub use crate::table::{Assoc, OperatorTable};
+
+/// The sum of every expression when addition and multiplication have the
+/// same precedence.
+pub fn part1(input: &str) -> Result<i64> {
+    sum(input, &OperatorTable::flat())
+}
+
+/// The sum of every expression when addition binds tighter than
+/// multiplication.
+pub fn part2(input: &str) -> Result<i64> {
+    sum(input, &OperatorTable::advanced())
+}
+
+/// The sum of every expression in `input`, one per line.
+pub fn sum(input: &str, table: &OperatorTable) -> Result<i64> {
+    let exprs = parse_lines(input, table)?;
+
+    Ok(exprs
+        .iter()
+        .map(|expr| expr.eval(input))
+        .sum::<Result<_, _>>()?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLES: &[(&str, i64, i64)] = &[
+        ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+        ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+        ("2 * 3 + (4 * 5)", 26, 46),
+        ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+        ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+        (
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+            13632,
+            23340,
+        ),
+    ];
+
+    /// The expression with every operator at offset 0, to compare the shapes of
+    /// expressions parsed from different text.
+    fn shape(expr: &Expr) -> Expr {
+        match expr {
+            Expr::Number(n) => Expr::Number(*n),
+            Expr::Unary(op, _, operand) => Expr::Unary(*op, 0, Box::new(shape(operand))),
+            Expr::Binary(op, _, lhs, rhs) => {
+                Expr::Binary(*op, 0, Box::new(shape(lhs)), Box::new(shape(rhs)))
+            }
+        }
+    }
+
+    #[test]
+    fn test_parts() {
+        for &(input, flat, advanced) in SAMPLES {
+            assert_eq!(part1(input).unwrap(), flat, "{}", input);
+            assert_eq!(part2(input).unwrap(), advanced, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_standard() {
+        let standard = OperatorTable::standard();
+        let eval = |input| parse(input, &standard).unwrap().eval(input);
+
+        assert_eq!(eval("7 - 2 - 3").unwrap(), 2);
+        assert_eq!(eval("2 ^ 3 ^ 2").unwrap(), 512);
+        assert_eq!(eval("-2 ^ 2").unwrap(), -4);
+        assert_eq!(eval("(-2) ^ 2").unwrap(), 4);
+        assert_eq!(eval("2 * -3 + 20 / 3").unwrap(), 0);
+
+        let error = |input| {
+            let e = eval(input).unwrap_err();
+            (e.message().to_string(), e.location().unwrap().column)
+        };
+
+        assert_eq!(error("1 / (2 - 2)"), ("division by zero".into(), 3));
+        assert_eq!(error("2 ^ 64"), ("overflow in 2 ^ 64".into(), 3));
+        assert_eq!(
+            error("1 + 2 ^ -1"),
+            ("negative exponent in 2 ^ -1".into(), 7)
+        );
+        assert_eq!(
+            error("-(-9223372036854775807 - 1)"),
+            ("overflow in --9223372036854775808".into(), 1)
+        );
+
+        let e = sum("1 + 2\n3 * (4 / 0)\n", &standard).unwrap_err();
+        assert_eq!(
+            e.to_string().lines().next().unwrap(),
+            "division by zero at line 2, column 8"
+        );
+    }
+
+    #[test]
+    fn test_pretty() {
+        let pretty = |input, table: &OperatorTable| {
+            let expr = parse(input, table).unwrap();
+            let pretty = expr.pretty(table).to_string();
+            assert_eq!(shape(&parse(&pretty, table).unwrap()), shape(&expr));
+            pretty
+        };
+
+        let standard = OperatorTable::standard();
+        assert_eq!(pretty("((1 + 2)) + (3 + 4)", &standard), "1 + 2 + (3 + 4)");
+        assert_eq!(pretty("(2 ^ 3) ^ (2 ^ 1)", &standard), "(2 ^ 3) ^ 2 ^ 1");
+        assert_eq!(
+            pretty("(-2) ^ 2 * -(3 - 1)", &standard),
+            "(-2) ^ 2 * -(3 - 1)"
+        );
+        assert_eq!(
+            pretty("-(2 ^ 2) - --(3 * 4)", &standard),
+            "-2 ^ 2 - --(3 * 4)"
+        );
+
+        let advanced = OperatorTable::advanced();
+        assert_eq!(
+            pretty(SAMPLES[3].0, &advanced),
+            "5 + (8 * 3 + 9 + 3 * 4 * 3)"
+        );
+        assert_eq!(pretty("(2 * 3) + (4 * 5)", &advanced), "(2 * 3) + (4 * 5)");
+        assert_eq!(pretty("(2 + 3) * (4 + 5)", &advanced), "2 + 3 * 4 + 5");
+    }
+}
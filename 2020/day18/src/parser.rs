@@ -0,0 +1,275 @@
+use std::iter::Peekable;
+use std::ops::Range;
+use std::str::CharIndices;
+
+use aoc_error::ParseError;
+
+use crate::expr::{BinaryOp, Expr, UnaryOp};
+use crate::table::OperatorTable;
+
+/// Parse each line of `input` as an expression.
+pub fn parse_lines(input: &str, table: &OperatorTable) -> Result<Vec<Expr>, ParseError> {
+    let mut offset = 0;
+    let mut exprs = vec![];
+
+    for line in input.split_inclusive('\n') {
+        let span = offset..offset + line.trim_end().len();
+        offset += line.len();
+
+        if !span.is_empty() {
+            exprs.push(parse_span(input, span, table)?);
+        }
+    }
+
+    Ok(exprs)
+}
+
+/// Parse all of `input` as a single expression.
+pub fn parse(input: &str, table: &OperatorTable) -> Result<Expr, ParseError> {
+    parse_span(input, 0..input.trim_end().len(), table)
+}
+
+fn parse_span(input: &str, span: Range<usize>, table: &OperatorTable) -> Result<Expr, ParseError> {
+    let mut parser = Parser {
+        input,
+        tokens: Lexer {
+            input,
+            start: span.start,
+            chars: input[span.clone()].char_indices().peekable(),
+        }
+        .peekable(),
+        end: span.end,
+        table,
+    };
+
+    let expr = parser.expr(0)?;
+    match parser.peek()? {
+        None => Ok(expr),
+        Some(token) => Err(ParseError::at(input, token.offset, "unmatched `)'")),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind<'a> {
+    Number(i64),
+    Operator(&'a str),
+    Open,
+    Close,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: Kind<'a>,
+    /// The byte offset of the token in the input.
+    offset: usize,
+}
+
+/// Splits an expression into tokens. Every character that isn't a digit,
+/// parenthesis or whitespace is an operator.
+struct Lexer<'a> {
+    input: &'a str,
+    start: usize,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Result<Token<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+
+        let (i, c) = self.chars.next()?;
+        let offset = self.start + i;
+
+        let kind = match c {
+            '(' => Kind::Open,
+            ')' => Kind::Close,
+            '0'..='9' => {
+                let mut end = offset + 1;
+                while let Some((j, _)) = self.chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                    end = self.start + j + 1;
+                }
+
+                match aoc_error::parse_at(self.input, &self.input[offset..end]) {
+                    Ok(n) => Kind::Number(n),
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            _ => Kind::Operator(&self.input[offset..offset + c.len_utf8()]),
+        };
+
+        Some(Ok(Token { kind, offset }))
+    }
+}
+
+/// A Pratt parser, which parses an operand and then keeps taking operators
+/// that bind more tightly than the operator before it.
+struct Parser<'a, 't> {
+    input: &'a str,
+    tokens: Peekable<Lexer<'a>>,
+    /// The byte offset of the end of the expression.
+    end: usize,
+    table: &'t OperatorTable,
+}
+
+impl<'a> Parser<'a, '_> {
+    /// Parse an expression where every operator binds with at least `power`.
+    fn expr(&mut self, power: u32) -> Result<Expr, ParseError> {
+        let mut lhs = self.operand()?;
+
+        while let Some(token) = self.peek()? {
+            let op = match token.kind {
+                Kind::Close => break,
+                Kind::Operator(symbol) => BinaryOp::from_symbol(symbol),
+                Kind::Number(..) | Kind::Open => return Err(self.unexpected(&token, "an operator")),
+            };
+
+            let (op, (lhs_power, rhs_power)) = op
+                .and_then(|op| Some((op, self.table.infix_power(op)?)))
+                .ok_or_else(|| self.unexpected(&token, "an infix operator"))?;
+
+            if lhs_power < power {
+                break;
+            }
+
+            self.tokens.next();
+            let rhs = self.expr(rhs_power)?;
+
+            lhs = Expr::Binary(op, token.offset, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    /// Parse a number, a parenthesized expression or a prefix operator and
+    /// its operand.
+    fn operand(&mut self) -> Result<Expr, ParseError> {
+        let token = match self.tokens.next().transpose()? {
+            Some(token) => token,
+            None => return Err(ParseError::at(self.input, self.end, "expected an operand")),
+        };
+
+        match token.kind {
+            Kind::Number(n) => Ok(Expr::Number(n)),
+
+            Kind::Open => {
+                let expr = self.expr(0)?;
+
+                match self.tokens.next().transpose()? {
+                    Some(Token {
+                        kind: Kind::Close, ..
+                    }) => Ok(expr),
+                    _ => Err(ParseError::at(self.input, token.offset, "unmatched `('")),
+                }
+            }
+
+            Kind::Operator(symbol) => {
+                let (op, power) = UnaryOp::from_symbol(symbol)
+                    .and_then(|op| Some((op, self.table.prefix_power(op)?)))
+                    .ok_or_else(|| self.unexpected(&token, "an operand"))?;
+
+                Ok(Expr::Unary(op, token.offset, Box::new(self.expr(power)?)))
+            }
+
+            Kind::Close => Err(self.unexpected(&token, "an operand")),
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<Token<'a>>, ParseError> {
+        match self.tokens.peek() {
+            Some(Err(e)) => Err(e.clone()),
+            Some(Ok(token)) => Ok(Some(*token)),
+            None => Ok(None),
+        }
+    }
+
+    fn unexpected(&self, token: &Token, expected: &str) -> ParseError {
+        let text = match token.kind {
+            Kind::Number(..) => "number".to_string(),
+            Kind::Operator(symbol) => format!("`{}'", symbol),
+            Kind::Open => "`('".to_string(),
+            Kind::Close => "`)'".to_string(),
+        };
+
+        ParseError::at(
+            self.input,
+            token.offset,
+            format!("expected {}, got {}", expected, text),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn n(n: i64) -> Box<Expr> {
+        Box::new(Expr::Number(n))
+    }
+
+    #[test]
+    fn test_parse() {
+        let standard = OperatorTable::standard();
+
+        assert_eq!(
+            parse("-2 ^ 3 ^ 2", &standard).unwrap(),
+            Expr::Unary(
+                UnaryOp::Neg,
+                0,
+                Box::new(Expr::Binary(
+                    BinaryOp::Pow,
+                    3,
+                    n(2),
+                    Box::new(Expr::Binary(BinaryOp::Pow, 7, n(3), n(2)))
+                ))
+            )
+        );
+
+        assert_eq!(
+            parse("10 - 4 - (3)", &standard).unwrap(),
+            Expr::Binary(
+                BinaryOp::Sub,
+                7,
+                Box::new(Expr::Binary(BinaryOp::Sub, 3, n(10), n(4))),
+                n(3)
+            )
+        );
+
+        assert_eq!(
+            parse_lines("1 + 2\n\n3\n4 * 5", &OperatorTable::flat()).unwrap(),
+            vec![
+                Expr::Binary(BinaryOp::Add, 2, n(1), n(2)),
+                Expr::Number(3),
+                Expr::Binary(BinaryOp::Mul, 11, n(4), n(5))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let flat = OperatorTable::flat();
+        let error = |input| {
+            let e = parse_lines(input, &flat).unwrap_err();
+            (e.message().to_string(), e.location().unwrap().column)
+        };
+
+        assert_eq!(
+            error("1 + 2\n3 - 4"),
+            ("expected an infix operator, got `-'".into(), 3)
+        );
+        assert_eq!(error("1 + (2 * 3"), ("unmatched `('".into(), 5));
+        assert_eq!(error("1 + 2)"), ("unmatched `)'".into(), 6));
+        assert_eq!(error("1 + * 2"), ("expected an operand, got `*'".into(), 5));
+        assert_eq!(error("1 2"), ("expected an operator, got number".into(), 3));
+        assert_eq!(error("1 +\n"), ("expected an operand".into(), 4));
+        assert_eq!(error("-1"), ("expected an operand, got `-'".into(), 1));
+        assert_eq!(
+            error("99999999999999999999"),
+            (
+                "could not parse `99999999999999999999': number too large to fit in target type"
+                    .into(),
+                1
+            )
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_error::ParseError;
+
+use crate::expr::{BinaryOp, UnaryOp};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Assoc {
+    Left,
+    Right,
+}
+
+/// Which operators an expression may use and how tightly they bind.
+///
+/// Operators with a higher precedence bind tighter. Operators that are not in
+/// the table are syntax errors.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OperatorTable {
+    infix: HashMap<BinaryOp, (u32, Assoc)>,
+    prefix: HashMap<UnaryOp, u32>,
+}
+
+impl OperatorTable {
+    /// A table without any operators.
+    pub fn new() -> Self {
+        OperatorTable::default()
+    }
+
+    /// Addition and multiplication with the same precedence.
+    pub fn flat() -> Self {
+        "left 1 + *".parse().unwrap()
+    }
+
+    /// Addition binding tighter than multiplication.
+    pub fn advanced() -> Self {
+        "left 1 *\nleft 2 +".parse().unwrap()
+    }
+
+    /// The usual arithmetic operators, where exponentiation binds tighter than
+    /// negation.
+    pub fn standard() -> Self {
+        "left 1 + -\nleft 2 * /\nprefix 3 -\nright 4 ^"
+            .parse()
+            .unwrap()
+    }
+
+    pub fn infix(mut self, op: BinaryOp, precedence: u32, assoc: Assoc) -> Self {
+        self.infix.insert(op, (precedence, assoc));
+        self
+    }
+
+    pub fn prefix(mut self, op: UnaryOp, precedence: u32) -> Self {
+        self.prefix.insert(op, precedence);
+        self
+    }
+
+    /// How strongly `op` binds to the expressions on its left and right, or
+    /// `None` if it is not in the table.
+    ///
+    /// Operators on the left of an expression take it if their right binding
+    /// power is at least the left binding power of the operator on its right.
+    pub(crate) fn infix_power(&self, op: BinaryOp) -> Option<(u32, u32)> {
+        let &(precedence, assoc) = self.infix.get(&op)?;
+        let power = 2 * precedence + 1;
+
+        Some(match assoc {
+            Assoc::Left => (power, power + 1),
+            Assoc::Right => (power + 1, power),
+        })
+    }
+
+    /// How strongly `op` binds to the expression on its right, or `None` if
+    /// it is not in the table.
+    pub(crate) fn prefix_power(&self, op: UnaryOp) -> Option<u32> {
+        self.prefix.get(&op).map(|precedence| 2 * precedence + 1)
+    }
+}
+
+impl FromStr for OperatorTable {
+    type Err = ParseError;
+
+    /// Parse a table with a line for each group of operators that share a
+    /// precedence, e.g., `left 2 * /`.
+    ///
+    /// Each line is `left`, `right` or `prefix`, then a precedence, then the
+    /// operators. Blank lines and lines starting with `#` are ignored.
+    fn from_str(config: &str) -> Result<Self, ParseError> {
+        let mut table = OperatorTable::new();
+
+        for line in config.lines() {
+            let mut words = line.split_whitespace();
+
+            let fixity = match words.next() {
+                None => continue,
+                Some(word) if word.starts_with('#') => continue,
+                Some(word) => word,
+            };
+
+            let precedence = match words.next() {
+                Some(word) => aoc_error::parse_at(config, word)?,
+                None => {
+                    return Err(ParseError::at(
+                        config,
+                        aoc_error::offset_of(config, line).unwrap() + line.len(),
+                        "expected a precedence",
+                    ))
+                }
+            };
+
+            let assoc = match fixity {
+                "left" => Some(Assoc::Left),
+                "right" => Some(Assoc::Right),
+                "prefix" => None,
+                _ => {
+                    return Err(ParseError::at_span(
+                        config,
+                        fixity,
+                        format!("expected `left', `right' or `prefix', got `{}'", fixity),
+                    ))
+                }
+            };
+
+            for symbol in words {
+                let unknown = || {
+                    ParseError::at_span(config, symbol, format!("unknown operator `{}'", symbol))
+                };
+
+                table = match assoc {
+                    Some(assoc) => {
+                        let op = BinaryOp::from_symbol(symbol).ok_or_else(unknown)?;
+                        table.infix(op, precedence, assoc)
+                    }
+                    None => {
+                        let op = UnaryOp::from_symbol(symbol).ok_or_else(unknown)?;
+                        table.prefix(op, precedence)
+                    }
+                };
+            }
+        }
+
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let table: OperatorTable = "# Exponents first.\nright 3 ^\n\nleft 1 + -\nprefix 2 -\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            table,
+            OperatorTable::new()
+                .infix(BinaryOp::Pow, 3, Assoc::Right)
+                .infix(BinaryOp::Add, 1, Assoc::Left)
+                .infix(BinaryOp::Sub, 1, Assoc::Left)
+                .prefix(UnaryOp::Neg, 2)
+        );
+
+        let e = "left 1 +\nright 2 %".parse::<OperatorTable>().unwrap_err();
+        assert_eq!(e.message(), "unknown operator `%'");
+        assert_eq!(e.location().unwrap().column, 9);
+
+        let e = "left 1 +\nprefix 2 *".parse::<OperatorTable>().unwrap_err();
+        assert_eq!(e.message(), "unknown operator `*'");
+
+        let e = "infix 1 +".parse::<OperatorTable>().unwrap_err();
+        assert_eq!(e.location().unwrap().column, 1);
+
+        let e = "left".parse::<OperatorTable>().unwrap_err();
+        assert_eq!(e.message(), "expected a precedence");
+    }
+}
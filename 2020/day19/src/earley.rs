@@ -0,0 +1,351 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+use crate::{Production, Rules};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Symbol {
+    Char(char),
+    /// The index of a rule in the grammar.
+    Rule(usize),
+}
+
+/// A set of rules that can recognize messages with an Earley parser.
+///
+/// Rules can refer to each other in any way, including recursively, but no
+/// rule may match an empty message.
+#[derive(Clone, Debug)]
+pub struct Grammar {
+    /// The alternatives for each rule, which are each a sequence of symbols.
+    rules: Vec<Vec<Vec<Symbol>>>,
+    /// The index of each numbered rule. Nested alternatives are given their
+    /// own unnumbered rules.
+    indices: HashMap<usize, usize>,
+}
+
+/// A message that matched a rule.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Match {
+    pub rule: usize,
+    /// Which of the rule's alternatives matched, in the order they were
+    /// written. If more than one did, this is the first.
+    pub alternative: usize,
+}
+
+/// Why a message did not match a rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    /// The length of the longest prefix of the message that could begin a
+    /// match.
+    pub position: usize,
+    /// The characters that could have come next.
+    pub expected: BTreeSet<char>,
+    /// The character that came next instead, or `None` if the message ended.
+    pub found: Option<char>,
+}
+
+/// An item in the Earley chart: an alternative of a rule that started at
+/// `origin`, matched up to `dot`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Item {
+    rule: usize,
+    alternative: usize,
+    dot: usize,
+    origin: usize,
+}
+
+impl Item {
+    fn advance(self) -> Self {
+        Item {
+            dot: self.dot + 1,
+            ..self
+        }
+    }
+}
+
+/// The items at one position of the message, in the order they were added.
+#[derive(Default)]
+struct ItemSet {
+    items: Vec<Item>,
+    seen: HashSet<Item>,
+}
+
+impl ItemSet {
+    fn insert(&mut self, item: Item) {
+        if self.seen.insert(item) {
+            self.items.push(item);
+        }
+    }
+}
+
+impl Grammar {
+    pub fn new(rules: &Rules) -> Result<Self> {
+        let mut ids: Vec<usize> = rules.keys().copied().collect();
+        ids.sort_unstable();
+
+        let mut grammar = Grammar {
+            rules: vec![vec![]; ids.len()],
+            indices: ids.iter().enumerate().map(|(i, &id)| (id, i)).collect(),
+        };
+
+        for (i, id) in ids.into_iter().enumerate() {
+            grammar.rules[i] = grammar
+                .alternatives(&rules[&id])
+                .map_err(|e| anyhow!("rule {}: {}", id, e))?;
+        }
+
+        Ok(grammar)
+    }
+
+    /// The alternatives of `production`, adding rules for any alternatives
+    /// nested inside of it.
+    fn alternatives(&mut self, production: &Production) -> Result<Vec<Vec<Symbol>>> {
+        let alternatives = match production {
+            Production::Or(ps) => ps.iter().collect(),
+            p => vec![p],
+        };
+
+        alternatives
+            .into_iter()
+            .map(|alternative| {
+                let symbols = match alternative {
+                    Production::Seq(ps) => ps.iter().collect(),
+                    p => vec![p],
+                };
+
+                if symbols.is_empty() {
+                    return Err(anyhow!("empty sequence"));
+                }
+
+                symbols.into_iter().map(|p| self.symbol(p)).collect()
+            })
+            .collect()
+    }
+
+    fn symbol(&mut self, production: &Production) -> Result<Symbol> {
+        match production {
+            Production::Char(c) => Ok(Symbol::Char(*c)),
+            Production::Ref(id) => self
+                .indices
+                .get(id)
+                .map(|&i| Symbol::Rule(i))
+                .ok_or_else(|| anyhow!("no rule {}", id)),
+            p => {
+                let alternatives = self.alternatives(p)?;
+                self.rules.push(alternatives);
+
+                Ok(Symbol::Rule(self.rules.len() - 1))
+            }
+        }
+    }
+
+    /// Whether there is a rule numbered `rule`.
+    pub fn contains(&self, rule: usize) -> bool {
+        self.indices.contains_key(&rule)
+    }
+
+    /// Whether all of `message` matches the rule numbered `rule`.
+    pub fn is_match(&self, rule: usize, message: &str) -> bool {
+        self.recognize(rule, message).is_ok()
+    }
+
+    /// Match all of `message` against the rule numbered `rule`.
+    ///
+    /// Panics if there is no such rule; see `contains`.
+    pub fn recognize(&self, rule: usize, message: &str) -> Result<Match, Mismatch> {
+        let start = self.indices[&rule];
+        let message: Vec<char> = message.chars().collect();
+
+        let mut chart: Vec<ItemSet> = Vec::with_capacity(message.len() + 1);
+        chart.resize_with(message.len() + 1, ItemSet::default);
+
+        for alternative in 0..self.rules[start].len() {
+            chart[0].insert(Item {
+                rule: start,
+                alternative,
+                dot: 0,
+                origin: 0,
+            });
+        }
+
+        for i in 0..=message.len() {
+            // Items are added to the set while it is processed.
+            let mut j = 0;
+            while j < chart[i].items.len() {
+                let item = chart[i].items[j];
+                j += 1;
+
+                match self.next_symbol(&item) {
+                    // Complete: every item waiting on this rule moves past it.
+                    None => {
+                        for k in 0..chart[item.origin].items.len() {
+                            let parent = chart[item.origin].items[k];
+                            if self.next_symbol(&parent) == Some(Symbol::Rule(item.rule)) {
+                                chart[i].insert(parent.advance());
+                            }
+                        }
+                    }
+
+                    // Predict: start every alternative of the next rule here.
+                    Some(Symbol::Rule(rule)) => {
+                        for alternative in 0..self.rules[rule].len() {
+                            chart[i].insert(Item {
+                                rule,
+                                alternative,
+                                dot: 0,
+                                origin: i,
+                            });
+                        }
+                    }
+
+                    // Scan: move past the next character if it matches.
+                    Some(Symbol::Char(c)) => {
+                        if message.get(i) == Some(&c) {
+                            chart[i + 1].insert(item.advance());
+                        }
+                    }
+                }
+            }
+        }
+
+        let matched = chart[message.len()]
+            .items
+            .iter()
+            .filter(|item| {
+                item.rule == start && item.origin == 0 && self.next_symbol(item).is_none()
+            })
+            .map(|item| item.alternative)
+            .min();
+
+        if let Some(alternative) = matched {
+            return Ok(Match { rule, alternative });
+        }
+
+        let position = (0..=message.len())
+            .rev()
+            .find(|&i| !chart[i].items.is_empty())
+            .unwrap();
+
+        let expected = chart[position]
+            .items
+            .iter()
+            .filter_map(|item| match self.next_symbol(item) {
+                Some(Symbol::Char(c)) => Some(c),
+                _ => None,
+            })
+            .collect();
+
+        Err(Mismatch {
+            position,
+            expected,
+            found: message.get(position).copied(),
+        })
+    }
+
+    fn next_symbol(&self, item: &Item) -> Option<Symbol> {
+        self.rules[item.rule][item.alternative]
+            .get(item.dot)
+            .copied()
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let expected = self
+            .expected
+            .iter()
+            .map(|c| format!("`{}'", c))
+            .collect::<Vec<_>>();
+
+        if expected.is_empty() {
+            write!(f, "expected the end of the message")?;
+        } else {
+            write!(f, "expected {}", expected.join(" or "))?;
+        }
+
+        match self.found {
+            Some(c) => write!(f, " at position {}, got `{}'", self.position, c),
+            None => write!(
+                f,
+                " at position {}, got the end of the message",
+                self.position
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_recognize() {
+        let (rules, _) = parse_input(
+            "0: 4 1 5 | 4 4\n1: 2 3 | 3 2\n2: 4 4 | 5 5\n3: 4 5 | 5 4\n4: \"a\"\n5: \"b\"\n\nab\n",
+        )
+        .unwrap();
+        let grammar = Grammar::new(&rules).unwrap();
+
+        assert_eq!(
+            grammar.recognize(0, "ababbb"),
+            Ok(Match {
+                rule: 0,
+                alternative: 0
+            })
+        );
+        assert_eq!(
+            grammar.recognize(0, "aa"),
+            Ok(Match {
+                rule: 0,
+                alternative: 1
+            })
+        );
+        assert_eq!(grammar.recognize(3, "ba").unwrap().alternative, 1);
+
+        let mismatch = grammar.recognize(0, "abbbbb").unwrap_err();
+        assert_eq!(mismatch.position, 4);
+        assert_eq!(mismatch.to_string(), "expected `a' at position 4, got `b'");
+
+        let mismatch = grammar.recognize(0, "abab").unwrap_err();
+        assert_eq!(mismatch.position, 4);
+        assert_eq!(mismatch.expected, ['b'].iter().copied().collect());
+        assert_eq!(mismatch.found, None);
+
+        let mismatch = grammar.recognize(3, "aba").unwrap_err();
+        assert_eq!(
+            mismatch.to_string(),
+            "expected the end of the message at position 2, got `a'"
+        );
+    }
+
+    #[test]
+    fn test_recursive() {
+        // a^n b^n, for n >= 1.
+        let mut rules = Rules::new();
+        rules.insert(
+            0,
+            Production::Or(vec![
+                Production::Seq(vec![Production::Ref(1), Production::Ref(2)]),
+                Production::Seq(vec![
+                    Production::Ref(1),
+                    Production::Ref(0),
+                    Production::Ref(2),
+                ]),
+            ]),
+        );
+        rules.insert(1, Production::Char('a'));
+        rules.insert(2, Production::Char('b'));
+
+        let grammar = Grammar::new(&rules).unwrap();
+        assert!(grammar.is_match(0, "aaaabbbb"));
+        assert!(!grammar.is_match(0, "aaaabbb"));
+        assert!(!grammar.is_match(0, ""));
+
+        rules.insert(3, Production::Ref(4));
+        let e = Grammar::new(&rules).unwrap_err();
+        assert_eq!(e.to_string(), "rule 3: no rule 4");
+    }
+}
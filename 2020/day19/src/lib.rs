@@ -0,0 +1,181 @@
+mod earley;
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use aoc_parse::{lines, parse_all, unsigned};
+use combine::parser::char::{char, digit, newline};
+use combine::parser::combinator::{attempt, look_ahead};
+use combine::parser::range::range;
+use combine::parser::repeat::{many1, sep_by1};
+use combine::parser::token::{any, none_of};
+use combine::{ParseError, Parser, RangeStream};
+
+pub use crate::earley::{Grammar, Match, Mismatch};
+
+/// The number of messages that completely match rule 0.
+pub fn part1(input: &str) -> Result<usize> {
+    let (rules, messages) = parse_input(input)?;
+
+    count_matches(&rules, &messages)
+}
+
+/// The number of messages that completely match rule 0 once rules 8 and 11
+/// loop.
+pub fn part2(input: &str) -> Result<usize> {
+    let (mut rules, messages) = parse_input(input)?;
+
+    let seq = |ids: &[usize]| Production::Seq(ids.iter().copied().map(Production::Ref).collect());
+    rules.insert(8, Production::Or(vec![seq(&[42]), seq(&[42, 8])]));
+    rules.insert(11, Production::Or(vec![seq(&[42, 31]), seq(&[42, 11, 31])]));
+
+    count_matches(&rules, &messages)
+}
+
+fn count_matches(rules: &Rules, messages: &[String]) -> Result<usize> {
+    let grammar = Grammar::new(rules)?;
+    if !grammar.contains(0) {
+        return Err(anyhow!("there is no rule 0"));
+    }
+
+    Ok(messages
+        .iter()
+        .filter(|message| grammar.is_match(0, message))
+        .count())
+}
+
+pub type Rules = HashMap<usize, Production>;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Production {
+    Char(char),
+    Ref(usize),
+    Or(Vec<Production>),
+    Seq(Vec<Production>),
+}
+
+pub fn parse_input(input: &str) -> Result<(Rules, Vec<String>), aoc_error::ParseError> {
+    let message = many1(none_of("\n".chars()));
+    let (rules, messages) = parse_all((lines(rule()).skip(newline()), lines(message)), input)?;
+
+    Ok((rules.into_iter().collect(), messages))
+}
+
+fn rule<'a, I>() -> impl Parser<I, Output = (usize, Production)> + 'a
+where
+    I: RangeStream<Token = char, Range = &'a str> + 'a,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+{
+    let string = char('"').with(any()).skip(char('"')).map(Production::Char);
+
+    let ref_seq = sep_by1(
+        unsigned().map(Production::Ref),
+        attempt(char(' ').skip(look_ahead(digit()))),
+    )
+    .map(|mut refs: Vec<Production>| {
+        if refs.len() == 1 {
+            refs.pop().unwrap()
+        } else {
+            Production::Seq(refs)
+        }
+    });
+
+    let ref_seq_or = sep_by1(ref_seq, range(" | ")).map(|mut seqs: Vec<Production>| {
+        if seqs.len() == 1 {
+            seqs.pop().unwrap()
+        } else {
+            Production::Or(seqs)
+        }
+    });
+
+    (unsigned().skip(range(": ")), string.or(ref_seq_or))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE: &str = "42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+";
+
+    #[test]
+    fn test_parse() {
+        let (rules, messages) = parse_input("0: 4 1 5\n1: 2 3 | 3 2\n4: \"a\"\n\nab\n").unwrap();
+
+        assert_eq!(
+            rules[&0],
+            Production::Seq(vec![
+                Production::Ref(4),
+                Production::Ref(1),
+                Production::Ref(5)
+            ])
+        );
+        assert_eq!(
+            rules[&1],
+            Production::Or(vec![
+                Production::Seq(vec![Production::Ref(2), Production::Ref(3)]),
+                Production::Seq(vec![Production::Ref(3), Production::Ref(2)]),
+            ])
+        );
+        assert_eq!(rules[&4], Production::Char('a'));
+        assert_eq!(messages, vec!["ab"]);
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(part1(SAMPLE).unwrap(), 3);
+        assert_eq!(part2(SAMPLE).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_missing_rule_0() {
+        let input = "1: \"a\"\n\na\n";
+        assert_eq!(part1(input).unwrap_err().to_string(), "there is no rule 0");
+    }
+}
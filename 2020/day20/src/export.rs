@@ -0,0 +1,120 @@
+use aoc_visualize::{Frame, Rgb};
+
+use crate::pattern::Match;
+use crate::Puzzle;
+
+/// The width and height of each pixel of a tile.
+const SCALE: usize = 4;
+
+/// The space above each tile for its ID.
+const LABEL_HEIGHT: usize = 7;
+
+/// The space around each tile.
+const GAP: usize = 2;
+
+const SET: Rgb = Rgb::WHITE;
+const UNSET: Rgb = Rgb(24, 24, 48);
+const BORDER_SET: Rgb = Rgb::GREY;
+const BORDER_UNSET: Rgb = Rgb(48, 48, 48);
+
+/// The colour of the pixels matched by each pattern, in turn.
+const MATCHED: [Rgb; 4] = [Rgb::GREEN, Rgb::RED, Rgb::BLUE, Rgb::YELLOW];
+
+/// Draw the puzzle tile by tile, with each tile's border, the pixels that
+/// matched a pattern highlighted and each tile's ID above it.
+pub fn draw(puzzle: &Puzzle, matches: &[Match]) -> Frame {
+    let tile_size = puzzle.tiles[0].size();
+    let grid_size = tile_size - 2;
+    let (tile_width, tile_height) = (
+        tile_size * SCALE + GAP,
+        LABEL_HEIGHT + tile_size * SCALE + GAP,
+    );
+
+    let mut frame = Frame::new(
+        GAP + puzzle.side * tile_width,
+        GAP + puzzle.side * tile_height,
+    );
+
+    // The top left of the first pixel of each tile.
+    let origin = |i: usize| {
+        (
+            GAP + i % puzzle.side * tile_width,
+            GAP + i / puzzle.side * tile_height + LABEL_HEIGHT,
+        )
+    };
+
+    let fill = |frame: &mut Frame, (x, y): (usize, usize), set: bool, colour: Rgb| {
+        let glyph = if set { '#' } else { '.' };
+        for dy in 0..SCALE {
+            for dx in 0..SCALE {
+                frame.set(x + dx, y + dy, glyph, colour);
+            }
+        }
+    };
+
+    for (i, (&id, tile)) in puzzle.ids.iter().zip(&puzzle.tiles).enumerate() {
+        let (left, top) = origin(i);
+        frame.draw_number(left, top - LABEL_HEIGHT + 1, id as u64, Rgb::YELLOW);
+
+        for y in 0..tile_size {
+            for x in 0..tile_size {
+                let set = tile.pixel(y, x);
+                let border = y == 0 || x == 0 || y == tile_size - 1 || x == tile_size - 1;
+                let colour = match (border, set) {
+                    (false, true) => SET,
+                    (false, false) => UNSET,
+                    (true, true) => BORDER_SET,
+                    (true, false) => BORDER_UNSET,
+                };
+
+                fill(&mut frame, (left + x * SCALE, top + y * SCALE), set, colour);
+            }
+        }
+    }
+
+    for m in matches {
+        let colour = MATCHED[m.pattern % MATCHED.len()];
+
+        for &(y, x) in &m.pixels {
+            let (left, top) = origin(y / grid_size * puzzle.side + x / grid_size);
+            let (y, x) = (1 + y % grid_size, 1 + x % grid_size);
+
+            fill(
+                &mut frame,
+                (left + x * SCALE, top + y * SCALE),
+                true,
+                colour,
+            );
+        }
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{assemble_image, find_matches, parse_tiles, Pattern};
+
+    #[test]
+    fn test_draw() {
+        let mut rng = aoc_generate::rng(20);
+        let input = aoc_generate::y2020::jigsaw(&mut rng, 2, 12, 1);
+        let puzzle = assemble_image(&parse_tiles(&input).unwrap()).unwrap();
+        let matches = find_matches(&puzzle.image(), &[Pattern::sea_monster()]);
+        assert_eq!(matches.len(), 1);
+
+        let frame = draw(&puzzle, &matches);
+        assert_eq!(frame.width(), 2 + 2 * (12 * 4 + 2));
+        assert_eq!(frame.height(), 2 + 2 * (7 + 12 * 4 + 2));
+
+        let cells = frame.rows().flatten();
+        assert_eq!(
+            cells.filter(|cell| cell.colour == Rgb::GREEN).count(),
+            15 * 4 * 4
+        );
+
+        // The first digit of the first tile's ID.
+        assert_eq!(frame.get(3, 3).glyph, '1');
+    }
+}
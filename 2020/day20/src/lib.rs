@@ -0,0 +1,328 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use aoc_error::ParseError;
+use aoc_parse::{blocks, lines, parse_all, section, unsigned};
+use combine::parser::char::string;
+use combine::{many1, one_of, Parser};
+
+pub use crate::export::draw;
+pub use crate::pattern::{find_matches, overlapping, parse_patterns, roughness, Match, Pattern};
+pub use crate::tile::{Edge, Grid, Tile, TileRef};
+
+mod export;
+mod pattern;
+mod tile;
+
+/// The product of the IDs of the four corner tiles.
+pub fn part1(input: &str) -> Result<u64> {
+    let tiles = parse_tiles(input)?;
+    let side = puzzle_side(tiles.len())?;
+    let corners = find_corners(&tiles, &index_edges(&tiles)?, side)?;
+
+    Ok(corners.iter().map(|c| *c as u64).product::<u64>())
+}
+
+/// The number of `#` in the assembled image that are not part of a sea
+/// monster.
+pub fn part2(input: &str) -> Result<usize> {
+    let image = assemble_image(&parse_tiles(input)?)?.image();
+    let matches = find_matches(&image, &[Pattern::sea_monster()]);
+
+    Ok(roughness(&image, &matches))
+}
+
+/// Parse square tiles that are all the same size.
+pub fn parse_tiles(input: &str) -> Result<HashMap<usize, Tile>, ParseError> {
+    let pixel = one_of(".#".chars()).map(|c| c == '#');
+    let tile = section(
+        string("Tile ").with(unsigned()),
+        lines(many1::<Vec<_>, _, _>(pixel)),
+    );
+
+    let mut tiles = HashMap::new();
+    let mut tile_size = None;
+
+    for (id, rows) in parse_all(blocks(tile), input)? {
+        let size = rows.len();
+
+        if rows.iter().any(|row| row.len() != size) {
+            return Err(ParseError::new(format!("tile {} is not square", id)));
+        } else if size < 2 {
+            return Err(ParseError::new(format!("tile {} is smaller than 2x2", id)));
+        }
+
+        match tile_size {
+            Some(tile_size) if tile_size != size => {
+                return Err(ParseError::new(format!(
+                    "tile {} is {}x{}, but the tiles before it are {}x{}",
+                    id, size, size, tile_size, tile_size
+                )))
+            }
+            _ => tile_size = Some(size),
+        }
+
+        if tiles.insert(id, Tile::new(size, &rows.concat())).is_some() {
+            return Err(ParseError::new(format!(
+                "there is more than one tile {}",
+                id
+            )));
+        }
+    }
+
+    Ok(tiles)
+}
+
+/// The number of tiles along each side of a square puzzle of `count` tiles.
+fn puzzle_side(count: usize) -> Result<usize> {
+    let side = (count as f64).sqrt().round() as usize;
+
+    if side * side == count {
+        Ok(side)
+    } else {
+        Err(anyhow!("{} tiles cannot make a square puzzle", count))
+    }
+}
+
+/// The IDs of the tiles that have an edge with each signature.
+type EdgeIndex = HashMap<Edge, Vec<usize>>;
+
+/// Index every tile by the signatures of its edges. Each edge can line up
+/// with at most one other tile.
+fn index_edges(tiles: &HashMap<usize, Tile>) -> Result<EdgeIndex> {
+    let mut index = EdgeIndex::new();
+
+    for (&id, tile) in tiles {
+        for edge in tile.edges().iter() {
+            index.entry(edge.signature()).or_default().push(id);
+        }
+    }
+
+    if let Some(ids) = index.values_mut().find(|ids| ids.len() > 2) {
+        ids.sort_unstable();
+
+        return Err(anyhow!(
+            "tiles {} all have the same edge",
+            ids.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    Ok(index)
+}
+
+/// Whether `edge` does not line up with any other tile.
+fn is_border(index: &EdgeIndex, edge: &Edge) -> bool {
+    index[&edge.signature()].len() == 1
+}
+
+/// The IDs of the corner tiles, which have two border edges, in increasing
+/// order.
+fn find_corners(
+    tiles: &HashMap<usize, Tile>,
+    index: &EdgeIndex,
+    side: usize,
+) -> Result<Vec<usize>> {
+    let mut corners = tiles
+        .iter()
+        .filter(|(_, tile)| {
+            tile.edges()
+                .iter()
+                .filter(|edge| is_border(index, edge))
+                .count()
+                >= 2
+        })
+        .map(|(&id, _)| id)
+        .collect::<Vec<_>>();
+    corners.sort_unstable();
+
+    let expected = if side == 1 { 1 } else { 4 };
+    if corners.len() != expected {
+        return Err(anyhow!(
+            "expected {} corner tiles, but found {}",
+            expected,
+            corners.len()
+        ));
+    }
+
+    Ok(corners)
+}
+
+/// A solved puzzle.
+#[derive(Clone, Debug)]
+pub struct Puzzle {
+    /// The number of tiles along each side.
+    pub side: usize,
+    /// The ID of each tile, in reading order.
+    pub ids: Vec<usize>,
+    /// Each tile, turned the way it fits in the puzzle.
+    pub tiles: Vec<Tile>,
+}
+
+impl Puzzle {
+    /// The grids of every tile joined together, without their borders.
+    pub fn image(&self) -> Grid {
+        let grid_size = self.tiles[0].grid().size();
+        let mut image = Grid::new(self.side * grid_size);
+
+        for (i, tile) in self.tiles.iter().enumerate() {
+            let (y_offset, x_offset) = (i / self.side * grid_size, i % self.side * grid_size);
+
+            for y in 0..grid_size {
+                for x in 0..grid_size {
+                    image[(y_offset + y, x_offset + x)] = tile.grid()[(y, x)];
+                }
+            }
+        }
+
+        image
+    }
+}
+
+/// Fit the tiles together into a square.
+///
+/// Starting from a corner, every other tile is found from the edge it shares
+/// with the tile to its left or above it, so each tile is only looked at once.
+pub fn assemble_image(tiles: &HashMap<usize, Tile>) -> Result<Puzzle> {
+    let side = puzzle_side(tiles.len())?;
+    let index = index_edges(tiles)?;
+    let corner = find_corners(tiles, &index, side)?[0];
+
+    // Turn the corner so that its border is at the top left.
+    let start = tiles[&corner]
+        .permute()
+        .iter()
+        .find(|tile| is_border(&index, &tile.top_edge()) && is_border(&index, &tile.left_edge()))
+        .map(|&tile| Tile::from(tile))
+        .ok_or_else(|| anyhow!("corner tile {} has borders on opposite sides", corner))?;
+
+    let mut puzzle = Puzzle {
+        side,
+        ids: vec![corner],
+        tiles: vec![start],
+    };
+    let mut used: HashSet<usize> = puzzle.ids.iter().copied().collect();
+
+    for i in 1..side * side {
+        let (y, x) = (i / side, i % side);
+        let left = if x > 0 {
+            Some(&puzzle.tiles[i - 1])
+        } else {
+            None
+        };
+        let above = if y > 0 {
+            Some(&puzzle.tiles[i - side])
+        } else {
+            None
+        };
+
+        // The first tile in each row is found from the one above it.
+        let (neighbour, edge) = match left {
+            Some(left) => (puzzle.ids[i - 1], left.right_edge()),
+            None => (puzzle.ids[i - side], above.unwrap().bottom_edge()),
+        };
+
+        let id = index[&edge.signature()]
+            .iter()
+            .copied()
+            .find(|&id| id != neighbour)
+            .ok_or_else(|| anyhow!("no tile fits at row {}, column {}", y, x))?;
+
+        if !used.insert(id) {
+            return Err(anyhow!("tile {} fits in more than one place", id));
+        }
+
+        let tile = tiles[&id]
+            .permute()
+            .iter()
+            .find(|tile| {
+                left.is_none_or(|left| tile.left_edge() == *left.right_edge())
+                    && above.is_none_or(|above| tile.top_edge() == *above.bottom_edge())
+            })
+            .map(|&tile| Tile::from(tile))
+            .ok_or_else(|| anyhow!("tile {} does not fit at row {}, column {}", id, y, x))?;
+
+        puzzle.ids.push(id);
+        puzzle.tiles.push(tile);
+    }
+
+    Ok(puzzle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        let mut rng = aoc_generate::rng(20);
+
+        for &(side, tile_size, monsters) in
+            &[(1, 24, 1), (3, 10, 2), (12, 10, 20), (4, 8, 3), (2, 31, 5)]
+        {
+            let input = aoc_generate::y2020::jigsaw(&mut rng, side, tile_size, monsters);
+
+            let corners = [
+                1000,
+                999 + side,
+                1000 + side * (side - 1),
+                999 + side * side,
+            ];
+            let corners: HashSet<_> = corners.iter().map(|&id| id as u64).collect();
+            assert_eq!(part1(&input).unwrap(), corners.iter().product());
+
+            let roughness = input
+                .split("\n\n")
+                .flat_map(|tile| tile.lines().skip(2).take(tile_size - 2))
+                .map(|row| row[1..tile_size - 1].matches('#').count())
+                .sum::<usize>();
+            assert_eq!(part2(&input).unwrap(), roughness - 15 * monsters);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |input: &str| part2(input).unwrap_err().to_string();
+
+        assert_eq!(error("Tile 1:\n#..\n...\n"), "tile 1 is not square");
+        assert_eq!(
+            error("Tile 1:\n#..\n...\n..#\n\nTile 2:\n#.\n..\n"),
+            "tile 2 is 2x2, but the tiles before it are 3x3"
+        );
+        assert_eq!(
+            error("Tile 1:\n#..\n...\n..#\n\nTile 2:\n#..\n...\n..#\n"),
+            "2 tiles cannot make a square puzzle"
+        );
+
+        let mut rng = aoc_generate::rng(20);
+        let input = aoc_generate::y2020::jigsaw(&mut rng, 3, 10, 0);
+        let tiles = input.split_terminator("\n\n").collect::<Vec<_>>();
+
+        // Replace the middle tile with one that fits nowhere, which leaves the
+        // tiles around it with another border.
+        let mut unsolvable = tiles
+            .iter()
+            .filter(|tile| !tile.starts_with("Tile 1004:"))
+            .map(|tile| format!("{}\n\n", tile))
+            .collect::<String>();
+        unsolvable.push_str("Tile 2000:\n###.......\n#.........\n");
+        unsolvable.push_str(&"..........\n".repeat(7));
+        unsolvable.push_str("....#.#..#\n");
+        assert_eq!(error(&unsolvable), "expected 4 corner tiles, but found 9");
+
+        // Replace it with a copy of a corner instead.
+        let corner = tiles.iter().find(|tile| tile.starts_with("Tile 1000:"));
+        let ambiguous = unsolvable.replace(
+            &unsolvable[unsolvable.find("Tile 2000:").unwrap()..],
+            &corner.unwrap().replace("1000", "2000"),
+        );
+        let e = error(&ambiguous);
+        assert!(
+            e.contains("2000") && e.ends_with("all have the same edge"),
+            "{}",
+            e
+        );
+    }
+}
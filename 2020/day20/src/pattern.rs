@@ -0,0 +1,281 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+use aoc_error::ParseError;
+
+use crate::tile::Grid;
+
+const SEA_MONSTER: &str = "                  # \n#    ##    ##    ###\n #  #  #  #  #  #   ";
+
+/// A shape to look for in an image.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pattern {
+    height: usize,
+    width: usize,
+    /// The pixels that must be set, relative to the top left corner and in
+    /// reading order.
+    pixels: Vec<(usize, usize)>,
+}
+
+impl Pattern {
+    /// Parse a pattern with a `#` for each pixel that must be set. Any other
+    /// character is a pixel that does not matter.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let pixels = text
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(x, _)| (y, x))
+            })
+            .collect::<Vec<_>>();
+
+        if pixels.is_empty() {
+            Err(ParseError::new("the pattern does not have any `#'"))
+        } else {
+            Ok(Pattern::new(pixels))
+        }
+    }
+
+    pub fn sea_monster() -> Self {
+        Pattern::parse(SEA_MONSTER).unwrap()
+    }
+
+    /// Move the pixels as close to the top left as they can go.
+    fn new(mut pixels: Vec<(usize, usize)>) -> Self {
+        let top = pixels.iter().map(|&(y, _)| y).min().unwrap();
+        let left = pixels.iter().map(|&(_, x)| x).min().unwrap();
+
+        for (y, x) in pixels.iter_mut() {
+            *y -= top;
+            *x -= left;
+        }
+        pixels.sort_unstable();
+
+        Pattern {
+            height: pixels.iter().map(|&(y, _)| y).max().unwrap() + 1,
+            width: pixels.iter().map(|&(_, x)| x).max().unwrap() + 1,
+            pixels,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn pixels(&self) -> &[(usize, usize)] {
+        &self.pixels
+    }
+
+    /// Turn the pattern a quarter turn clockwise.
+    fn rotate(&self) -> Self {
+        Pattern::new(
+            self.pixels
+                .iter()
+                .map(|&(y, x)| (x, self.height - y - 1))
+                .collect(),
+        )
+    }
+
+    fn flip_horizontal(&self) -> Self {
+        Pattern::new(
+            self.pixels
+                .iter()
+                .map(|&(y, x)| (y, self.width - x - 1))
+                .collect(),
+        )
+    }
+
+    /// Every different way the pattern can be turned or flipped, starting
+    /// with the pattern as it is.
+    pub fn orientations(&self) -> Vec<Pattern> {
+        let mut orientations = vec![];
+        let mut pattern = self.clone();
+
+        for _ in 0..4 {
+            for p in [pattern.clone(), pattern.flip_horizontal()] {
+                if !orientations.contains(&p) {
+                    orientations.push(p);
+                }
+            }
+
+            pattern = pattern.rotate();
+        }
+
+        orientations
+    }
+}
+
+/// Parse patterns separated by empty lines.
+pub fn parse_patterns(text: &str) -> Result<Vec<Pattern>, ParseError> {
+    text.split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+        .enumerate()
+        .map(|(i, block)| {
+            Pattern::parse(block).map_err(|e| {
+                ParseError::at_span(text, block, format!("pattern {}: {}", i + 1, e.message()))
+            })
+        })
+        .collect()
+}
+
+/// A place where a pattern was found in an image.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    /// The index of the pattern.
+    pub pattern: usize,
+    /// The index of the pattern's orientation.
+    pub orientation: usize,
+    /// The top left corner of the match.
+    pub y: usize,
+    pub x: usize,
+    /// The pixels of the image that the pattern covers.
+    pub pixels: Vec<(usize, usize)>,
+}
+
+impl fmt::Display for Match {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "pattern {} (orientation {}) at row {}, column {}",
+            self.pattern + 1,
+            self.orientation + 1,
+            self.y,
+            self.x
+        )
+    }
+}
+
+/// Find every place in the image that matches any orientation of any of the
+/// patterns, including matches that overlap.
+pub fn find_matches(image: &Grid, patterns: &[Pattern]) -> Vec<Match> {
+    let mut matches = vec![];
+
+    for (i, pattern) in patterns.iter().enumerate() {
+        for (j, orientation) in pattern.orientations().iter().enumerate() {
+            if orientation.height > image.size() || orientation.width > image.size() {
+                continue;
+            }
+
+            for y in 0..=image.size() - orientation.height {
+                for x in 0..=image.size() - orientation.width {
+                    let pixels = orientation
+                        .pixels
+                        .iter()
+                        .map(|&(dy, dx)| (y + dy, x + dx))
+                        .collect::<Vec<_>>();
+
+                    if pixels.iter().all(|&pos| image[pos]) {
+                        matches.push(Match {
+                            pattern: i,
+                            orientation: j,
+                            y,
+                            x,
+                            pixels,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+/// The pairs of matches that share any pixels, by index.
+pub fn overlapping(matches: &[Match]) -> Vec<(usize, usize)> {
+    let mut owners = HashMap::<(usize, usize), Vec<usize>>::new();
+    for (i, m) in matches.iter().enumerate() {
+        for &pos in &m.pixels {
+            owners.entry(pos).or_default().push(i);
+        }
+    }
+
+    let mut pairs = BTreeSet::new();
+    for owners in owners.values() {
+        for (k, &i) in owners.iter().enumerate() {
+            for &j in &owners[k + 1..] {
+                pairs.insert((i, j));
+            }
+        }
+    }
+
+    pairs.into_iter().collect()
+}
+
+/// The number of set pixels in the image that are not part of any match.
+pub fn roughness(image: &Grid, matches: &[Match]) -> usize {
+    let matched: HashSet<_> = matches.iter().flat_map(|m| &m.pixels).collect();
+
+    (0..image.size())
+        .flat_map(|y| (0..image.size()).map(move |x| (y, x)))
+        .filter(|pos| image[*pos] && !matched.contains(pos))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_orientations() {
+        let monster = Pattern::sea_monster();
+        assert_eq!((monster.height(), monster.width()), (3, 20));
+        assert_eq!(monster.pixels().len(), 15);
+        assert_eq!(monster.orientations().len(), 8);
+
+        let patterns = parse_patterns("\n.#.\n###\n.#.\n\n#\n#\n").unwrap();
+        assert_eq!(patterns[0].orientations().len(), 1);
+        assert_eq!(patterns[1].orientations().len(), 2);
+        assert_eq!(patterns[1].orientations()[1].pixels(), &[(0, 0), (0, 1)]);
+
+        let e = parse_patterns("#\n\n...\n").unwrap_err();
+        assert_eq!(e.message(), "pattern 2: the pattern does not have any `#'");
+        assert_eq!(e.location().unwrap().line, 3);
+    }
+
+    #[test]
+    fn test_find_matches() {
+        // ###.
+        // .###
+        // ....
+        let mut image = Grid::new(4);
+        for &pos in &[(0, 0), (0, 1), (0, 2), (1, 1), (1, 2), (1, 3)] {
+            image[pos] = true;
+        }
+
+        let matches = find_matches(&image, &[Pattern::parse("##").unwrap()]);
+
+        let found = matches
+            .iter()
+            .map(|m| (m.orientation, m.y, m.x))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (0, 0, 0),
+                (0, 0, 1),
+                (0, 1, 1),
+                (0, 1, 2),
+                (1, 0, 1),
+                (1, 0, 2),
+            ]
+        );
+        assert_eq!(
+            matches[0].to_string(),
+            "pattern 1 (orientation 1) at row 0, column 0"
+        );
+
+        assert!(overlapping(&matches).contains(&(0, 1)));
+        assert!(!overlapping(&matches).contains(&(0, 2)));
+        assert_eq!(roughness(&image, &matches), 0);
+        assert_eq!(roughness(&image, &matches[..1]), 4);
+    }
+}
@@ -1,28 +1,93 @@
-use std::convert::AsRef;
+use std::cmp;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::ops::{Index, IndexMut};
 
-/// Width and height of each tile.
-//
-// Does include the border.
-pub const TILE_SIZE: usize = 10;
+/// The pixels along one side of a tile, read from left to right or from top
+/// to bottom.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Edge(Vec<bool>);
 
-/// Width and height of each tile's grid.
-///
-/// Does not include the border.
-pub const GRID_SIZE: usize = 8;
+impl Edge {
+    pub fn reversed(&self) -> Edge {
+        Edge(self.0.iter().rev().copied().collect())
+    }
+
+    /// The same value for an edge and its reverse, so that every edge that
+    /// could line up with this one has the same signature.
+    pub fn signature(&self) -> Edge {
+        cmp::min(self.clone(), self.reversed())
+    }
+}
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Debug)]
 pub struct Tile {
     pub(crate) grid: Grid,
-    pub(crate) top: u16,
-    pub(crate) bottom: u16,
-    pub(crate) right: u16,
-    pub(crate) left: u16,
+    pub(crate) top: Edge,
+    pub(crate) bottom: Edge,
+    pub(crate) right: Edge,
+    pub(crate) left: Edge,
 }
 
 impl Tile {
+    /// A tile from its pixels, one row after another. The border of the tile
+    /// makes up its edges and the rest makes up its grid.
+    ///
+    /// Panics if `size` is less than two or there are not `size * size`
+    /// pixels.
+    pub fn new(size: usize, pixels: &[bool]) -> Self {
+        assert!(size >= 2, "tiles must be at least 2x2");
+        assert_eq!(pixels.len(), size * size);
+
+        let mut grid = Grid::new(size - 2);
+        for y in 0..grid.size() {
+            for x in 0..grid.size() {
+                grid[(y, x)] = pixels[(y + 1) * size + x + 1];
+            }
+        }
+
+        Tile {
+            grid,
+            top: Edge(pixels[..size].to_vec()),
+            bottom: Edge(pixels[size * (size - 1)..].to_vec()),
+            left: Edge(pixels.iter().step_by(size).copied().collect()),
+            right: Edge(
+                pixels
+                    .iter()
+                    .skip(size - 1)
+                    .step_by(size)
+                    .copied()
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The width and height of the tile, including its border.
+    pub fn size(&self) -> usize {
+        self.top.0.len()
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// The pixel at `(y, x)`, counting the border.
+    pub fn pixel(&self, y: usize, x: usize) -> bool {
+        let last = self.size() - 1;
+
+        if y == 0 {
+            self.top.0[x]
+        } else if y == last {
+            self.bottom.0[x]
+        } else if x == 0 {
+            self.left.0[y]
+        } else if x == last {
+            self.right.0[y]
+        } else {
+            self.grid[(y - 1, x - 1)]
+        }
+    }
+
     fn flip_horizontal(&self) -> TileRef<'_> {
         TileRef {
             tile: self,
@@ -39,20 +104,24 @@ impl Tile {
         }
     }
 
-    pub fn top_edge(&self) -> u16 {
-        self.top
+    pub fn top_edge(&self) -> &Edge {
+        &self.top
+    }
+    pub fn bottom_edge(&self) -> &Edge {
+        &self.bottom
     }
-    pub fn bottom_edge(&self) -> u16 {
-        self.bottom
+    pub fn left_edge(&self) -> &Edge {
+        &self.left
     }
-    pub fn left_edge(&self) -> u16 {
-        self.left
+    pub fn right_edge(&self) -> &Edge {
+        &self.right
     }
-    pub fn right_edge(&self) -> u16 {
-        self.right
+
+    pub fn edges(&self) -> [&Edge; 4] {
+        [&self.top, &self.right, &self.bottom, &self.left]
     }
 
-    pub fn permute(&self) -> [TileRef; 8] {
+    pub fn permute(&self) -> [TileRef<'_>; 8] {
         [
             self.into(),
             self.flip_horizontal(),
@@ -64,33 +133,6 @@ impl Tile {
             self.rotate().rotate().rotate().flip_horizontal(),
         ]
     }
-
-    fn compute_bitmap<I>(bits: I) -> u16
-    where
-        I: Iterator<Item = bool>,
-    {
-        let mut map = 0;
-        for bit in bits {
-            map <<= 1;
-            map |= bit as u16;
-        }
-        assert!(map <= 0b1111111111);
-
-        map
-    }
-
-    fn reverse_bitmap(mut map: u16) -> u16 {
-        assert!(map <= 0b1111111111);
-        let mut new = 0;
-
-        for _ in 0..10 {
-            new <<= 1;
-            new |= map & 1;
-            map >>= 1;
-        }
-
-        new
-    }
 }
 
 impl Hash for Tile {
@@ -112,7 +154,7 @@ impl Eq for Tile {}
 
 impl<'t> From<TileRef<'t>> for Tile {
     fn from(tile_ref: TileRef<'t>) -> Tile {
-        let mut grid = tile_ref.tile.grid;
+        let mut grid = tile_ref.tile.grid.clone();
 
         let mut rotation = tile_ref.rotation;
         while rotation != Rotation::None {
@@ -134,95 +176,79 @@ impl<'t> From<TileRef<'t>> for Tile {
     }
 }
 
-impl<'p> From<&'p [bool; TILE_SIZE * TILE_SIZE]> for Tile {
-    fn from(pixels: &'p [bool; TILE_SIZE * TILE_SIZE]) -> Self {
-        let mut grid = Grid::default();
-
-        for y in 0..GRID_SIZE {
-            let pixel_slice = &pixels[(y + 1) * TILE_SIZE + 1..(y + 2) * TILE_SIZE - 1];
-            let grid_slice = &mut grid.0[y * GRID_SIZE..(y + 1) * GRID_SIZE];
-
-            grid_slice.copy_from_slice(pixel_slice);
-        }
-
-        let top = Self::compute_bitmap(pixels.iter().take(10).cloned());
-        let bottom = Self::compute_bitmap(pixels.iter().skip(90).cloned());
-        let left = Self::compute_bitmap(pixels.iter().step_by(10).cloned());
-        let right = Self::compute_bitmap(pixels.iter().skip(9).step_by(10).cloned());
-
-        Tile {
-            grid,
-            top,
-            bottom,
-            left,
-            right,
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
-pub struct Grid([bool; GRID_SIZE * GRID_SIZE]);
-
-impl Default for Grid {
-    fn default() -> Self {
-        Grid([false; GRID_SIZE * GRID_SIZE])
-    }
+/// A square grid of pixels.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Grid {
+    size: usize,
+    pixels: Vec<bool>,
 }
 
 impl Index<(usize, usize)> for Grid {
     type Output = bool;
 
     fn index(&self, (y, x): (usize, usize)) -> &Self::Output {
-        &self.0[y * GRID_SIZE + x]
+        &self.pixels[y * self.size + x]
     }
 }
 
 impl IndexMut<(usize, usize)> for Grid {
     fn index_mut(&mut self, (y, x): (usize, usize)) -> &mut Self::Output {
-        &mut self.0[y * GRID_SIZE + x]
+        &mut self.pixels[y * self.size + x]
     }
 }
 
 impl fmt::Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for i in 0..(GRID_SIZE * GRID_SIZE) {
-            write!(f, "{}", if self.0[i] { '#' } else { '.' })?;
-            if (i + 1) % 10 == 0 {
-                writeln!(f)?;
+        for row in self.pixels.chunks(self.size) {
+            for &pixel in row {
+                write!(f, "{}", if pixel { '#' } else { '.' })?;
             }
+            writeln!(f)?;
         }
 
         Ok(())
     }
 }
 
-impl AsRef<[bool; GRID_SIZE * GRID_SIZE]> for Grid {
-    fn as_ref(&self) -> &[bool; GRID_SIZE * GRID_SIZE] {
-        &self.0
+impl AsRef<[bool]> for Grid {
+    fn as_ref(&self) -> &[bool] {
+        &self.pixels
     }
 }
 
 impl Grid {
+    /// A grid of `size` by `size` unset pixels.
+    pub fn new(size: usize) -> Self {
+        Grid {
+            size,
+            pixels: vec![false; size * size],
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
     pub fn rotate(&mut self) {
-        let old = *self;
+        let old = self.clone();
 
-        for y in 0..GRID_SIZE {
-            for x in 0..GRID_SIZE {
-                self[(y, x)] = old[(GRID_SIZE - x - 1, y)];
+        for y in 0..self.size {
+            for x in 0..self.size {
+                self[(y, x)] = old[(self.size - x - 1, y)];
             }
         }
     }
 
     pub fn flip_horizontal(&mut self) {
-        for y in 0..GRID_SIZE {
-            for x in 0..GRID_SIZE / 2 {
-                self.swap((y, x), (y, GRID_SIZE - x - 1));
+        for y in 0..self.size {
+            for x in 0..self.size / 2 {
+                self.swap((y, x), (y, self.size - x - 1));
             }
         }
     }
 
     pub fn swap(&mut self, (y, x): (usize, usize), (v, u): (usize, usize)) {
-        self.0.swap(y * GRID_SIZE + x, v * GRID_SIZE + u);
+        self.pixels.swap(y * self.size + x, v * self.size + u);
     }
 }
 
@@ -286,67 +312,59 @@ impl<'t> TileRef<'t> {
         }
     }
 
-    pub fn top_edge(&self) -> u16 {
+    pub fn top_edge(&self) -> Edge {
         match (self.rotation, self.flip) {
-            (Rotation::None, Flip::None) => self.tile.top_edge(),
-            (Rotation::Quarter, Flip::None) => Tile::reverse_bitmap(self.tile.left_edge()),
-            (Rotation::Half, Flip::None) => Tile::reverse_bitmap(self.tile.bottom_edge()),
-            (Rotation::ThreeQuarter, Flip::None) => self.tile.right_edge(),
-
-            (Rotation::None, Flip::Horizontal) => Tile::reverse_bitmap(self.tile.top_edge()),
-            (Rotation::Quarter, Flip::Horizontal) => self.tile.left_edge(),
-            (Rotation::Half, Flip::Horizontal) => self.tile.bottom_edge(),
-            (Rotation::ThreeQuarter, Flip::Horizontal) => {
-                Tile::reverse_bitmap(self.tile.right_edge())
-            }
+            (Rotation::None, Flip::None) => self.tile.top_edge().clone(),
+            (Rotation::Quarter, Flip::None) => self.tile.left_edge().reversed(),
+            (Rotation::Half, Flip::None) => self.tile.bottom_edge().reversed(),
+            (Rotation::ThreeQuarter, Flip::None) => self.tile.right_edge().clone(),
+
+            (Rotation::None, Flip::Horizontal) => self.tile.top_edge().reversed(),
+            (Rotation::Quarter, Flip::Horizontal) => self.tile.left_edge().clone(),
+            (Rotation::Half, Flip::Horizontal) => self.tile.bottom_edge().clone(),
+            (Rotation::ThreeQuarter, Flip::Horizontal) => self.tile.right_edge().reversed(),
         }
     }
 
-    pub fn bottom_edge(&self) -> u16 {
+    pub fn bottom_edge(&self) -> Edge {
         match (self.rotation, self.flip) {
-            (Rotation::None, Flip::None) => self.tile.bottom_edge(),
-            (Rotation::Quarter, Flip::None) => Tile::reverse_bitmap(self.tile.right_edge()),
-            (Rotation::Half, Flip::None) => Tile::reverse_bitmap(self.tile.top_edge()),
-            (Rotation::ThreeQuarter, Flip::None) => self.tile.left_edge(),
-
-            (Rotation::None, Flip::Horizontal) => Tile::reverse_bitmap(self.tile.bottom_edge()),
-            (Rotation::Quarter, Flip::Horizontal) => self.tile.right_edge(),
-            (Rotation::Half, Flip::Horizontal) => self.tile.top_edge(),
-            (Rotation::ThreeQuarter, Flip::Horizontal) => {
-                Tile::reverse_bitmap(self.tile.left_edge())
-            }
+            (Rotation::None, Flip::None) => self.tile.bottom_edge().clone(),
+            (Rotation::Quarter, Flip::None) => self.tile.right_edge().reversed(),
+            (Rotation::Half, Flip::None) => self.tile.top_edge().reversed(),
+            (Rotation::ThreeQuarter, Flip::None) => self.tile.left_edge().clone(),
+
+            (Rotation::None, Flip::Horizontal) => self.tile.bottom_edge().reversed(),
+            (Rotation::Quarter, Flip::Horizontal) => self.tile.right_edge().clone(),
+            (Rotation::Half, Flip::Horizontal) => self.tile.top_edge().clone(),
+            (Rotation::ThreeQuarter, Flip::Horizontal) => self.tile.left_edge().reversed(),
         }
     }
 
-    pub fn left_edge(&self) -> u16 {
+    pub fn left_edge(&self) -> Edge {
         match (self.rotation, self.flip) {
-            (Rotation::None, Flip::None) => self.tile.left_edge(),
-            (Rotation::Quarter, Flip::None) => self.tile.bottom_edge(),
-            (Rotation::Half, Flip::None) => Tile::reverse_bitmap(self.tile.right_edge()),
-            (Rotation::ThreeQuarter, Flip::None) => Tile::reverse_bitmap(self.tile.top_edge()),
-
-            (Rotation::None, Flip::Horizontal) => self.tile.right_edge(),
-            (Rotation::Quarter, Flip::Horizontal) => self.tile.top_edge(),
-            (Rotation::Half, Flip::Horizontal) => Tile::reverse_bitmap(self.tile.left_edge()),
-            (Rotation::ThreeQuarter, Flip::Horizontal) => {
-                Tile::reverse_bitmap(self.tile.bottom_edge())
-            }
+            (Rotation::None, Flip::None) => self.tile.left_edge().clone(),
+            (Rotation::Quarter, Flip::None) => self.tile.bottom_edge().clone(),
+            (Rotation::Half, Flip::None) => self.tile.right_edge().reversed(),
+            (Rotation::ThreeQuarter, Flip::None) => self.tile.top_edge().reversed(),
+
+            (Rotation::None, Flip::Horizontal) => self.tile.right_edge().clone(),
+            (Rotation::Quarter, Flip::Horizontal) => self.tile.top_edge().clone(),
+            (Rotation::Half, Flip::Horizontal) => self.tile.left_edge().reversed(),
+            (Rotation::ThreeQuarter, Flip::Horizontal) => self.tile.bottom_edge().reversed(),
         }
     }
 
-    pub fn right_edge(&self) -> u16 {
+    pub fn right_edge(&self) -> Edge {
         match (self.rotation, self.flip) {
-            (Rotation::None, Flip::None) => self.tile.right_edge(),
-            (Rotation::Quarter, Flip::None) => self.tile.top_edge(),
-            (Rotation::Half, Flip::None) => Tile::reverse_bitmap(self.tile.left_edge()),
-            (Rotation::ThreeQuarter, Flip::None) => Tile::reverse_bitmap(self.tile.bottom_edge()),
-
-            (Rotation::None, Flip::Horizontal) => self.tile.left_edge(),
-            (Rotation::Quarter, Flip::Horizontal) => self.tile.bottom_edge(),
-            (Rotation::Half, Flip::Horizontal) => Tile::reverse_bitmap(self.tile.right_edge()),
-            (Rotation::ThreeQuarter, Flip::Horizontal) => {
-                Tile::reverse_bitmap(self.tile.top_edge())
-            }
+            (Rotation::None, Flip::None) => self.tile.right_edge().clone(),
+            (Rotation::Quarter, Flip::None) => self.tile.top_edge().clone(),
+            (Rotation::Half, Flip::None) => self.tile.left_edge().reversed(),
+            (Rotation::ThreeQuarter, Flip::None) => self.tile.bottom_edge().reversed(),
+
+            (Rotation::None, Flip::Horizontal) => self.tile.left_edge().clone(),
+            (Rotation::Quarter, Flip::Horizontal) => self.tile.bottom_edge().clone(),
+            (Rotation::Half, Flip::Horizontal) => self.tile.right_edge().reversed(),
+            (Rotation::ThreeQuarter, Flip::Horizontal) => self.tile.top_edge().reversed(),
         }
     }
 }
@@ -365,15 +383,19 @@ impl<'t> From<&'t Tile> for TileRef<'t> {
 mod test {
     use super::*;
 
+    fn edge(bits: u16) -> Edge {
+        Edge((0..10).rev().map(|i| bits >> i & 1 == 1).collect())
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_bitmap() {
         {
-            assert_eq!(Tile::reverse_bitmap(0b1000000000), 0b0000000001);
+            assert_eq!(edge(0b1000000000).reversed(), edge(0b0000000001));
         }
 
         {
-            let tile = Tile::from(&[
+            let tile = Tile::new(10, &[
                 true,  true,  true,  true,  true,  true,  true,  true,  true, true,
                 true, false, false, false, false, false, false, false, false, true,
                 true, false, false, false, false, false, false, false, false, true,
@@ -386,13 +408,13 @@ mod test {
                 true,  true,  true,  true,  true,  true,  true,  true,  true, true,
             ]);
 
-            assert_eq!(tile.top_edge(),    0b1111111111);
-            assert_eq!(tile.right_edge(),  0b1111111111);
-            assert_eq!(tile.bottom_edge(), 0b1111111111);
-            assert_eq!(tile.left_edge(),   0b1111111111);
+            assert_eq!(tile.top_edge(),    &edge(0b1111111111));
+            assert_eq!(tile.right_edge(),  &edge(0b1111111111));
+            assert_eq!(tile.bottom_edge(), &edge(0b1111111111));
+            assert_eq!(tile.left_edge(),   &edge(0b1111111111));
         }
         {
-            let tile = Tile::from(&[
+            let tile = Tile::new(10, &[
                 true,  false,  true, false,  true, false,  true, false,  true, false,
                 false, false, false, false, false, false, false, false, false, false,
                 false, false, false, false, false, false, false, false, false, false,
@@ -405,52 +427,52 @@ mod test {
                 false, false, false, false, false, false, false, false, false, false,
             ]);
 
-            assert_eq!(tile.top_edge(),    0b1010101010);
-            assert_eq!(tile.right_edge(),  0b0000000000);
-            assert_eq!(tile.bottom_edge(), 0b0000000000);
-            assert_eq!(tile.left_edge(),   0b1000000000);
+            assert_eq!(tile.top_edge(),    &edge(0b1010101010));
+            assert_eq!(tile.right_edge(),  &edge(0b0000000000));
+            assert_eq!(tile.bottom_edge(), &edge(0b0000000000));
+            assert_eq!(tile.left_edge(),   &edge(0b1000000000));
 
             let tref = tile.flip_horizontal();
-            assert_eq!(tref.top_edge(),    0b0101010101);
-            assert_eq!(tref.right_edge(),  0b1000000000);
-            assert_eq!(tref.bottom_edge(), 0b0000000000);
-            assert_eq!(tref.left_edge(),   0b0000000000);
+            assert_eq!(tref.top_edge(),    edge(0b0101010101));
+            assert_eq!(tref.right_edge(),  edge(0b1000000000));
+            assert_eq!(tref.bottom_edge(), edge(0b0000000000));
+            assert_eq!(tref.left_edge(),   edge(0b0000000000));
 
             let tref = tile.rotate();
-            assert_eq!(tref.top_edge(),    0b0000000001);
-            assert_eq!(tref.right_edge(),  0b1010101010);
-            assert_eq!(tref.bottom_edge(), 0b0000000000);
-            assert_eq!(tref.left_edge(),   0b0000000000);
+            assert_eq!(tref.top_edge(),    edge(0b0000000001));
+            assert_eq!(tref.right_edge(),  edge(0b1010101010));
+            assert_eq!(tref.bottom_edge(), edge(0b0000000000));
+            assert_eq!(tref.left_edge(),   edge(0b0000000000));
 
             let tref = tile.rotate().flip_horizontal();
-            assert_eq!(tref.top_edge(),    0b1000000000);
-            assert_eq!(tref.right_edge(),  0b0000000000);
-            assert_eq!(tref.bottom_edge(), 0b0000000000);
-            assert_eq!(tref.left_edge(),   0b1010101010);
+            assert_eq!(tref.top_edge(),    edge(0b1000000000));
+            assert_eq!(tref.right_edge(),  edge(0b0000000000));
+            assert_eq!(tref.bottom_edge(), edge(0b0000000000));
+            assert_eq!(tref.left_edge(),   edge(0b1010101010));
 
             let tref = tile.rotate().rotate();
-            assert_eq!(tref.top_edge(),    0b0000000000);
-            assert_eq!(tref.right_edge(),  0b0000000001);
-            assert_eq!(tref.bottom_edge(), 0b0101010101);
-            assert_eq!(tref.left_edge(),   0b0000000000);
+            assert_eq!(tref.top_edge(),    edge(0b0000000000));
+            assert_eq!(tref.right_edge(),  edge(0b0000000001));
+            assert_eq!(tref.bottom_edge(), edge(0b0101010101));
+            assert_eq!(tref.left_edge(),   edge(0b0000000000));
 
             let tref = tref.flip_horizontal();
-            assert_eq!(tref.top_edge(),    0b0000000000);
-            assert_eq!(tref.right_edge(),  0b0000000000);
-            assert_eq!(tref.bottom_edge(), 0b1010101010);
-            assert_eq!(tref.left_edge(),   0b0000000001);
+            assert_eq!(tref.top_edge(),    edge(0b0000000000));
+            assert_eq!(tref.right_edge(),  edge(0b0000000000));
+            assert_eq!(tref.bottom_edge(), edge(0b1010101010));
+            assert_eq!(tref.left_edge(),   edge(0b0000000001));
 
             let tref = tile.rotate().rotate().rotate();
-            assert_eq!(tref.top_edge(),    0b0000000000);
-            assert_eq!(tref.right_edge(),  0b0000000000);
-            assert_eq!(tref.bottom_edge(), 0b1000000000);
-            assert_eq!(tref.left_edge(),   0b0101010101);
+            assert_eq!(tref.top_edge(),    edge(0b0000000000));
+            assert_eq!(tref.right_edge(),  edge(0b0000000000));
+            assert_eq!(tref.bottom_edge(), edge(0b1000000000));
+            assert_eq!(tref.left_edge(),   edge(0b0101010101));
 
             let tref = tref.flip_horizontal();
-            assert_eq!(tref.top_edge(),    0b0000000000);
-            assert_eq!(tref.right_edge(),  0b0101010101);
-            assert_eq!(tref.bottom_edge(), 0b0000000001);
-            assert_eq!(tref.left_edge(),   0b0000000000);
+            assert_eq!(tref.top_edge(),    edge(0b0000000000));
+            assert_eq!(tref.right_edge(),  edge(0b0101010101));
+            assert_eq!(tref.bottom_edge(), edge(0b0000000001));
+            assert_eq!(tref.left_edge(),   edge(0b0000000000));
         }
     }
 }
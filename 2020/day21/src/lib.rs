@@ -0,0 +1,149 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use aoc_error::ParseError;
+
+#[derive(Debug, Default)]
+pub struct Food {
+    ingredients: HashSet<String>,
+    allergens: HashSet<String>,
+}
+
+/// The number of times ingredients that cannot contain an allergen appear.
+pub fn part1(input: &str) -> Result<usize> {
+    let all_foods = parse_foods(input)?;
+    let possible_ingredients_by_allergen = possible_ingredients(&all_foods);
+
+    let ingredients: HashSet<&str> = all_foods
+        .iter()
+        .flat_map(|f| f.ingredients.iter().map(|s| s.as_str()))
+        .collect();
+    let foods_by_ingredient = {
+        let mut map = HashMap::<&str, Vec<&Food>>::new();
+        for food in &all_foods {
+            for ingredient in &food.ingredients {
+                map.entry(ingredient).or_default().push(food);
+            }
+        }
+        map
+    };
+
+    Ok(ingredients
+        .iter()
+        .cloned()
+        .filter(|i| {
+            !possible_ingredients_by_allergen
+                .values()
+                .any(|is| is.contains(i))
+        })
+        .map(|i| foods_by_ingredient[i].len())
+        .sum::<usize>())
+}
+
+/// The dangerous ingredients, sorted by the allergen they contain.
+pub fn part2(input: &str) -> Result<String> {
+    let all_foods = parse_foods(input)?;
+    let mut possible_ingredients_by_allergen = possible_ingredients(&all_foods);
+
+    let mut remaining: HashSet<&str> = possible_ingredients_by_allergen.keys().cloned().collect();
+    while !remaining.is_empty() {
+        let (allergen, ingredient) = possible_ingredients_by_allergen
+            .iter()
+            .filter_map(|(allergen, ingredients)| {
+                if remaining.contains(allergen) && ingredients.len() == 1 {
+                    Some((*allergen, ingredients.iter().cloned().next().unwrap()))
+                } else {
+                    None
+                }
+            })
+            .next()
+            .unwrap();
+
+        for (_, possible_ingredients) in possible_ingredients_by_allergen
+            .iter_mut()
+            .filter(|(&a, _)| a != allergen)
+        {
+            possible_ingredients.remove(ingredient);
+        }
+
+        remaining.remove(allergen);
+    }
+
+    let allergens_by_ingredient: HashMap<&str, &str> = possible_ingredients_by_allergen
+        .iter()
+        .map(|(allergen, ingredients)| {
+            assert_eq!(ingredients.len(), 1);
+            (ingredients.iter().next().cloned().unwrap(), *allergen)
+        })
+        .collect();
+
+    let mut dangerous_ingredients: Vec<&str> = allergens_by_ingredient.keys().cloned().collect();
+
+    dangerous_ingredients.sort_by_key(|ingredient| allergens_by_ingredient[ingredient]);
+
+    Ok(dangerous_ingredients.join(","))
+}
+
+/// Map each allergen to the ingredients that could contain it.
+fn possible_ingredients(all_foods: &[Food]) -> HashMap<&str, HashSet<&str>> {
+    let foods_by_allergen = {
+        let mut map = HashMap::<&str, Vec<&Food>>::new();
+        for food in all_foods {
+            for allergen in &food.allergens {
+                map.entry(allergen).or_default().push(food);
+            }
+        }
+        map
+    };
+
+    let mut possible_ingredients_by_allergen = HashMap::<&str, HashSet<&str>>::new();
+    for (allergen, foods) in &foods_by_allergen {
+        let mut sets = foods
+            .iter()
+            .map(|f| &f.ingredients)
+            .map(|i| i.iter().map(|s| s.as_str()).collect::<HashSet<&str>>());
+        let first = sets.next().unwrap();
+
+        possible_ingredients_by_allergen.insert(
+            allergen,
+            sets.fold(first, |a, b| a.intersection(&b).cloned().collect()),
+        );
+    }
+
+    possible_ingredients_by_allergen
+}
+
+pub fn parse_foods(input: &str) -> Result<Vec<Food>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let mut f = Food::default();
+            let mut ingredient = true;
+
+            for item in line.split(' ') {
+                if ingredient && item == "(contains" {
+                    ingredient = false;
+                } else if ingredient {
+                    f.ingredients.insert(item.into());
+                } else {
+                    // Remove the trailing `,` or `)`.
+                    let allergen = item
+                        .strip_suffix(',')
+                        .or_else(|| item.strip_suffix(')'))
+                        .filter(|allergen| !allergen.is_empty())
+                        .ok_or_else(|| {
+                            ParseError::at_span(
+                                input,
+                                item,
+                                "expected an allergen, e.g., `dairy,' or `fish)'",
+                            )
+                        })?;
+
+                    f.allergens.insert(allergen.into());
+                }
+            }
+
+            Ok(f)
+        })
+        .collect()
+}
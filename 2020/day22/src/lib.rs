@@ -0,0 +1,108 @@
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::Result;
+use aoc_error::ParseError;
+use aoc_parse::{lines, parse_all, section, unsigned};
+use combine::parser::char::{newline, string};
+use combine::Parser;
+
+/// The winning player's score in Combat.
+pub fn part1(input: &str) -> Result<usize> {
+    let (player1, player2) = parse_decks(input)?;
+
+    Ok(play(&player1, &player2))
+}
+
+/// The winning player's score in Recursive Combat.
+pub fn part2(input: &str) -> Result<usize> {
+    let (player1, player2) = parse_decks(input)?;
+
+    Ok(play_recursive(&player1, &player2).1)
+}
+
+pub fn parse_decks(input: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let player = |name| section(string(name), lines(unsigned())).map(|(_, cards)| cards);
+
+    parse_all(
+        (player("Player 1").skip(newline()), player("Player 2")),
+        input,
+    )
+}
+
+fn play(player1: &[usize], player2: &[usize]) -> usize {
+    let mut player1: VecDeque<usize> = player1.iter().cloned().collect();
+    let mut player2: VecDeque<usize> = player2.iter().cloned().collect();
+
+    while !player1.is_empty() && !player2.is_empty() {
+        let p1 = player1.pop_front().unwrap();
+        let p2 = player2.pop_front().unwrap();
+
+        if p1 > p2 {
+            player1.push_back(p1);
+            player1.push_back(p2);
+        } else {
+            player2.push_back(p2);
+            player2.push_back(p1);
+        }
+    }
+
+    if !player1.is_empty() {
+        score(&player1)
+    } else {
+        score(&player2)
+    }
+}
+
+enum Player {
+    Player1,
+    Player2,
+}
+
+fn play_recursive(player1: &[usize], player2: &[usize]) -> (Player, usize) {
+    let mut player1: VecDeque<usize> = player1.iter().cloned().collect();
+    let mut player2: VecDeque<usize> = player2.iter().cloned().collect();
+
+    let mut previous_rounds: HashSet<(VecDeque<usize>, VecDeque<usize>)> = HashSet::new();
+
+    loop {
+        if previous_rounds.contains(&(player1.clone(), player2.clone())) || player2.is_empty() {
+            return (Player::Player1, score(&player1));
+        } else if player1.is_empty() {
+            return (Player::Player2, score(&player2));
+        }
+        previous_rounds.insert((player1.clone(), player2.clone()));
+
+        let p1 = player1.pop_front().unwrap();
+        let p2 = player2.pop_front().unwrap();
+
+        if player1.len() >= p1 && player2.len() >= p2 {
+            let deck1: Vec<usize> = player1.iter().take(p1).cloned().collect();
+            let deck2: Vec<usize> = player2.iter().take(p2).cloned().collect();
+            match play_recursive(&deck1, &deck2).0 {
+                Player::Player1 => {
+                    player1.push_back(p1);
+                    player1.push_back(p2);
+                }
+                Player::Player2 => {
+                    player2.push_back(p2);
+                    player2.push_back(p1);
+                }
+            }
+        } else if p1 > p2 {
+            player1.push_back(p1);
+            player1.push_back(p2);
+        } else {
+            player2.push_back(p2);
+            player2.push_back(p1);
+        }
+    }
+}
+
+fn score(deck: &VecDeque<usize>) -> usize {
+    deck.iter()
+        .rev()
+        .cloned()
+        .zip(1..)
+        .map(|(card, factor)| card * factor)
+        .sum()
+}
@@ -0,0 +1,136 @@
+use anyhow::{anyhow, Result};
+use aoc_error::ParseError;
+use aoc_ring::Ring;
+
+/// The labels on the cups after cup 1 after 100 moves.
+pub fn part1(input: &str) -> Result<usize> {
+    let cups = play(&parse_cups(input)?, 0, 100)?;
+
+    Ok(cups
+        .iter_from(1)
+        .skip(1)
+        .fold(0, |labels, cup| labels * 10 + cup as usize))
+}
+
+/// The product of the two cups after cup 1 after ten million moves with a
+/// million cups.
+pub fn part2(input: &str) -> Result<u64> {
+    stars(&parse_cups(input)?, 1_000_000, 10_000_000)
+}
+
+/// The product of the two cups after cup 1 after `moves` moves with
+/// `cup_count` cups.
+pub fn stars(cups: &[u8], cup_count: u32, moves: usize) -> Result<u64> {
+    let cups = play(cups, cup_count, moves)?;
+    let first = cups.next(1);
+    let second = cups.next(first);
+
+    Ok(u64::from(first) * u64::from(second))
+}
+
+/// The most cups that fit in a ring, whose capacity must be below `u32::MAX`.
+pub const MAX_CUPS: u32 = u32::MAX - 2;
+
+pub fn parse_cups(input: &str) -> Result<Vec<u8>, ParseError> {
+    let cups = input.trim();
+
+    cups.char_indices()
+        .map(|(i, c)| {
+            if c.is_ascii_digit() {
+                Ok(c as u8 - b'0')
+            } else {
+                Err(ParseError::at_span(
+                    input,
+                    &cups[i..i + c.len_utf8()],
+                    format!("invalid cup `{}'", c),
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Play `moves` moves with `cups`, followed by the rest of the cups up to
+/// `cup_count` in order.
+///
+/// The cups must be labelled from 1 up to however many there are.
+pub fn play(cups: &[u8], cup_count: u32, moves: usize) -> Result<Ring> {
+    if cups.is_empty() {
+        return Err(anyhow!("there must be at least one labelled cup"));
+    }
+
+    let mut labels = cups.to_vec();
+    labels.sort_unstable();
+    if labels.iter().copied().ne(1..=cups.len() as u8) {
+        return Err(anyhow!(
+            "the cups must be labelled from 1 to {}",
+            cups.len()
+        ));
+    }
+
+    let max_cup = cup_count.max(cups.len() as u32);
+    if max_cup < 4 {
+        return Err(anyhow!("there must be at least 4 cups"));
+    } else if max_cup > MAX_CUPS {
+        return Err(anyhow!("there can be at most {} cups", MAX_CUPS));
+    }
+
+    // Cup 0 does not exist, so that each cup is its own label.
+    let mut ring = Ring::from_values(
+        max_cup as usize + 1,
+        cups.iter()
+            .map(|&cup| u32::from(cup))
+            .chain(cups.len() as u32 + 1..=max_cup),
+    );
+
+    let mut current = u32::from(cups[0]);
+
+    for _ in 0..moves {
+        let first = ring.next(current);
+        let second = ring.next(first);
+        let third = ring.next(second);
+
+        let mut dest = current;
+        loop {
+            dest = if dest == 1 { max_cup } else { dest - 1 };
+
+            if dest != first && dest != second && dest != third {
+                break;
+            }
+        }
+
+        ring.splice_after(first, third, dest);
+        current = ring.next(current);
+    }
+
+    Ok(ring)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_play() {
+        let cups = parse_cups("389125467").unwrap();
+
+        let ring = play(&cups, 0, 10).unwrap();
+        assert_eq!(
+            ring.iter_from(1).collect::<Vec<_>>(),
+            vec![1, 9, 2, 6, 5, 8, 3, 7, 4]
+        );
+
+        assert_eq!(part1("389125467").unwrap(), 67384529);
+
+        assert!(play(&[], 4, 1).is_err());
+        assert!(play(&[1, 2, 4], 0, 1).is_err());
+        assert!(play(&[1, 2, 3], 0, 1).is_err());
+        assert_eq!(play(&[1, 2, 3], 4, 1).unwrap().len(), 4);
+        assert!(play(&[1, 2, 3], MAX_CUPS + 1, 1).is_err());
+        assert!(play(&[1, 2, 3], u32::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2("389125467").unwrap(), 149245887792);
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+use std::io;
+
+use anyhow::Result;
+use aoc_automaton::{Automaton, Hex, Life};
+use aoc_error::ParseError;
+use aoc_geometry::{BoundingBox, HexCoord, Orientation, Point};
+use aoc_visualize::{Disabled, Frame, Rgb, Visualizer};
+
+/// The number of tiles left with the black side up.
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(flip_tiles(&parse_paths(input)?).len())
+}
+
+/// The number of black tiles after 100 days.
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(evolve_tiles(
+        flip_tiles(&parse_paths(input)?),
+        100,
+        &mut Disabled,
+    )?)
+}
+
+/// The steps from the reference tile to each tile to flip.
+pub fn parse_paths(input: &str) -> Result<Vec<Vec<HexCoord>>, ParseError> {
+    Orientation::Pointy.parse_paths(input)
+}
+
+/// The tiles left with the black side up.
+pub fn flip_tiles(paths: &[Vec<HexCoord>]) -> HashSet<HexCoord> {
+    let mut black = HashSet::new();
+
+    for path in paths {
+        let tile = path
+            .iter()
+            .fold(HexCoord::ORIGIN, |tile, step| tile + *step);
+
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    }
+
+    black
+}
+
+/// Flip the tiles every day for `days` days, returning the number of black
+/// tiles.
+pub fn evolve_tiles(
+    black: HashSet<HexCoord>,
+    days: usize,
+    visualizer: &mut dyn Visualizer,
+) -> io::Result<usize> {
+    // Black tiles with one or two black neighbours stay black and white tiles
+    // with two black neighbours are flipped to black.
+    let mut tiles = Automaton::new(Hex, Life::new(&[2], &[1, 2]), black);
+
+    for _ in 0..days {
+        visualizer.emit(|| draw(tiles.alive()))?;
+        tiles.step();
+    }
+    visualizer.emit(|| draw(tiles.alive()))?;
+
+    Ok(tiles.population())
+}
+
+/// Draw the black tiles and the white tiles around them.
+fn draw(black: &HashSet<HexCoord>) -> Frame {
+    let tiles = black
+        .iter()
+        .flat_map(|tile| tile.neighbours())
+        .chain(black.iter().copied())
+        .collect::<HashSet<_>>();
+
+    let layout = |tile: HexCoord| Orientation::Pointy.layout(tile);
+    let bounds = BoundingBox::from_points(tiles.iter().map(|tile| layout(*tile)))
+        .unwrap_or_else(|| BoundingBox::new(Point::new(0, 0)));
+
+    let mut frame = Frame::new(bounds.width(), bounds.height());
+    for tile in tiles {
+        let p = layout(tile) - bounds.min;
+        let (x, y) = (p.x() as usize, p.y() as usize);
+
+        if black.contains(&tile) {
+            frame.set(x, y, '#', Rgb::WHITE);
+        } else {
+            frame.set(x, y, '.', Rgb::GREY);
+        }
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew
+";
+
+        assert_eq!(part1(input).unwrap(), 10);
+        assert_eq!(part2(input).unwrap(), 2208);
+    }
+}
@@ -0,0 +1,39 @@
+use anyhow::{anyhow, Result};
+use aoc_error::{parse_at, ParseError};
+use aoc_math::{discrete_log, pow_mod};
+
+/// The modulus of the handshake transformations.
+const MODULUS: u64 = 20201227;
+
+/// The encryption key the card and door use to handshake.
+pub fn part1(input: &str) -> Result<u64> {
+    let (card_key, door_key) = parse_keys(input)?;
+
+    let loop_size = discrete_log(7, card_key, MODULUS)
+        .ok_or_else(|| anyhow!("the card key is not a power of 7"))?;
+
+    Ok(pow_mod(door_key, loop_size, MODULUS))
+}
+
+pub fn parse_keys(input: &str) -> Result<(u64, u64), ParseError> {
+    let mut iter = input.lines().map(|line| parse_at(input, line));
+
+    let a = iter
+        .next()
+        .ok_or_else(|| ParseError::new("missing card key"))??;
+    let b = iter
+        .next()
+        .ok_or_else(|| ParseError::new("missing door key"))??;
+
+    Ok((a, b))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("5764801\n17807724\n").unwrap(), 14897079);
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Day {
+    pub fn new(year: u16, day: u8) -> Result<Self> {
+        if year < 2015 {
+            bail!("there was no Advent of Code in {}", year);
+        }
+
+        if !(1..=25).contains(&day) {
+            bail!("day must be between 1 and 25, not {}", day);
+        }
+
+        Ok(Day { year, day })
+    }
+
+    /// The path of the crate relative to the workspace root, e.g., `2020/day01`.
+    pub fn member(&self) -> String {
+        format!("{}/day{:02}", self.year, self.day)
+    }
+
+    pub fn path(&self) -> PathBuf {
+        PathBuf::from(self.year.to_string()).join(format!("day{:02}", self.day))
+    }
+
+    /// Parse a workspace member of the form `yyyy/dayNN`.
+    ///
+    /// Returns `None` for members that are not days, e.g., `common/aoc`.
+    pub fn from_member(member: &str) -> Option<Self> {
+        let (year, day) = member.split_once('/')?;
+        let day = day.strip_prefix("day")?;
+
+        if year.len() != 4 || day.len() != 2 {
+            return None;
+        }
+
+        Day::new(year.parse().ok()?, day.parse().ok()?).ok()
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.member())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_member() {
+        assert_eq!(
+            Day::from_member("2018/day09"),
+            Some(Day { year: 2018, day: 9 })
+        );
+        assert_eq!(Day::from_member("common/aoc"), None);
+        assert_eq!(Day::from_member("2020/day26"), None);
+        assert_eq!(Day::from_member("2020/day1"), None);
+    }
+}
@@ -0,0 +1,137 @@
+use std::process::exit;
+use std::thread;
+
+use anyhow::{bail, Result};
+use structopt::StructOpt;
+
+mod day;
+mod runner;
+mod scaffold;
+mod workspace;
+
+use crate::day::Day;
+use crate::workspace::Workspace;
+
+#[derive(StructOpt)]
+#[structopt(name = "aoc")]
+enum Command {
+    /// Generate the crate for a new day, or for every day of a new year.
+    #[structopt(name = "new")]
+    New { year: u16, day: Option<u8> },
+
+    /// Run a day, or every day with --all, and print a table of answers and timings.
+    #[structopt(name = "run")]
+    Run {
+        /// Run every day, or every day of `year` if given.
+        #[structopt(long = "all")]
+        all: bool,
+
+        /// The number of days to run in parallel.
+        #[structopt(long = "jobs", short = "j")]
+        jobs: Option<usize>,
+
+        year: Option<u16>,
+        day: Option<u8>,
+    },
+
+    /// Print a random, well-formed input for a day to stdout.
+    #[structopt(name = "generate")]
+    Generate {
+        /// The number of entries (usually lines) to generate.
+        #[structopt(long = "size", default_value = "1000")]
+        size: usize,
+
+        #[structopt(long = "seed", default_value = "0")]
+        seed: u64,
+
+        year: u16,
+        day: u8,
+    },
+}
+
+fn main() -> Result<()> {
+    match Command::from_args() {
+        Command::New { year, day } => {
+            let mut workspace = Workspace::find()?;
+
+            let days = match day {
+                Some(day) => vec![Day::new(year, day)?],
+                None => (1..=25)
+                    .map(|day| Day::new(year, day))
+                    .collect::<Result<_>>()?,
+            };
+
+            // Check every day first so that a day that already exists does not
+            // leave the rest of the year half-scaffolded.
+            for &day in &days {
+                scaffold::check_new_day(&workspace, day)?;
+            }
+
+            for day in days {
+                scaffold::new_day(&mut workspace, day)?;
+                println!("created {}", day.member());
+            }
+
+            workspace.save()
+        }
+
+        Command::Run {
+            all,
+            jobs,
+            year,
+            day,
+        } => {
+            let workspace = Workspace::find()?;
+
+            let days = match (all, year, day) {
+                (false, Some(year), Some(day)) => vec![Day::new(year, day)?],
+                (false, _, _) => bail!("expected a year and day, or --all"),
+                (true, _, Some(_)) => bail!("--all cannot be used with a day"),
+                (true, year, None) => workspace
+                    .members()?
+                    .iter()
+                    .filter_map(|member| Day::from_member(member))
+                    .filter(|day| year.is_none_or(|year| day.year == year))
+                    .collect(),
+            };
+
+            let jobs = jobs.unwrap_or_else(|| {
+                thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+            });
+
+            let results = runner::run(&workspace, &days, jobs)?;
+            print!("{}", runner::table(&results));
+
+            let failed = results
+                .iter()
+                .filter(|result| matches!(result.status, runner::Status::Failed(_)))
+                .count();
+
+            if failed > 0 {
+                eprintln!("{} of {} days failed", failed, results.len());
+                exit(1);
+            }
+
+            Ok(())
+        }
+
+        Command::Generate {
+            size,
+            seed,
+            year,
+            day,
+        } => {
+            let day = Day::new(year, day)?;
+
+            match aoc_generate::generate(&mut aoc_generate::rng(seed), day.year, day.day, size) {
+                Some(input) => {
+                    print!("{}", input);
+                    Ok(())
+                }
+                None => bail!("there is no input generator for {}", day),
+            }
+        }
+    }
+}
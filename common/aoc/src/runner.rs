@@ -0,0 +1,431 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::Value;
+
+use crate::day::Day;
+use crate::workspace::Workspace;
+
+/// Days that cannot run unattended.
+const INTERACTIVE: &[(u16, u8)] = &[(2019, 25)];
+
+/// The answer to one part of a day.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Part {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub image: Option<String>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Status {
+    Passed,
+    Failed(String),
+    Skipped(&'static str),
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: Day,
+    pub parts: Vec<Part>,
+    pub status: Status,
+}
+
+struct Job {
+    day: Day,
+    dir: PathBuf,
+    executables: Vec<PathBuf>,
+}
+
+/// Build and run `days`, running up to `jobs` days at once.
+///
+/// Each day runs in its own process, so a day that panics or exits with an
+/// error is reported as failed without affecting the others.
+pub fn run(workspace: &Workspace, days: &[Day], jobs: usize) -> Result<Vec<DayResult>> {
+    let executables = build(workspace)?;
+
+    let queue = days
+        .iter()
+        .map(|&day| Job {
+            day,
+            dir: workspace.root().join(day.path()),
+            executables: executables.get(&day).cloned().unwrap_or_default(),
+        })
+        .collect::<VecDeque<_>>();
+
+    let queue = Mutex::new(queue);
+    let results = Mutex::new(Vec::with_capacity(days.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| loop {
+                let job = match queue.lock().unwrap().pop_front() {
+                    Some(job) => job,
+                    None => break,
+                };
+
+                let result = run_day(&job);
+                results.lock().unwrap().push(result);
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|result| result.day);
+
+    Ok(results)
+}
+
+/// Build every binary in the workspace, returning the executables of each day.
+fn build(workspace: &Workspace) -> Result<BTreeMap<Day, Vec<PathBuf>>> {
+    let output = Command::new(cargo())
+        .args([
+            "build",
+            "--release",
+            "--workspace",
+            "--bins",
+            "--message-format=json",
+        ])
+        .current_dir(workspace.root())
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Could not run cargo")?;
+
+    if !output.status.success() {
+        bail!("cargo build failed");
+    }
+
+    let mut executables = BTreeMap::<Day, Vec<PathBuf>>::new();
+
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let message: Value = serde_json::from_str(line).context("Could not parse cargo output")?;
+
+        if message["reason"] != "compiler-artifact" {
+            continue;
+        }
+
+        let (manifest, executable) = match (
+            message["manifest_path"].as_str(),
+            message["executable"].as_str(),
+        ) {
+            (Some(manifest), Some(executable)) => (manifest, executable),
+            _ => continue,
+        };
+
+        let day = Path::new(manifest)
+            .parent()
+            .and_then(|dir| dir.strip_prefix(workspace.root()).ok())
+            .and_then(|member| Day::from_member(&member.to_string_lossy()));
+
+        if let Some(day) = day {
+            executables.entry(day).or_default().push(executable.into());
+        }
+    }
+
+    for executables in executables.values_mut() {
+        executables.sort();
+    }
+
+    Ok(executables)
+}
+
+/// The cargo that is running us, if any, so that we use the same toolchain.
+fn cargo() -> String {
+    env::var("CARGO").unwrap_or_else(|_| "cargo".into())
+}
+
+fn run_day(job: &Job) -> DayResult {
+    let mut result = DayResult {
+        day: job.day,
+        parts: vec![],
+        status: Status::Passed,
+    };
+
+    if INTERACTIVE.contains(&(job.day.year, job.day.day)) {
+        result.status = Status::Skipped("interactive");
+        return result;
+    }
+
+    if job.executables.is_empty() {
+        result.status = Status::Failed("no binaries".into());
+        return result;
+    }
+
+    for executable in &job.executables {
+        if let Err(e) = run_executable(job, executable, &mut result.parts) {
+            result.status = Status::Failed(e.to_string());
+            break;
+        }
+    }
+
+    result.parts.sort_by_key(|part| part.part);
+    result
+}
+
+fn run_executable(job: &Job, executable: &Path, parts: &mut Vec<Part>) -> Result<()> {
+    let args = match arguments(job.day) {
+        Some(parse) => {
+            let input = fs::read_to_string(job.dir.join("input"))
+                .map_err(|e| anyhow!("could not read input: {}", e))?;
+            parse(&input)
+        }
+        None => vec![],
+    };
+
+    let start = Instant::now();
+    let output = Command::new(executable)
+        .args(&args)
+        .args(["--format", "json"])
+        .current_dir(&job.dir)
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("could not run `{}'", executable.display()))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!(describe_failure(&stderr).unwrap_or_else(|| {
+            format!("exited with {} after {:?}", output.status, start.elapsed())
+        })));
+    }
+
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        parts.push(parse_part(line)?);
+    }
+
+    Ok(())
+}
+
+/// Days that take their input on the command line instead of from `input`.
+fn arguments(day: Day) -> Option<fn(&str) -> Vec<String>> {
+    match (day.year, day.day) {
+        // `N players; last marble is worth M points`
+        (2018, 9) => Some(|input| {
+            input
+                .split_whitespace()
+                .filter(|word| word.parse::<u32>().is_ok())
+                .map(String::from)
+                .collect()
+        }),
+        (2018, 11) | (2020, 15) | (2020, 23) => Some(|input| vec![input.trim().into()]),
+        _ => None,
+    }
+}
+
+fn parse_part(line: &str) -> Result<Part> {
+    let value: Value =
+        serde_json::from_str(line).with_context(|| format!("unexpected output `{}'", line))?;
+
+    let part = value["part"]
+        .as_u64()
+        .ok_or_else(|| anyhow!("output has no part: `{}'", line))?;
+    let elapsed = value["elapsed_ms"].as_f64().unwrap_or(0.0);
+
+    Ok(Part {
+        part: part as u8,
+        answer: value["answer"].as_str().map(String::from),
+        image: value["image"].as_str().map(String::from),
+        elapsed: Duration::from_secs_f64(elapsed / 1000.0),
+    })
+}
+
+/// Summarize why a day failed from its stderr.
+fn describe_failure(stderr: &str) -> Option<String> {
+    let mut lines = stderr.lines();
+
+    while let Some(line) = lines.next() {
+        // thread 'main' panicked at src/main.rs:10:5:
+        // called `Option::unwrap()` on a `None` value
+        if line.starts_with("thread '") && line.contains("panicked at") {
+            let location = line.split("panicked at ").nth(1).unwrap_or("");
+            let message = lines.next().unwrap_or("").trim();
+            return Some(format!(
+                "panicked at {}: {}",
+                location.trim_end_matches(':'),
+                message
+            ));
+        }
+
+        if let Some(error) = line.strip_prefix("Error: ") {
+            return Some(error.into());
+        }
+
+        if let Some(error) = line.strip_prefix("error: ") {
+            return Some(error.into());
+        }
+    }
+
+    None
+}
+
+/// Render `results` as a table with one row per part.
+pub fn table(results: &[DayResult]) -> String {
+    let mut rows = vec![[
+        "day".to_string(),
+        "part".into(),
+        "answer".into(),
+        "time".into(),
+    ]];
+
+    for result in results {
+        for part in &result.parts {
+            let answer = match (&part.answer, &part.image) {
+                (Some(answer), _) => answer.clone(),
+                (None, Some(_)) => "(image)".into(),
+                (None, None) => "".into(),
+            };
+
+            rows.push([
+                result.day.to_string(),
+                part.part.to_string(),
+                answer,
+                format_duration(part.elapsed),
+            ]);
+        }
+
+        match &result.status {
+            Status::Passed => {}
+            Status::Failed(e) => rows.push([
+                result.day.to_string(),
+                "-".into(),
+                format!("FAILED: {}", e),
+                "".into(),
+            ]),
+            Status::Skipped(why) => rows.push([
+                result.day.to_string(),
+                "-".into(),
+                format!("skipped ({})", why),
+                "".into(),
+            ]),
+        }
+    }
+
+    // Failures and skips have no time, and are long enough that they would
+    // push the time of every other row off to the side.
+    let mut widths = [0; 4];
+    for row in rows.iter().filter(|row| !row[3].is_empty()) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut s = String::new();
+    for row in &rows {
+        let line = format!(
+            "{:<w0$}  {:>w1$}  {:<w2$}  {:>w3$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+        s.push_str(line.trim_end());
+        s.push('\n');
+    }
+
+    s
+}
+
+fn format_duration(d: Duration) -> String {
+    if d < Duration::from_millis(1) {
+        format!("{}µs", d.as_micros())
+    } else if d < Duration::from_secs(1) {
+        format!("{:.1}ms", d.as_secs_f64() * 1000.0)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_describe_failure() {
+        assert_eq!(
+            describe_failure(
+                "thread 'main' panicked at 2020/day01/src/main.rs:10:5:\n\
+                 called `Option::unwrap()` on a `None` value\n\
+                 note: run with `RUST_BACKTRACE=1`\n"
+            ),
+            Some(
+                "panicked at 2020/day01/src/main.rs:10:5: called `Option::unwrap()` on a `None` value"
+                    .into()
+            )
+        );
+        assert_eq!(
+            describe_failure("Error: No such file or directory (os error 2)\n"),
+            Some("No such file or directory (os error 2)".into())
+        );
+        assert_eq!(describe_failure(""), None);
+    }
+
+    #[test]
+    fn test_table() {
+        let results = vec![
+            DayResult {
+                day: Day::new(2019, 8).unwrap(),
+                parts: vec![
+                    Part {
+                        part: 1,
+                        answer: Some("1806".into()),
+                        image: None,
+                        elapsed: Duration::from_micros(250),
+                    },
+                    Part {
+                        part: 2,
+                        answer: None,
+                        image: Some("#\n".into()),
+                        elapsed: Duration::from_millis(12),
+                    },
+                ],
+                status: Status::Passed,
+            },
+            DayResult {
+                day: Day::new(2019, 25).unwrap(),
+                parts: vec![],
+                status: Status::Skipped("interactive"),
+            },
+            DayResult {
+                day: Day::new(2020, 1).unwrap(),
+                parts: vec![],
+                status: Status::Failed("panicked".into()),
+            },
+        ];
+
+        assert_eq!(
+            table(&results),
+            "day         part  answer     time\n\
+             2019/day08     1  1806      250µs\n\
+             2019/day08     2  (image)  12.0ms\n\
+             2019/day25     -  skipped (interactive)\n\
+             2020/day01     -  FAILED: panicked\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(
+            parse_part(r#"{"answer":"67384529","day":23,"elapsed_ms":1.5,"image":null,"part":1,"year":2020}"#)
+                .unwrap(),
+            Part {
+                part: 1,
+                answer: Some("67384529".into()),
+                image: None,
+                elapsed: Duration::from_micros(1500),
+            }
+        );
+
+        assert!(parse_part("part 1: 1234").is_err());
+    }
+}
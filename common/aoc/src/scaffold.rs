@@ -0,0 +1,50 @@
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+
+use crate::day::Day;
+use crate::workspace::Workspace;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+const MAIN_RS: &str = include_str!("../templates/main.rs.in");
+
+fn render(template: &str, day: Day) -> String {
+    template
+        .replace("{{year}}", &day.year.to_string())
+        .replace("{{day}}", &format!("{:02}", day.day))
+        .replace("{{day_number}}", &day.day.to_string())
+}
+
+/// Check that the crate for `day` does not exist yet.
+pub fn check_new_day(workspace: &Workspace, day: Day) -> Result<()> {
+    let dir = workspace.root().join(day.path());
+
+    if dir.exists() {
+        bail!("`{}' already exists", dir.display());
+    }
+
+    Ok(())
+}
+
+/// Generate the crate for `day` and add it to the workspace.
+pub fn new_day(workspace: &mut Workspace, day: Day) -> Result<()> {
+    check_new_day(workspace, day)?;
+
+    let dir = workspace.root().join(day.path());
+    let src = dir.join("src");
+    fs::create_dir_all(&src).with_context(|| format!("Could not create `{}'", src.display()))?;
+
+    for (path, template) in &[
+        (dir.join("Cargo.toml"), CARGO_TOML),
+        (src.join("lib.rs"), LIB_RS),
+        (src.join("main.rs"), MAIN_RS),
+    ] {
+        fs::write(path, render(template, day))
+            .with_context(|| format!("Could not write `{}'", path.display()))?;
+    }
+
+    workspace.add_member(&day.member())?;
+
+    Ok(())
+}
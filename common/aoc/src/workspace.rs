@@ -0,0 +1,176 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+
+pub struct Workspace {
+    root: PathBuf,
+    manifest: String,
+}
+
+impl Workspace {
+    /// Find the workspace containing the current directory.
+    pub fn find() -> Result<Self> {
+        let cwd = env::current_dir().context("Could not determine current directory")?;
+
+        for dir in cwd.ancestors() {
+            let path = dir.join("Cargo.toml");
+
+            if !path.is_file() {
+                continue;
+            }
+
+            let manifest = fs::read_to_string(&path)
+                .with_context(|| format!("Could not read `{}'", path.display()))?;
+
+            if manifest.lines().any(|line| line.trim() == "[workspace]") {
+                return Ok(Workspace {
+                    root: dir.into(),
+                    manifest,
+                });
+            }
+        }
+
+        Err(anyhow!("Could not find a workspace in `{}'", cwd.display()))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn members(&self) -> Result<Vec<String>> {
+        parse_members(&self.manifest)
+    }
+
+    /// Add a member to the workspace.
+    ///
+    /// Returns whether or not the member was added.
+    pub fn add_member(&mut self, member: &str) -> Result<bool> {
+        match insert_member(&self.manifest, member)? {
+            Some(manifest) => {
+                self.manifest = manifest;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = self.root.join("Cargo.toml");
+        fs::write(&path, &self.manifest)
+            .with_context(|| format!("Could not write `{}'", path.display()))
+    }
+}
+
+/// Find the byte range of the contents of the `members = [...]` array.
+///
+/// Only a `members` key at the start of a line counts, so the word can appear
+/// in comments or other keys.
+fn members_span(manifest: &str) -> Result<(usize, usize)> {
+    let mut offset = 0;
+    let mut key = None;
+
+    for line in manifest.split_inclusive('\n') {
+        let value = line
+            .trim_start()
+            .strip_prefix("members")
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('='));
+
+        if let Some(value) = value {
+            key = Some(offset + line.len() - value.len());
+            break;
+        }
+
+        offset += line.len();
+    }
+
+    let key = key.ok_or_else(|| anyhow!("workspace manifest has no members"))?;
+
+    let start = Some(&manifest[key..])
+        .filter(|value| value.trim_start().starts_with('['))
+        .and_then(|value| value.find('['))
+        .map(|i| key + i + 1)
+        .ok_or_else(|| anyhow!("workspace members are not an array"))?;
+
+    let end = manifest[start..]
+        .find(']')
+        .map(|i| start + i)
+        .ok_or_else(|| anyhow!("workspace members array is not terminated"))?;
+
+    Ok((start, end))
+}
+
+fn parse_members(manifest: &str) -> Result<Vec<String>> {
+    let (start, end) = members_span(manifest)?;
+
+    Ok(manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Insert `member` into the members array of `manifest`, keeping it sorted.
+///
+/// Returns `None` if `member` is already present.
+fn insert_member(manifest: &str, member: &str) -> Result<Option<String>> {
+    let mut members = parse_members(manifest)?;
+
+    if members.iter().any(|m| m == member) {
+        return Ok(None);
+    }
+
+    members.push(member.into());
+    members.sort();
+
+    let (start, end) = members_span(manifest)?;
+
+    let mut result = String::with_capacity(manifest.len() + member.len() + 8);
+    result.push_str(&manifest[..start]);
+    result.push('\n');
+    for member in &members {
+        result.push_str(&format!("    \"{}\",\n", member));
+    }
+    result.push_str(&manifest[end..]);
+
+    Ok(Some(result))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]\n\nmembers = [\n    \"2018/day01\",\n    \"2020/day02\",\n    \"common/aoc\",\n]\n";
+
+    #[test]
+    fn test_parse_members() {
+        assert_eq!(
+            parse_members(MANIFEST).unwrap(),
+            &["2018/day01", "2020/day02", "common/aoc"]
+        );
+
+        let manifest = format!(
+            "# The members are listed below.\n{}default-members = [\"common/aoc\"]\n",
+            MANIFEST
+        );
+        assert_eq!(
+            parse_members(&manifest).unwrap(),
+            &["2018/day01", "2020/day02", "common/aoc"]
+        );
+
+        assert!(parse_members("[workspace]\nmembers = \"2018/day01\"\n").is_err());
+    }
+
+    #[test]
+    fn test_insert_member() {
+        assert_eq!(
+            insert_member(MANIFEST, "2020/day01").unwrap().unwrap(),
+            "[workspace]\n\nmembers = [\n    \"2018/day01\",\n    \"2020/day01\",\n    \"2020/day02\",\n    \"common/aoc\",\n]\n"
+        );
+
+        assert!(insert_member(MANIFEST, "2018/day01").unwrap().is_none());
+    }
+}
@@ -0,0 +1,152 @@
+use std::mem::swap;
+
+use aoc_geometry::{BoundingBox, Point};
+use aoc_grid::Grid;
+
+use crate::rule::Rule;
+use crate::topology::Topology;
+
+/// An automaton on a bounded grid, where cells can be in any state.
+///
+/// Neighbours outside of the grid are ignored. The next generation is written
+/// into a second grid, which is swapped with the first after every step.
+#[derive(Clone, Debug)]
+pub struct GridAutomaton<T, R, C> {
+    topology: T,
+    rule: R,
+    grid: Grid<C>,
+    next: Grid<C>,
+    generation: usize,
+}
+
+impl<T, R, C> GridAutomaton<T, R, C>
+where
+    T: Topology<Cell = Point>,
+    R: Rule<C>,
+    C: Copy + PartialEq,
+{
+    pub fn new(topology: T, rule: R, grid: Grid<C>) -> Self {
+        GridAutomaton {
+            topology,
+            rule,
+            next: grid.clone(),
+            grid,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<C> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<C> {
+        self.grid
+    }
+
+    /// The number of steps taken so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Advance by one generation, returning whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let mut changed = false;
+
+        for p in self.grid.points() {
+            let cell = self.grid[p];
+            let neighbours = self
+                .topology
+                .neighbours(p)
+                .filter_map(|q| self.grid.get(q).copied());
+
+            let next = self.rule.next(cell, neighbours);
+            self.next[p] = next;
+
+            changed |= next != cell;
+        }
+
+        swap(&mut self.grid, &mut self.next);
+        self.generation += 1;
+
+        changed
+    }
+
+    /// Advance by `generations` generations.
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Step until nothing changes, returning the number of steps that changed
+    /// the grid.
+    pub fn run_until_stable(&mut self) -> usize {
+        let start = self.generation;
+        while self.step() {}
+
+        self.generation - start - 1
+    }
+}
+
+impl<T, R> GridAutomaton<T, R, bool> {
+    /// The number of live cells.
+    pub fn population(&self) -> usize {
+        self.grid.cells().filter(|&&alive| alive).count()
+    }
+
+    /// The smallest box containing every live cell, or `None` if every cell
+    /// is dead.
+    pub fn bounds(&self) -> Option<BoundingBox<2>> {
+        BoundingBox::from_points(self.grid.iter().filter(|(_, &alive)| alive).map(|(p, _)| p))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rule::Life;
+    use crate::topology::{Moore, VonNeumann};
+
+    fn parse(s: &str) -> Grid<bool> {
+        Grid::parse(s, |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_blinker() {
+        let vertical = parse(".....\n..#..\n..#..\n..#..\n.....\n");
+        let horizontal = parse(".....\n.....\n.###.\n.....\n.....\n");
+        let mut life = GridAutomaton::new(Moore, Life::new(&[3], &[2, 3]), vertical.clone());
+
+        assert!(life.step());
+        assert_eq!(life.grid(), &horizontal);
+        assert_eq!(life.population(), 3);
+        assert_eq!(
+            life.bounds(),
+            Some(BoundingBox {
+                min: Point::new(1, 2),
+                max: Point::new(3, 2),
+            })
+        );
+
+        life.run(3);
+        assert_eq!(life.generation(), 4);
+        assert_eq!(life.grid(), &vertical);
+    }
+
+    #[test]
+    fn test_edges() {
+        // A block in the corner is stable, since cells outside the grid are
+        // ignored.
+        let block = parse("##.\n##.\n...\n");
+        let mut life = GridAutomaton::new(Moore, Life::new(&[3], &[2, 3]), block);
+        assert_eq!(life.run_until_stable(), 0);
+        assert_eq!(life.population(), 4);
+
+        let bug = parse("#..\n...\n...\n");
+        let mut bugs = GridAutomaton::new(VonNeumann, Life::new(&[1], &[0]), bug);
+        bugs.step();
+        assert_eq!(bugs.grid(), &parse("##.\n#..\n...\n"));
+        bugs.step();
+        assert_eq!(bugs.grid(), &parse("..#\n...\n#..\n"));
+    }
+}
@@ -0,0 +1,20 @@
+//! Cellular automata.
+//!
+//! An automaton is a `Topology`, which says which cells neighbour each other,
+//! and a `Rule`, which gives the next state of a cell from its current state
+//! and the states of its neighbours. A `GridAutomaton` stores every cell of a
+//! bounded grid, while an `Automaton` only stores the live cells and so can
+//! grow without bound. A `MirroredLife` also only stores one reflection of
+//! each live cell.
+
+mod grid;
+mod mirrored;
+mod rule;
+mod sparse;
+mod topology;
+
+pub use crate::grid::GridAutomaton;
+pub use crate::mirrored::MirroredLife;
+pub use crate::rule::{Life, Rule};
+pub use crate::sparse::Automaton;
+pub use crate::topology::{Hex, Line, Moore, Recursive, Topology, VonNeumann};
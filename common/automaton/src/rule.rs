@@ -0,0 +1,85 @@
+/// How a cell changes from one generation to the next.
+pub trait Rule<T> {
+    /// The next state of a cell in state `cell` with neighbours in the states
+    /// `neighbours`, in the order the topology gives them.
+    fn next<I>(&self, cell: T, neighbours: I) -> T
+    where
+        I: Iterator<Item = T>;
+}
+
+/// A rule for cells that are either alive or dead that only depends on how
+/// many neighbours are alive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Life {
+    birth: u128,
+    survival: u128,
+}
+
+impl Life {
+    /// A rule where dead cells with a number of live neighbours in `birth`
+    /// come to life and live cells with a number of live neighbours in
+    /// `survival` stay alive. Every other cell is dead in the next generation.
+    ///
+    /// Panics if any of the counts is 128 or more.
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        let mask = |counts: &[usize]| {
+            counts.iter().fold(0, |mask, &n| {
+                assert!(n < 128, "{} neighbours is too many for a rule", n);
+                mask | 1 << n
+            })
+        };
+
+        Life {
+            birth: mask(birth),
+            survival: mask(survival),
+        }
+    }
+
+    pub fn born(&self, neighbours: usize) -> bool {
+        neighbours < 128 && self.birth & 1 << neighbours != 0
+    }
+
+    pub fn survives(&self, neighbours: usize) -> bool {
+        neighbours < 128 && self.survival & 1 << neighbours != 0
+    }
+}
+
+impl Rule<bool> for Life {
+    fn next<I>(&self, alive: bool, neighbours: I) -> bool
+    where
+        I: Iterator<Item = bool>,
+    {
+        let n = neighbours.filter(|&alive| alive).count();
+
+        if alive {
+            self.survives(n)
+        } else {
+            self.born(n)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_life() {
+        let life = Life::new(&[3], &[2, 3]);
+
+        assert!(life.born(3));
+        assert!(!life.born(2));
+        assert!(life.survives(2));
+        assert!(!life.survives(4));
+        assert!(!life.survives(200));
+
+        assert!(life.next(false, vec![true, false, true, true].into_iter()));
+        assert!(!life.next(true, vec![true, false, false, false].into_iter()));
+    }
+
+    #[test]
+    #[should_panic(expected = "128 neighbours is too many for a rule")]
+    fn test_too_many_neighbours() {
+        Life::new(&[3], &[2, 128]);
+    }
+}
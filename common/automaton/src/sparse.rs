@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+use std::mem::swap;
+
+use aoc_geometry::{BoundingBox, Vector};
+
+use crate::rule::Rule;
+use crate::topology::Topology;
+
+/// An automaton that only stores its live cells, so it can grow without bound.
+///
+/// Only live cells and their neighbours are considered when stepping, so the
+/// rule must leave cells without any live neighbours dead.
+#[derive(Clone, Debug)]
+pub struct Automaton<T: Topology, R> {
+    topology: T,
+    rule: R,
+    alive: HashSet<T::Cell>,
+    next: HashSet<T::Cell>,
+    candidates: HashSet<T::Cell>,
+    generation: usize,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Topology,
+    R: Rule<bool>,
+{
+    pub fn new<I>(topology: T, rule: R, alive: I) -> Self
+    where
+        I: IntoIterator<Item = T::Cell>,
+    {
+        Automaton {
+            topology,
+            rule,
+            alive: alive.into_iter().collect(),
+            next: HashSet::new(),
+            candidates: HashSet::new(),
+            generation: 0,
+        }
+    }
+
+    pub fn topology(&self) -> &T {
+        &self.topology
+    }
+
+    /// The number of steps taken so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn alive(&self) -> &HashSet<T::Cell> {
+        &self.alive
+    }
+
+    pub fn is_alive(&self, cell: T::Cell) -> bool {
+        self.alive.contains(&cell)
+    }
+
+    /// The number of live cells.
+    pub fn population(&self) -> usize {
+        self.alive.len()
+    }
+
+    /// Advance by one generation, returning whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        self.candidates.clear();
+        for &cell in &self.alive {
+            self.candidates.insert(cell);
+            self.candidates.extend(self.topology.neighbours(cell));
+        }
+
+        let mut changed = false;
+        self.next.clear();
+
+        for &cell in &self.candidates {
+            let alive = self.alive.contains(&cell);
+            let neighbours = self
+                .topology
+                .neighbours(cell)
+                .map(|n| self.alive.contains(&n));
+
+            let next = self.rule.next(alive, neighbours);
+            if next {
+                self.next.insert(cell);
+            }
+
+            changed |= next != alive;
+        }
+
+        swap(&mut self.alive, &mut self.next);
+        self.generation += 1;
+
+        changed
+    }
+
+    /// Advance by `generations` generations.
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+}
+
+impl<T, R, const N: usize> Automaton<T, R>
+where
+    T: Topology<Cell = Vector<N>>,
+{
+    /// The smallest box containing every live cell, or `None` if every cell
+    /// is dead.
+    pub fn bounds(&self) -> Option<BoundingBox<N>> {
+        BoundingBox::from_points(self.alive.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_geometry::{HexCoord, Point};
+
+    use super::*;
+    use crate::rule::Life;
+    use crate::topology::{Hex, Moore};
+
+    #[test]
+    fn test_glider() {
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let mut life = Automaton::new(
+            Moore,
+            Life::new(&[3], &[2, 3]),
+            glider.iter().map(|&(x, y)| Point::new(x, y)),
+        );
+
+        assert_eq!(life.population(), 5);
+        assert_eq!(
+            life.bounds(),
+            Some(BoundingBox {
+                min: Point::new(0, 0),
+                max: Point::new(2, 2),
+            })
+        );
+
+        life.run(4);
+
+        assert_eq!(life.generation(), 4);
+        assert_eq!(life.population(), 5);
+        assert_eq!(
+            life.alive(),
+            &glider
+                .iter()
+                .map(|&(x, y)| Point::new(x + 1, y + 1))
+                .collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        let mut tiles = Automaton::new(
+            Hex,
+            Life::new(&[2], &[1, 2]),
+            vec![HexCoord::new(0, 0), HexCoord::new(1, 0)],
+        );
+
+        assert!(tiles.step());
+        assert_eq!(tiles.population(), 4);
+        assert!(tiles.is_alive(HexCoord::new(0, 1)));
+        assert!(tiles.is_alive(HexCoord::new(1, -1)));
+
+        let mut empty = Automaton::new(Hex, Life::new(&[2], &[1, 2]), vec![]);
+        assert!(!empty.step());
+    }
+}
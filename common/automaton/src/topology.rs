@@ -0,0 +1,268 @@
+use std::hash::Hash;
+
+use aoc_geometry::{HexCoord, Point, Vector, ORTHOGONAL};
+
+/// Which cells of an automaton neighbour each other.
+///
+/// Neighbourhoods must be symmetric, i.e., if `a` is a neighbour of `b` then
+/// `b` is a neighbour of `a`.
+pub trait Topology {
+    type Cell: Copy + Eq + Hash;
+
+    fn neighbours(&self, cell: Self::Cell) -> impl Iterator<Item = Self::Cell>;
+}
+
+/// A line of cells, each of which neighbours the `radius` cells on either side
+/// of it, from left to right.
+#[derive(Clone, Copy, Debug)]
+pub struct Line {
+    pub radius: isize,
+}
+
+impl Topology for Line {
+    type Cell = Vector<1>;
+
+    fn neighbours(&self, cell: Vector<1>) -> impl Iterator<Item = Vector<1>> {
+        (-self.radius..=self.radius)
+            .filter(|&d| d != 0)
+            .map(move |d| cell + Vector([d]))
+    }
+}
+
+/// An `N`-dimensional grid where cells neighbour every cell that differs by at
+/// most one along each axis.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Moore<const N: usize>;
+
+impl<const N: usize> Topology for Moore<N> {
+    type Cell = Vector<N>;
+
+    /// Each neighbour of `cell`, in the order given by `Vector`'s `Ord`.
+    fn neighbours(&self, cell: Vector<N>) -> impl Iterator<Item = Vector<N>> {
+        let count = 3usize.pow(N as u32);
+
+        // Each offset is a number in base 3, with a digit for each axis.
+        (0..count)
+            .filter(move |&i| i != count / 2)
+            .map(move |mut i| {
+                let mut p = cell;
+                for axis in 0..N {
+                    p[axis] += (i % 3) as isize - 1;
+                    i /= 3;
+                }
+                p
+            })
+    }
+}
+
+/// An `N`-dimensional grid where cells neighbour the cells one step away along
+/// a single axis.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct VonNeumann<const N: usize>;
+
+impl<const N: usize> Topology for VonNeumann<N> {
+    type Cell = Vector<N>;
+
+    fn neighbours(&self, cell: Vector<N>) -> impl Iterator<Item = Vector<N>> {
+        (0..N).flat_map(move |axis| {
+            let mut before = cell;
+            let mut after = cell;
+            before[axis] -= 1;
+            after[axis] += 1;
+
+            vec![before, after]
+        })
+    }
+}
+
+/// A grid of hexagons, which each neighbour the six hexagons around them.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hex;
+
+impl Topology for Hex {
+    type Cell = HexCoord;
+
+    fn neighbours(&self, cell: HexCoord) -> impl Iterator<Item = HexCoord> {
+        cell.neighbours()
+    }
+}
+
+/// Square grids of odd `size` nested inside each other, where the centre cell
+/// of each level is replaced by the whole of the next level.
+///
+/// Cells are a level and a position on that level. Cells next to the centre
+/// neighbour the cells along the facing edge of the next level and cells on the
+/// edge neighbour the cells next to the centre of the previous level.
+#[derive(Clone, Copy, Debug)]
+pub struct Recursive {
+    pub size: isize,
+}
+
+impl Recursive {
+    pub fn centre(&self) -> Point {
+        Point::new(self.size / 2, self.size / 2)
+    }
+
+    fn in_bounds(&self, p: Point) -> bool {
+        0 <= p.x() && p.x() < self.size && 0 <= p.y() && p.y() < self.size
+    }
+}
+
+impl Topology for Recursive {
+    type Cell = (isize, Point);
+
+    fn neighbours(&self, (level, p): (isize, Point)) -> impl Iterator<Item = (isize, Point)> {
+        let mut neighbours = Vec::new();
+
+        for &d in &ORTHOGONAL {
+            let q = p + d;
+
+            if !self.in_bounds(q) {
+                neighbours.push((level - 1, self.centre() + d));
+            } else if q == self.centre() {
+                // Step onto the edge of the next level that faces `p`.
+                let edge = if d.x() + d.y() > 0 { 0 } else { self.size - 1 };
+
+                for i in 0..self.size {
+                    let q = if d.x() == 0 {
+                        Point::new(i, edge)
+                    } else {
+                        Point::new(edge, i)
+                    };
+
+                    neighbours.push((level + 1, q));
+                }
+            } else {
+                neighbours.push((level, q));
+            }
+        }
+
+        neighbours.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn neighbours<T: Topology>(topology: T, cell: T::Cell) -> HashSet<T::Cell> {
+        topology.neighbours(cell).collect()
+    }
+
+    #[test]
+    fn test_moore() {
+        let p = Vector([3, -1, 0, 2]);
+        let ns = Moore.neighbours(p).collect::<Vec<_>>();
+
+        assert_eq!(ns.len(), 80);
+        assert!(!ns.contains(&p));
+        assert!(ns.iter().all(|q| q.chebyshev_to(p) == 1));
+        assert!(ns.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(
+            Moore.neighbours(Point::new(0, 0)).collect::<Vec<_>>(),
+            aoc_geometry::ADJACENT.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_von_neumann() {
+        assert_eq!(
+            neighbours(VonNeumann, Point::new(1, 1)),
+            ORTHOGONAL.iter().map(|d| Point::new(1, 1) + *d).collect()
+        );
+        assert_eq!(VonNeumann.neighbours(Vector([0, 0, 0])).count(), 6);
+    }
+
+    #[test]
+    fn test_line() {
+        assert_eq!(
+            Line { radius: 2 }
+                .neighbours(Vector([5]))
+                .collect::<Vec<_>>(),
+            vec![Vector([3]), Vector([4]), Vector([6]), Vector([7])]
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        for &d in &HexCoord::DIRECTIONS {
+            assert!(neighbours(Hex, d).contains(&HexCoord::ORIGIN));
+        }
+    }
+
+    #[test]
+    fn test_recursive() {
+        let grids = Recursive { size: 5 };
+
+        assert_eq!(
+            neighbours(grids, (0, Point::new(1, 1))),
+            vec![
+                (0, Point::new(0, 1)),
+                (0, Point::new(2, 1)),
+                (0, Point::new(1, 0)),
+                (0, Point::new(1, 2)),
+            ]
+            .into_iter()
+            .collect()
+        );
+
+        assert_eq!(
+            neighbours(grids, (0, Point::new(0, 0))),
+            vec![
+                (0, Point::new(0, 1)),
+                (0, Point::new(1, 0)),
+                (-1, Point::new(2, 1)),
+                (-1, Point::new(1, 2)),
+            ]
+            .into_iter()
+            .collect()
+        );
+
+        assert_eq!(
+            neighbours(grids, (0, Point::new(2, 1))),
+            vec![
+                (0, Point::new(1, 1)),
+                (0, Point::new(3, 1)),
+                (0, Point::new(2, 0)),
+                (1, Point::new(0, 0)),
+                (1, Point::new(1, 0)),
+                (1, Point::new(2, 0)),
+                (1, Point::new(3, 0)),
+                (1, Point::new(4, 0)),
+            ]
+            .into_iter()
+            .collect()
+        );
+
+        assert_eq!(
+            neighbours(grids, (3, Point::new(3, 2))),
+            vec![
+                (3, Point::new(3, 1)),
+                (3, Point::new(3, 3)),
+                (3, Point::new(4, 2)),
+                (4, Point::new(4, 0)),
+                (4, Point::new(4, 1)),
+                (4, Point::new(4, 2)),
+                (4, Point::new(4, 3)),
+                (4, Point::new(4, 4)),
+            ]
+            .into_iter()
+            .collect()
+        );
+
+        for level in -1..=1 {
+            for p in (0..5).flat_map(|y| (0..5).map(move |x| Point::new(x, y))) {
+                if p == grids.centre() {
+                    continue;
+                }
+
+                for q in grids.neighbours((level, p)) {
+                    assert!(neighbours(grids, q).contains(&(level, p)));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::hash::Hash;
+use std::ops::Sub;
+
+use crate::Cycle;
+
+/// Finds a cycle by remembering every state in a sequence.
+///
+/// States are given one step at a time, so the sequence can come from
+/// something that changes in place. Each state can be split into a shape and
+/// a position, in which case the cycle ends at the first repeated shape.
+#[derive(Clone, Debug)]
+pub struct Detector<K, P = ()> {
+    seen: HashMap<K, (usize, P)>,
+    step: usize,
+}
+
+impl<K, P> Detector<K, P>
+where
+    K: Eq + Hash,
+{
+    pub fn new() -> Self {
+        Detector {
+            seen: HashMap::new(),
+            step: 0,
+        }
+    }
+
+    /// The number of states given so far.
+    pub fn steps(&self) -> usize {
+        self.step
+    }
+
+    /// Record the next state, returning the step and position it was first
+    /// seen at if it has been seen before.
+    fn insert(&mut self, shape: K, position: P) -> Option<(usize, &P)> {
+        self.step += 1;
+
+        match self.seen.entry(shape) {
+            Entry::Occupied(e) => {
+                let (start, first) = e.into_mut();
+                Some((*start, first))
+            }
+            Entry::Vacant(e) => {
+                e.insert((self.step - 1, position));
+                None
+            }
+        }
+    }
+}
+
+impl<K> Detector<K>
+where
+    K: Eq + Hash,
+{
+    /// Record the next state, returning the cycle if the state has been seen
+    /// before.
+    pub fn push(&mut self, state: K) -> Option<Cycle> {
+        let step = self.step;
+        let (start, _) = self.insert(state, ())?;
+
+        Some(Cycle {
+            start,
+            length: step - start,
+            offset: (),
+        })
+    }
+}
+
+impl<K, P> Detector<K, P>
+where
+    K: Eq + Hash,
+    P: Copy + Sub<Output = P>,
+{
+    /// Record the next state as its shape and position, returning the cycle
+    /// if the shape has been seen before.
+    pub fn push_at(&mut self, shape: K, position: P) -> Option<Cycle<P>> {
+        let step = self.step;
+        let (start, &first) = self.insert(shape, position)?;
+
+        Some(Cycle {
+            start,
+            length: step - start,
+            offset: position - first,
+        })
+    }
+}
+
+impl<K, P> Default for Detector<K, P>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Detector::new()
+    }
+}
+
+/// The first cycle in `states`, or `None` if the states run out before any of
+/// them repeat.
+pub fn find_cycle<I>(states: I) -> Option<Cycle>
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash,
+{
+    let mut detector = Detector::new();
+
+    states.into_iter().find_map(|state| detector.push(state))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        assert_eq!(
+            find_cycle("abcdefdefdef".chars()),
+            Some(Cycle {
+                start: 3,
+                length: 3,
+                offset: (),
+            })
+        );
+        assert_eq!(find_cycle("abc".chars()), None);
+    }
+
+    #[test]
+    fn test_push_at() {
+        // A glider that moves one cell every two steps.
+        let mut detector = Detector::new();
+        let states = vec![("x", 0), ("o", 0), ("+", 0), ("o", 1), ("+", 1)];
+
+        let cycle = states
+            .into_iter()
+            .find_map(|(shape, position)| detector.push_at(shape, position));
+
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                start: 1,
+                length: 2,
+                offset: 1,
+            })
+        );
+        assert_eq!(detector.steps(), 4);
+    }
+}
@@ -0,0 +1,111 @@
+//! Finding where a sequence of states starts repeating itself.
+//!
+//! `floyd` and `brent` need a function from each state to the next and only
+//! keep a couple of states around at once, while a `Detector` remembers every
+//! state it is given. A `Detector` can also find cycles where the state comes
+//! back to the same shape in a different position.
+
+mod detector;
+mod tortoise;
+
+use std::convert::TryFrom;
+use std::ops::Mul;
+
+pub use crate::detector::{find_cycle, Detector};
+pub use crate::tortoise::{brent, floyd};
+
+/// A cycle in a sequence of states.
+///
+/// The state at step `start + length` is the same as the state at `start`,
+/// except moved by `offset`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle<P = ()> {
+    /// The first step in the cycle.
+    pub start: usize,
+    pub length: usize,
+    pub offset: P,
+}
+
+// The step `n` is a `u64` so that steps far past the cycle still fit on 32-bit
+// targets.
+impl<P> Cycle<P> {
+    /// The step before the end of the first time around the cycle that is in
+    /// the same state as step `n`, up to its position.
+    pub fn reduce(&self, n: u64) -> u64 {
+        let (start, length) = (self.start as u64, self.length as u64);
+
+        if n < start {
+            n
+        } else {
+            start + (n - start) % length
+        }
+    }
+
+    /// The number of times around the cycle between `reduce(n)` and `n`.
+    pub fn laps(&self, n: u64) -> u64 {
+        let (start, length) = (self.start as u64, self.length as u64);
+
+        if n < start {
+            0
+        } else {
+            (n - start) / length
+        }
+    }
+}
+
+impl<P> Cycle<P>
+where
+    P: Copy + Mul<i64, Output = P>,
+{
+    /// How far the state at step `n` has moved from the state at `reduce(n)`,
+    /// or `None` if the number of laps does not fit in an `i64`.
+    pub fn shift(&self, n: u64) -> Option<P> {
+        i64::try_from(self.laps(n))
+            .ok()
+            .map(|laps| self.offset * laps)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reduce() {
+        let cycle = Cycle {
+            start: 2,
+            length: 4,
+            offset: 3,
+        };
+
+        assert_eq!(
+            (cycle.reduce(1), cycle.laps(1), cycle.shift(1)),
+            (1, 0, Some(0))
+        );
+        assert_eq!(
+            (cycle.reduce(5), cycle.laps(5), cycle.shift(5)),
+            (5, 0, Some(0))
+        );
+        assert_eq!(
+            (cycle.reduce(6), cycle.laps(6), cycle.shift(6)),
+            (2, 1, Some(3))
+        );
+        assert_eq!(
+            (cycle.reduce(1_000_000_001), cycle.laps(1_000_000_001)),
+            (5, 249_999_999)
+        );
+    }
+
+    #[test]
+    fn test_shift() {
+        let cycle = Cycle {
+            start: 0,
+            length: 1,
+            offset: 2,
+        };
+
+        assert_eq!(cycle.laps(50_000_000_000), 50_000_000_000);
+        assert_eq!(cycle.shift(50_000_000_000), Some(100_000_000_000));
+        assert_eq!(cycle.shift(u64::MAX), None);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::Cycle;
+
+/// The cycle in the sequence starting at `start` where each state is followed
+/// by `next` of it, using Floyd's tortoise and hare algorithm.
+///
+/// Never returns if the sequence does not repeat.
+pub fn floyd<T, F>(start: T, mut next: F) -> Cycle
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    // Find a step inside the cycle, where the hare has gone around it some
+    // whole number of times more than the tortoise.
+    let mut tortoise = next(&start);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        hare = next(&hare);
+    }
+
+    // The hare is now as far from the start of the cycle as the start of the
+    // sequence is, so they meet at the start of the cycle.
+    let mut cycle_start = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        cycle_start += 1;
+    }
+
+    let mut length = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        length,
+        offset: (),
+    }
+}
+
+/// The cycle in the sequence starting at `start` where each state is followed
+/// by `next` of it, using Brent's algorithm.
+///
+/// This usually calls `next` fewer times than `floyd`. Never returns if the
+/// sequence does not repeat.
+pub fn brent<T, F>(start: T, mut next: F) -> Cycle
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    // The tortoise teleports to the hare every power of two steps, so the hare
+    // catches it after going once around the cycle.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = next(&hare);
+        length += 1;
+    }
+
+    // Start the hare one cycle ahead, so they meet at the start of the cycle.
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        cycle_start += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        length,
+        offset: (),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 2, 3, 4, 5, ...
+    fn next(&n: &u32) -> u32 {
+        if n < 5 {
+            n + 1
+        } else {
+            n - 3
+        }
+    }
+
+    #[test]
+    fn test_floyd() {
+        let expected = Cycle {
+            start: 2,
+            length: 4,
+            offset: (),
+        };
+
+        assert_eq!(floyd(0, next), expected);
+        assert_eq!(floyd(4, next).start, 0);
+        assert_eq!(floyd(7, |n| n * 3 % 10).length, 4);
+        assert_eq!(floyd(5, |n| *n).length, 1);
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(0, next), floyd(0, next));
+        assert_eq!(brent(4, next), floyd(4, next));
+
+        for start in 0..100 {
+            let next = |n: &u64| (n * n + 1) % 255;
+            assert_eq!(brent(start, next), floyd(start, next));
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+
+use crate::graph::Digraph;
+
+impl<V, W> Digraph<V, W>
+where
+    V: Clone + Display + Eq + Hash + Ord,
+{
+    /// The graph in Graphviz's DOT language, without edge labels.
+    pub fn to_dot(&self) -> String {
+        self.dot_with(|_| None)
+    }
+
+    /// Write the graph in the DOT language, labelling each edge with `label`
+    /// of its weight.
+    fn dot_with<F>(&self, label: F) -> String
+    where
+        F: Fn(&W) -> Option<String>,
+    {
+        let mut vertices: Vec<&V> = self.vertices().collect();
+        vertices.sort();
+
+        let mut edges: Vec<(&V, &V, &W)> = self.edges().collect();
+        edges.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        let mut dot = String::from("digraph {\n");
+
+        for v in vertices {
+            writeln!(dot, "    {};", quote(v)).unwrap();
+        }
+
+        for (from, to, weight) in edges {
+            write!(dot, "    {} -> {}", quote(from), quote(to)).unwrap();
+            if let Some(label) = label(weight) {
+                write!(dot, " [label={}]", quote(label)).unwrap();
+            }
+            dot.push_str(";\n");
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<V, W> Digraph<V, W>
+where
+    V: Clone + Display + Eq + Hash + Ord,
+    W: Display,
+{
+    /// The graph in Graphviz's DOT language, with each edge labelled with its
+    /// weight.
+    pub fn to_weighted_dot(&self) -> String {
+        self.dot_with(|weight| Some(weight.to_string()))
+    }
+}
+
+/// `s` as a quoted DOT identifier.
+fn quote<T: Display>(s: T) -> String {
+    format!(
+        "\"{}\"",
+        s.to_string().replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dot() {
+        let graph: Digraph<&str, usize> =
+            vec![("b", "a", 2), ("a", "\"c\"", 1)].into_iter().collect();
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    \"\\\"c\\\"\";\n    \"a\";\n    \"b\";\n    \"a\" -> \"\\\"c\\\"\";\n    \"b\" -> \"a\";\n}\n"
+        );
+        assert_eq!(
+            graph.to_weighted_dot(),
+            "digraph {\n    \"\\\"c\\\"\";\n    \"a\";\n    \"b\";\n    \"a\" -> \"\\\"c\\\"\" [label=\"1\"];\n    \"b\" -> \"a\" [label=\"2\"];\n}\n"
+        );
+    }
+}
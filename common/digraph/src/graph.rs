@@ -0,0 +1,230 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/// A directed graph with a weight of type `W` on each edge.
+///
+/// There is at most one edge from one vertex to another.
+#[derive(Clone, Debug)]
+pub struct Digraph<V, W = ()> {
+    successors: HashMap<V, HashMap<V, W>>,
+    predecessors: HashMap<V, HashSet<V>>,
+}
+
+impl<V, W> Digraph<V, W>
+where
+    V: Clone + Eq + Hash,
+{
+    pub fn new() -> Self {
+        Digraph {
+            successors: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Add a vertex without any edges, if it is not already in the graph.
+    pub fn add_vertex(&mut self, v: V) {
+        self.predecessors.entry(v.clone()).or_default();
+        self.successors.entry(v).or_default();
+    }
+
+    /// Add an edge, replacing the weight of any edge already between the same
+    /// vertices.
+    pub fn add_edge(&mut self, from: V, to: V, weight: W) {
+        self.add_vertex(from.clone());
+        self.add_vertex(to.clone());
+
+        self.predecessors.get_mut(&to).unwrap().insert(from.clone());
+        self.successors.get_mut(&from).unwrap().insert(to, weight);
+    }
+
+    /// The number of vertices.
+    pub fn len(&self) -> usize {
+        self.successors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.successors.is_empty()
+    }
+
+    pub fn contains(&self, v: &V) -> bool {
+        self.successors.contains_key(v)
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = &V> {
+        self.successors.keys()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&V, &V, &W)> {
+        self.successors
+            .iter()
+            .flat_map(|(from, to)| to.iter().map(move |(to, weight)| (from, to, weight)))
+    }
+
+    /// The weight of the edge from `from` to `to`, if there is one.
+    pub fn weight(&self, from: &V, to: &V) -> Option<&W> {
+        self.successors.get(from)?.get(to)
+    }
+
+    /// The vertices that `v` has an edge to, along with the weight of that
+    /// edge.
+    pub fn successors(&self, v: &V) -> impl Iterator<Item = (&V, &W)> {
+        self.successors.get(v).into_iter().flatten()
+    }
+
+    /// The vertices that have an edge to `v`.
+    pub fn predecessors(&self, v: &V) -> impl Iterator<Item = &V> {
+        self.predecessors.get(v).into_iter().flatten()
+    }
+
+    pub fn in_degree(&self, v: &V) -> usize {
+        self.predecessors.get(v).map_or(0, HashSet::len)
+    }
+
+    pub fn out_degree(&self, v: &V) -> usize {
+        self.successors.get(v).map_or(0, HashMap::len)
+    }
+
+    /// The vertices without any incoming edges.
+    pub fn roots(&self) -> impl Iterator<Item = &V> {
+        self.vertices().filter(move |v| self.in_degree(v) == 0)
+    }
+
+    /// The vertices without any outgoing edges.
+    pub fn leaves(&self) -> impl Iterator<Item = &V> {
+        self.vertices().filter(move |v| self.out_degree(v) == 0)
+    }
+
+    /// The vertices that can be reached from `v` by following at least one
+    /// edge.
+    pub fn descendants(&self, v: &V) -> HashSet<V> {
+        reachable(v, |v| self.successors(v).map(|(to, _)| to))
+    }
+
+    /// The vertices that can reach `v` by following at least one edge.
+    pub fn ancestors(&self, v: &V) -> HashSet<V> {
+        reachable(v, |v| self.predecessors(v))
+    }
+
+    /// The fewest edges from `from` to each vertex it can reach, including
+    /// itself.
+    pub fn distances(&self, from: &V) -> HashMap<V, usize> {
+        let mut distances = HashMap::new();
+        let mut open = VecDeque::new();
+
+        distances.insert(from.clone(), 0);
+        open.push_back((from, 0));
+
+        while let Some((v, distance)) = open.pop_front() {
+            for (to, _) in self.successors(v) {
+                if !distances.contains_key(to) {
+                    distances.insert(to.clone(), distance + 1);
+                    open.push_back((to, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+impl<V, W> Default for Digraph<V, W>
+where
+    V: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Digraph::new()
+    }
+}
+
+impl<V, W> FromIterator<(V, V, W)> for Digraph<V, W>
+where
+    V: Clone + Eq + Hash,
+{
+    fn from_iter<I>(edges: I) -> Self
+    where
+        I: IntoIterator<Item = (V, V, W)>,
+    {
+        let mut graph = Digraph::new();
+        for (from, to, weight) in edges {
+            graph.add_edge(from, to, weight);
+        }
+
+        graph
+    }
+}
+
+impl<V> FromIterator<(V, V)> for Digraph<V>
+where
+    V: Clone + Eq + Hash,
+{
+    fn from_iter<I>(edges: I) -> Self
+    where
+        I: IntoIterator<Item = (V, V)>,
+    {
+        edges.into_iter().map(|(from, to)| (from, to, ())).collect()
+    }
+}
+
+/// Every vertex reachable from `start` in at least one step.
+fn reachable<'a, V, F, I>(start: &'a V, mut next: F) -> HashSet<V>
+where
+    V: Clone + Eq + Hash,
+    F: FnMut(&'a V) -> I,
+    I: Iterator<Item = &'a V>,
+{
+    let mut seen = HashSet::new();
+    let mut open = vec![start];
+
+    while let Some(v) = open.pop() {
+        for w in next(v) {
+            if seen.insert(w.clone()) {
+                open.push(w);
+            }
+        }
+    }
+
+    seen
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> Digraph<char> {
+        "CA CF AB AD BE DE FE"
+            .split(' ')
+            .map(|e| (e.as_bytes()[0] as char, e.as_bytes()[1] as char))
+            .collect()
+    }
+
+    #[test]
+    fn test_edges() {
+        let mut graph = sample();
+        graph.add_vertex('G');
+
+        assert_eq!(graph.len(), 7);
+        assert_eq!(graph.edges().count(), 7);
+        assert_eq!(graph.roots().collect::<HashSet<_>>(), [&'C', &'G'].into());
+        assert_eq!(graph.leaves().collect::<HashSet<_>>(), [&'E', &'G'].into());
+        assert_eq!(graph.in_degree(&'E'), 3);
+        assert_eq!(graph.out_degree(&'A'), 2);
+        assert_eq!(graph.weight(&'A', &'B'), Some(&()));
+        assert_eq!(graph.weight(&'B', &'A'), None);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let graph = sample();
+
+        assert_eq!(graph.descendants(&'A'), ['B', 'D', 'E'].into());
+        assert_eq!(graph.ancestors(&'E'), ['A', 'B', 'C', 'D', 'F'].into());
+        assert_eq!(graph.ancestors(&'C'), HashSet::new());
+
+        let distances = graph.distances(&'C');
+        assert_eq!(distances[&'C'], 0);
+        assert_eq!(distances[&'B'], 2);
+        assert_eq!(distances[&'E'], 2);
+        assert!(!distances.contains_key(&'G'));
+    }
+}
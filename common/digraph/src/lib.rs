@@ -0,0 +1,13 @@
+//! Directed graphs with weighted edges.
+//!
+//! Vertices can be any `Clone`, `Eq` and `Hash` type. Anything that visits
+//! vertices in a particular order, like topological sorting, also needs them
+//! to be `Ord` so that ties are broken the same way every time.
+
+mod dot;
+mod graph;
+mod paths;
+mod sort;
+
+pub use crate::graph::Digraph;
+pub use crate::sort::Schedule;
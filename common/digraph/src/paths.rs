@@ -0,0 +1,95 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::{Add, Mul};
+
+use crate::graph::Digraph;
+
+impl<V, W> Digraph<V, W>
+where
+    V: Clone + Eq + Hash,
+    W: Copy + Default + Add<Output = W> + Mul<Output = W>,
+{
+    /// The sum, over every path that starts at `v` and follows at least one
+    /// edge, of the product of the weights along that path.
+    ///
+    /// With a weight of one on every edge, this is the number of such paths.
+    /// Returns `None` if there is a cycle reachable from `v`, since then there
+    /// are infinitely many.
+    pub fn path_sum(&self, v: &V) -> Option<W> {
+        self.path_sum_memo(v, &mut HashMap::new(), &mut HashSet::new())
+    }
+
+    /// The `path_sum` of every vertex, or `None` if there is a cycle.
+    pub fn path_sums(&self) -> Option<HashMap<V, W>> {
+        let mut memo = HashMap::with_capacity(self.len());
+        let mut visiting = HashSet::new();
+        for v in self.vertices() {
+            self.path_sum_memo(v, &mut memo, &mut visiting)?;
+        }
+
+        Some(memo)
+    }
+
+    /// The `path_sum` of `v`, where `visiting` is the vertices on the way to
+    /// `v`.
+    fn path_sum_memo(
+        &self,
+        v: &V,
+        memo: &mut HashMap<V, W>,
+        visiting: &mut HashSet<V>,
+    ) -> Option<W> {
+        if let Some(&sum) = memo.get(v) {
+            return Some(sum);
+        }
+
+        if !visiting.insert(v.clone()) {
+            return None;
+        }
+
+        // Each path from `v` is an edge, optionally followed by a path from
+        // the end of that edge.
+        let mut sum = W::default();
+        for (to, &weight) in self.successors(v) {
+            sum = sum + weight + weight * self.path_sum_memo(to, memo, visiting)?;
+        }
+
+        visiting.remove(v);
+        memo.insert(v.clone(), sum);
+        Some(sum)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_path_sum() {
+        // Each bag and the number of each other bag inside it.
+        let bags: Digraph<&str, usize> = vec![
+            ("shiny gold", "dark olive", 1),
+            ("shiny gold", "vibrant plum", 2),
+            ("dark olive", "faded blue", 3),
+            ("dark olive", "dotted black", 4),
+            ("vibrant plum", "faded blue", 5),
+            ("vibrant plum", "dotted black", 6),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(bags.path_sum(&"shiny gold"), Some(32));
+        assert_eq!(bags.path_sum(&"faded blue"), Some(0));
+
+        let sums = bags.path_sums().unwrap();
+        assert_eq!(sums.len(), 5);
+        assert_eq!(sums["dark olive"], 7);
+        assert_eq!(sums["vibrant plum"], 11);
+
+        let bags: Digraph<&str, usize> = vec![("a", "b", 1), ("b", "c", 2), ("c", "b", 3)]
+            .into_iter()
+            .collect();
+        assert_eq!(bags.path_sum(&"a"), None);
+        assert_eq!(bags.path_sum(&"c"), None);
+        assert_eq!(bags.path_sums(), None);
+    }
+}
@@ -0,0 +1,214 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::graph::Digraph;
+
+/// Hands out the vertices of a graph once every vertex with an edge to them
+/// has been completed.
+///
+/// Vertices that are ready at the same time are handed out smallest first.
+/// More than one vertex can be in progress at once.
+#[derive(Clone, Debug)]
+pub struct Schedule<'a, V, W> {
+    graph: &'a Digraph<V, W>,
+    waiting: HashMap<&'a V, usize>,
+    ready: BinaryHeap<Reverse<&'a V>>,
+    in_progress: HashSet<&'a V>,
+}
+
+impl<'a, V, W> Schedule<'a, V, W>
+where
+    V: Clone + Eq + Hash + Ord,
+{
+    pub fn new(graph: &'a Digraph<V, W>) -> Self {
+        let mut waiting = HashMap::new();
+        let mut ready = BinaryHeap::new();
+
+        for v in graph.vertices() {
+            match graph.in_degree(v) {
+                0 => ready.push(Reverse(v)),
+                n => {
+                    waiting.insert(v, n);
+                }
+            }
+        }
+
+        Schedule {
+            graph,
+            waiting,
+            ready,
+            in_progress: HashSet::new(),
+        }
+    }
+
+    /// Whether there are no vertices ready to start.
+    pub fn is_blocked(&self) -> bool {
+        self.ready.is_empty()
+    }
+
+    /// Whether every vertex has been handed out.
+    pub fn is_done(&self) -> bool {
+        self.ready.is_empty() && self.waiting.is_empty()
+    }
+
+    /// Start the smallest vertex that is ready, if any.
+    pub fn start(&mut self) -> Option<V> {
+        let Reverse(v) = self.ready.pop()?;
+        self.in_progress.insert(v);
+
+        Some(v.clone())
+    }
+
+    /// Mark `v` as completed, making any vertices that were only waiting on it
+    /// ready.
+    ///
+    /// Returns `false` and does nothing if `v` was not in progress, i.e., it
+    /// has not been started or has already been completed.
+    pub fn complete(&mut self, v: &V) -> bool {
+        if !self.in_progress.remove(v) {
+            return false;
+        }
+
+        for (to, _) in self.graph.successors(v) {
+            let remaining = self.waiting.get_mut(to).unwrap();
+            *remaining -= 1;
+
+            if *remaining == 0 {
+                self.waiting.remove(to);
+                self.ready.push(Reverse(to));
+            }
+        }
+
+        true
+    }
+}
+
+impl<V, W> Digraph<V, W>
+where
+    V: Clone + Eq + Hash + Ord,
+{
+    /// Every vertex, ordered so that each edge goes from an earlier vertex to a
+    /// later one, or `None` if there is a cycle.
+    ///
+    /// Of all such orders, this is the one that puts the smallest vertex
+    /// available first at each step.
+    pub fn toposort(&self) -> Option<Vec<V>> {
+        let mut schedule = Schedule::new(self);
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(v) = schedule.start() {
+            schedule.complete(&v);
+            order.push(v);
+        }
+
+        if order.len() == self.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// The vertices of some cycle in the graph, in order, or `None` if there
+    /// isn't one.
+    pub fn find_cycle(&self) -> Option<Vec<V>> {
+        let mut starts: Vec<&V> = self.vertices().collect();
+        starts.sort();
+
+        let mut done = HashSet::new();
+        let mut path = Vec::new();
+
+        for start in starts {
+            if let Some(cycle) = self.find_cycle_from(start, &mut path, &mut done) {
+                return Some(cycle);
+            }
+        }
+
+        None
+    }
+
+    /// Depth-first search from `v`, where `path` is the vertices on the way
+    /// to `v` and `done` is every vertex without a cycle after it.
+    fn find_cycle_from<'a>(
+        &'a self,
+        v: &'a V,
+        path: &mut Vec<&'a V>,
+        done: &mut HashSet<&'a V>,
+    ) -> Option<Vec<V>> {
+        if done.contains(v) {
+            return None;
+        }
+
+        if let Some(i) = path.iter().position(|&u| u == v) {
+            return Some(path[i..].iter().map(|&u| u.clone()).collect());
+        }
+
+        path.push(v);
+
+        let mut successors: Vec<&V> = self.successors(v).map(|(to, _)| to).collect();
+        successors.sort();
+
+        for to in successors {
+            if let Some(cycle) = self.find_cycle_from(to, path, done) {
+                return Some(cycle);
+            }
+        }
+
+        path.pop();
+        done.insert(v);
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(edges: &str) -> Digraph<char> {
+        edges
+            .split(' ')
+            .map(|e| (e.as_bytes()[0] as char, e.as_bytes()[1] as char))
+            .collect()
+    }
+
+    #[test]
+    fn test_toposort() {
+        let graph = parse("CA CF AB AD BE DE FE");
+
+        assert_eq!(graph.toposort(), Some("CABDFE".chars().collect()));
+        assert_eq!(graph.find_cycle(), None);
+
+        let graph = parse("AB BC CD DB");
+        assert_eq!(graph.toposort(), None);
+        assert_eq!(graph.find_cycle(), Some(vec!['B', 'C', 'D']));
+        assert_eq!(parse("AA").find_cycle(), Some(vec!['A']));
+    }
+
+    #[test]
+    fn test_schedule() {
+        let graph = parse("CA CF AB AD BE DE FE");
+        let mut schedule = Schedule::new(&graph);
+
+        assert_eq!(schedule.start(), Some('C'));
+        assert!(schedule.is_blocked());
+
+        assert!(!schedule.complete(&'A'));
+        assert!(schedule.complete(&'C'));
+        assert!(!schedule.complete(&'C'));
+        assert_eq!(schedule.start(), Some('A'));
+        assert_eq!(schedule.start(), Some('F'));
+
+        schedule.complete(&'F');
+        schedule.complete(&'A');
+        assert_eq!(schedule.start(), Some('B'));
+        assert_eq!(schedule.start(), Some('D'));
+        schedule.complete(&'B');
+        assert!(schedule.is_blocked());
+        assert!(!schedule.is_done());
+
+        schedule.complete(&'D');
+        assert_eq!(schedule.start(), Some('E'));
+        assert!(schedule.is_done());
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use combine::easy::{Error, Errors, Info};
+use combine::stream::PointerOffset;
+
+use crate::ParseError;
+
+impl ParseError {
+    /// Convert an error from parsing a slice of `input` with `combine`.
+    pub fn from_combine<T, R>(input: &str, err: Errors<T, R, PointerOffset<str>>) -> Self
+    where
+        T: Display,
+        R: Display,
+    {
+        let offset = err.position.translate_position(input);
+
+        let mut unexpected = None;
+        let mut expected = Vec::new();
+        let mut messages = Vec::new();
+
+        for e in &err.errors {
+            match e {
+                Error::Unexpected(info) => {
+                    unexpected = Some(format!("unexpected {}", describe(info)))
+                }
+                Error::Expected(info) => expected.push(describe(info)),
+                Error::Message(info) => messages.push(info.to_string()),
+                Error::Other(e) => messages.push(e.to_string()),
+            }
+        }
+
+        let mut parts = Vec::new();
+        parts.extend(unexpected);
+
+        if let Some((last, rest)) = expected.split_last() {
+            if rest.is_empty() {
+                parts.push(format!("expected {}", last));
+            } else {
+                parts.push(format!("expected {} or {}", rest.join(", "), last));
+            }
+        }
+
+        parts.extend(messages);
+
+        if parts.is_empty() {
+            parts.push("parse error".into());
+        }
+
+        ParseError::at(input, offset, parts.join(", "))
+    }
+}
+
+/// Quote and escape tokens, but not descriptions like "end of input" or "integer".
+fn describe<T, R>(info: &Info<T, R>) -> String
+where
+    T: Display,
+    R: Display,
+{
+    match info {
+        Info::Token(t) => format!("`{}'", t.to_string().escape_debug()),
+        Info::Range(r) => format!("`{}'", r.to_string().escape_debug()),
+        Info::Owned(s) => s.clone(),
+        Info::Static(s) => s.to_string(),
+    }
+}
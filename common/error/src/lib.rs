@@ -0,0 +1,52 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::result;
+
+#[cfg(feature = "combine")]
+mod combine;
+mod parse_error;
+
+pub use crate::parse_error::{offset_of, parse_at, Location, ParseError};
+
+pub enum Error {
+    Io(io::Error, String),
+    Parse(ParseError),
+    Other(String),
+}
+
+pub type Result<T> = result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e, s) => write!(f, "{}: {}", s, e),
+            Error::Parse(e) => fmt::Display::fmt(e, f),
+            Error::Other(s) => f.write_str(s),
+        }
+    }
+}
+
+// Errors returned from `main` are printed with `Debug`, so we want them to look
+// the same as when they are printed with `Display`.
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e, _) => Some(e),
+            Error::Parse(e) => Some(e),
+            Error::Other(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
@@ -0,0 +1,195 @@
+use std::error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A 1-based line and column in an input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Compute the location of the byte at `offset` in `input`.
+    ///
+    /// Offsets past the end of `input` or inside a character are moved back to
+    /// the nearest character.
+    pub fn of(input: &str, offset: usize) -> Self {
+        let before = &input[..clamp_to_char_boundary(input, offset)];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct ParseError {
+    message: String,
+    location: Option<Location>,
+    text: String,
+}
+
+impl ParseError {
+    /// An error that does not correspond to any particular part of the input.
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        ParseError {
+            message: message.into(),
+            location: None,
+            text: String::new(),
+        }
+    }
+
+    /// An error at the byte `offset` in `input`.
+    pub fn at<S: Into<String>>(input: &str, offset: usize, message: S) -> Self {
+        let offset = clamp_to_char_boundary(input, offset);
+
+        let line_start = input[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[offset..]
+            .find('\n')
+            .map(|i| offset + i)
+            .unwrap_or_else(|| input.len());
+
+        ParseError {
+            message: message.into(),
+            location: Some(Location::of(input, offset)),
+            text: input[line_start..line_end].trim_end_matches('\r').into(),
+        }
+    }
+
+    /// An error at the start of `span`, which must be a slice of `input`.
+    ///
+    /// If `span` is not a slice of `input`, the error will have no location.
+    pub fn at_span<S: Into<String>>(input: &str, span: &str, message: S) -> Self {
+        match offset_of(input, span) {
+            Some(offset) => ParseError::at(input, offset, message),
+            None => ParseError::new(message),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let location = match self.location {
+            Some(location) => location,
+            None => return f.write_str(&self.message),
+        };
+
+        let line_no = location.line.to_string();
+        let gutter = " ".repeat(line_no.len());
+
+        // Keep tabs so that the caret lines up with the snippet.
+        let indent = self
+            .text
+            .chars()
+            .take(location.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        writeln!(f, "{} at {}", self.message, location)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_no, self.text)?;
+        write!(f, "{} | {}^", gutter, indent)
+    }
+}
+
+// Like `Error`, a `ParseError` returned from `main` should be readable.
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// The byte offset of `span` in `input`, if `span` is a slice of `input`.
+pub fn offset_of(input: &str, span: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let span_start = span.as_ptr() as usize;
+
+    if span_start >= start && span_start + span.len() <= start + input.len() {
+        Some(span_start - start)
+    } else {
+        None
+    }
+}
+
+/// Parse `span`, which must be a slice of `input`, reporting any failure at its location.
+pub fn parse_at<T>(input: &str, span: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    span.parse()
+        .map_err(|e| ParseError::at_span(input, span, format!("could not parse `{}': {}", span, e)))
+}
+
+fn clamp_to_char_boundary(input: &str, offset: usize) -> usize {
+    let mut offset = offset.min(input.len());
+
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let input = "abc\ndef\n\nghi";
+
+        assert_eq!(Location::of(input, 0), Location { line: 1, column: 1 });
+        assert_eq!(Location::of(input, 2), Location { line: 1, column: 3 });
+        assert_eq!(Location::of(input, 3), Location { line: 1, column: 4 });
+        assert_eq!(Location::of(input, 4), Location { line: 2, column: 1 });
+        assert_eq!(Location::of(input, 8), Location { line: 3, column: 1 });
+        assert_eq!(Location::of(input, 11), Location { line: 4, column: 3 });
+        assert_eq!(Location::of(input, 99), Location { line: 4, column: 4 });
+        assert_eq!(Location::of("aé", 2), Location { line: 1, column: 2 });
+    }
+
+    #[test]
+    fn test_display() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1; 4x4\n";
+        let line = input.lines().nth(1).unwrap();
+
+        let e = ParseError::at_span(input, &line[8..], "expected `:'");
+        assert_eq!(e.location(), Some(Location { line: 2, column: 9 }));
+        assert_eq!(
+            e.to_string(),
+            "expected `:' at line 2, column 9\n  |\n2 | #2 @ 3,1; 4x4\n  |         ^"
+        );
+
+        let e = parse_at::<u32>(input, &line[5..6]).unwrap();
+        assert_eq!(e, 3);
+
+        let e = parse_at::<u32>(input, &line[..2]).unwrap_err();
+        assert_eq!(e.location(), Some(Location { line: 2, column: 1 }));
+        assert_eq!(
+            e.message(),
+            "could not parse `#2': invalid digit found in string"
+        );
+
+        assert_eq!(ParseError::at_span(input, "x", "oops").to_string(), "oops");
+    }
+}
@@ -0,0 +1,69 @@
+//! Random, well-formed puzzle inputs of any size.
+//!
+//! Generators produce input in exactly the format of the real puzzle, so the
+//! output can be fed straight to a day's `part1` and `part2`. Most take the
+//! number of lines (or entries) to generate, and those are the ones that
+//! `generate` knows about.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+pub mod y2018;
+pub mod y2019;
+pub mod y2020;
+
+pub use rand::Rng;
+
+/// A deterministic generator seeded with `seed`.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Generate an input of `size` entries for the given day.
+///
+/// Returns `None` if there is no generator for that day.
+pub fn generate<R: Rng>(rng: &mut R, year: u16, day: u8, size: usize) -> Option<String> {
+    let input = match (year, day) {
+        (2018, 3) => y2018::claims(rng, size),
+        (2018, 6) => y2018::coordinates(rng, size),
+        (2019, 22) => y2019::shuffle(rng, size, y2019::DECK_SIZE),
+        (2020, 2) => y2020::passwords(rng, size),
+        (2020, 5) => y2020::boarding_passes(rng, size),
+        (2020, 14) => y2020::bitmask_program(rng, size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+/// The days that `generate` has a generator for.
+pub const DAYS: &[(u16, u8)] = &[
+    (2018, 3),
+    (2018, 6),
+    (2019, 22),
+    (2020, 2),
+    (2020, 5),
+    (2020, 14),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        for &(year, day) in DAYS {
+            assert_eq!(
+                generate(&mut rng(1), year, day, 100),
+                generate(&mut rng(1), year, day, 100)
+            );
+            assert_eq!(
+                generate(&mut rng(1), year, day, 100)
+                    .unwrap()
+                    .lines()
+                    .count(),
+                100
+            );
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Fabric claims of the form `#1 @ 1,3: 4x4`.
+///
+/// Like the real input, exactly one claim overlaps no other claim.
+pub fn claims<R: Rng>(rng: &mut R, count: usize) -> String {
+    let side = ((count * 500) as f64).sqrt().ceil() as usize + 30;
+    let mut claims: Vec<(usize, usize, usize, usize)> = Vec::with_capacity(count);
+
+    // Every claim but the first overlaps some earlier claim, so no claim is
+    // left on its own.
+    for _ in 1..count {
+        let width = rng.gen_range(1..=29);
+        let height = rng.gen_range(1..=29);
+
+        let (left, top) = if let Some(&(l, t, w, h)) = claims.choose(rng) {
+            (
+                rng.gen_range((l + 1).saturating_sub(width)..l + w),
+                rng.gen_range((t + 1).saturating_sub(height)..t + h),
+            )
+        } else {
+            (
+                rng.gen_range(0..=side - width),
+                rng.gen_range(0..=side - height),
+            )
+        };
+
+        claims.push((left, top, width, height));
+    }
+
+    if count > 0 {
+        let overlaps = |a: &(usize, usize, usize, usize)| {
+            claims
+                .iter()
+                .any(|b| a.0 < b.0 + b.2 && a.0 + a.2 > b.0 && a.1 < b.1 + b.3 && a.1 + a.3 > b.1)
+        };
+
+        let width = rng.gen_range(1..=29);
+        let height = rng.gen_range(1..=29);
+
+        let alone = (0..100)
+            .map(|_| {
+                (
+                    rng.gen_range(0..=side - width),
+                    rng.gen_range(0..=side - height),
+                    width,
+                    height,
+                )
+            })
+            .find(|claim| !overlaps(claim))
+            .unwrap_or_else(|| {
+                let right = claims.iter().map(|c| c.0 + c.2).max().unwrap_or(0);
+                (right, 0, width, height)
+            });
+
+        claims.insert(rng.gen_range(0..=claims.len()), alone);
+    }
+
+    let mut input = String::new();
+    for (id, (left, top, width, height)) in (1..).zip(claims) {
+        writeln!(input, "#{} @ {},{}: {}x{}", id, left, top, width, height).unwrap();
+    }
+
+    input
+}
+
+/// Distinct coordinates of the form `1, 6`.
+///
+/// The coordinates are spread out as much as in the real input, which for
+/// about 50 coordinates leaves a non-empty region within 10000 of all of them.
+pub fn coordinates<R: Rng>(rng: &mut R, count: usize) -> String {
+    let side = (300.0 * (count as f64 / 50.0).sqrt()).ceil() as i32 + 10;
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+
+    while seen.len() < count {
+        let point = (rng.gen_range(0..side), rng.gen_range(0..side));
+
+        if seen.insert(point) {
+            writeln!(input, "{}, {}", point.0, point.1).unwrap();
+        }
+    }
+
+    input
+}
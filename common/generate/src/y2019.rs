@@ -0,0 +1,36 @@
+use std::fmt::Write;
+
+use aoc_math::gcd;
+use rand::Rng;
+
+/// The number of cards in the deck for part 1 of 2019 day 22.
+pub const DECK_SIZE: u64 = 10007;
+
+/// Shuffle techniques for a deck of `deck_size` cards.
+///
+/// Every increment is co-prime with `deck_size`, so the shuffle is always a
+/// permutation.
+pub fn shuffle<R: Rng>(rng: &mut R, count: usize, deck_size: u64) -> String {
+    let mut input = String::new();
+
+    for _ in 0..count {
+        match rng.gen_range(0..3) {
+            0 => input.push_str("deal into new stack\n"),
+            1 => {
+                let n = rng.gen_range(-(deck_size as i64 - 1)..deck_size as i64);
+                writeln!(input, "cut {}", n).unwrap();
+            }
+            _ => {
+                let n = loop {
+                    let n = rng.gen_range(1..deck_size.min(100));
+                    if gcd(n, deck_size) == 1 {
+                        break n;
+                    }
+                };
+                writeln!(input, "deal with increment {}", n).unwrap();
+            }
+        }
+    }
+
+    input
+}
@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Password policies and passwords of the form `1-3 a: abcde`.
+///
+/// Both positions in a policy are always within the password.
+pub fn passwords<R: Rng>(rng: &mut R, count: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..count {
+        let letter = rng.gen_range(b'a'..=b'z') as char;
+        let len = rng.gen_range(2..=20);
+        let i = rng.gen_range(1..len);
+        let j = rng.gen_range(i + 1..=len);
+
+        let password = (0..len)
+            .map(|_| {
+                if rng.gen_bool(0.4) {
+                    letter
+                } else {
+                    rng.gen_range(b'a'..=b'z') as char
+                }
+            })
+            .collect::<String>();
+
+        writeln!(input, "{}-{} {}: {}", i, j, letter, password).unwrap();
+    }
+
+    input
+}
+
+/// Boarding passes of the form `FBFBBFFRLR` for a run of consecutive seats
+/// with exactly one seat missing from the middle.
+///
+/// There are only 1024 seats, so `count` is clamped to at most 1022.
+pub fn boarding_passes<R: Rng>(rng: &mut R, count: usize) -> String {
+    let count = count.clamp(2, 1022) as u32;
+    let first = rng.gen_range(0..1024 - count);
+    let missing = rng.gen_range(first + 1..first + count);
+
+    let mut ids = (first..=first + count)
+        .filter(|id| *id != missing)
+        .collect::<Vec<_>>();
+    ids.shuffle(rng);
+
+    let mut input = String::new();
+    for id in ids {
+        for bit in (0..10).rev() {
+            let set = id & (1 << bit) != 0;
+            input.push(match (bit >= 3, set) {
+                (true, false) => 'F',
+                (true, true) => 'B',
+                (false, false) => 'L',
+                (false, true) => 'R',
+            });
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+/// An initialization program of `mask = ...` and `mem[8] = 11` lines.
+///
+/// Like the real input, masks have at most nine floating bits so that every
+/// address mask covers at most 512 addresses.
+pub fn bitmask_program<R: Rng>(rng: &mut R, count: usize) -> String {
+    let mut input = String::new();
+    let mut updates = 0;
+
+    for line in 0..count {
+        if line == 0 || updates == 0 {
+            let n_floating = rng.gen_range(0..=9);
+            let floating = (0..36)
+                .collect::<Vec<_>>()
+                .choose_multiple(rng, n_floating)
+                .copied()
+                .collect::<HashSet<_>>();
+
+            let mask = (0..36)
+                .map(|i| match (floating.contains(&i), rng.gen_bool(0.5)) {
+                    (true, _) => 'X',
+                    (false, true) => '1',
+                    (false, false) => '0',
+                })
+                .collect::<String>();
+
+            writeln!(input, "mask = {}", mask).unwrap();
+            updates = rng.gen_range(1..=6);
+        } else {
+            let addr = rng.gen_range(0..65536);
+            let value = rng.gen_range(0..1 << 30);
+
+            writeln!(input, "mem[{}] = {}", addr, value).unwrap();
+            updates -= 1;
+        }
+    }
+
+    input
+}
+
+/// The sea monster that hides in jigsaw images, one row per line.
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// A `side` by `side` jigsaw of `tile_size` by `tile_size` tiles, with
+/// `monsters` sea monsters hidden in the assembled image.
+///
+/// Tile IDs are 1000 plus the tile's index in reading order before the tiles
+/// are turned and shuffled, so the corners are 1000, `999 + side`,
+/// `1000 + side * (side - 1)` and `999 + side * side`. No two edges can line
+/// up except the ones that should.
+///
+/// Panics if the sea monsters do not fit in the image.
+pub fn jigsaw<R: Rng>(rng: &mut R, side: usize, tile_size: usize, monsters: usize) -> String {
+    assert!(tile_size >= 3 && side >= 1);
+
+    // Neighbouring tiles share the pixels on their common edge, so every tile
+    // is a window onto one large grid.
+    let step = tile_size - 1;
+    let size = side * step + 1;
+    let mut pixels = vec![vec![false; size]; size];
+
+    // Corners are as likely to be set as not, to spread the edges out over
+    // as many signatures as possible.
+    for (y, row) in pixels.iter_mut().enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            let corner = y % step == 0 && x % step == 0;
+            *pixel = rng.gen_bool(if corner { 0.5 } else { 0.25 });
+        }
+    }
+
+    // Draw each edge until it is different from every other edge, even when
+    // reversed. The corners are shared by several edges, so they stay put.
+    let mut signatures = HashSet::new();
+    for i in 0..=side {
+        for j in 0..side {
+            let horizontal = (0..tile_size).map(|k| (i * step, j * step + k));
+            let vertical = (0..tile_size).map(|k| (j * step + k, i * step));
+
+            for edge in [horizontal.collect::<Vec<_>>(), vertical.collect()] {
+                let unique = (0..1000).any(|_| {
+                    for &(y, x) in &edge[1..tile_size - 1] {
+                        pixels[y][x] = rng.gen_bool(0.5);
+                    }
+
+                    let bits = edge.iter().map(|&(y, x)| pixels[y][x]).collect::<Vec<_>>();
+                    let reversed = bits.iter().rev().copied().collect::<Vec<_>>();
+
+                    bits != reversed && !signatures.contains(&reversed) && signatures.insert(bits)
+                });
+
+                assert!(unique, "the tiles are too small to have unique edges");
+            }
+        }
+    }
+
+    // Positions in the image skip the edges of each tile.
+    let grid_size = tile_size - 2;
+    let image_size = side * grid_size;
+    let to_grid = |i: usize| i / grid_size * step + 1 + i % grid_size;
+
+    let (height, width) = (SEA_MONSTER.len(), SEA_MONSTER[0].len());
+    let mut placed: Vec<(usize, usize)> = vec![];
+
+    for _ in 0..monsters {
+        let (y, x) = (0..10_000)
+            .map(|_| {
+                (
+                    rng.gen_range(0..=image_size.saturating_sub(height)),
+                    rng.gen_range(0..=image_size.saturating_sub(width)),
+                )
+            })
+            .find(|&(y, x)| {
+                y + height <= image_size
+                    && x + width <= image_size
+                    && placed
+                        .iter()
+                        .all(|&(v, u)| y.abs_diff(v) >= height || x.abs_diff(u) >= width)
+            })
+            .expect("the sea monsters do not fit in the image");
+
+        for (dy, line) in SEA_MONSTER.iter().enumerate() {
+            for (dx, c) in line.chars().enumerate() {
+                if c == '#' {
+                    pixels[to_grid(y + dy)][to_grid(x + dx)] = true;
+                }
+            }
+        }
+
+        placed.push((y, x));
+    }
+
+    let mut tiles = vec![];
+    for i in 0..side {
+        for j in 0..side {
+            let mut tile = (0..tile_size)
+                .map(|y| pixels[i * step + y][j * step..j * step + tile_size].to_vec())
+                .collect::<Vec<_>>();
+
+            for _ in 0..rng.gen_range(0..4) {
+                tile = (0..tile_size)
+                    .map(|y| (0..tile_size).map(|x| tile[tile_size - x - 1][y]).collect())
+                    .collect();
+            }
+
+            if rng.gen_bool(0.5) {
+                tile.iter_mut().for_each(|row| row.reverse());
+            }
+
+            tiles.push((1000 + i * side + j, tile));
+        }
+    }
+    tiles.shuffle(rng);
+
+    let mut input = String::new();
+    for (id, tile) in tiles {
+        writeln!(input, "Tile {}:", id).unwrap();
+        for row in tile {
+            let row = row
+                .into_iter()
+                .map(|pixel| if pixel { '#' } else { '.' })
+                .collect::<String>();
+            writeln!(input, "{}", row).unwrap();
+        }
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,137 @@
+use std::cmp::{max, min};
+
+use crate::vector::Vector;
+
+/// The smallest box containing a set of points.
+///
+/// Both corners are included in the box.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BoundingBox<const N: usize> {
+    pub min: Vector<N>,
+    pub max: Vector<N>,
+}
+
+impl<const N: usize> BoundingBox<N> {
+    /// A box containing only `p`.
+    pub fn new(p: Vector<N>) -> Self {
+        BoundingBox { min: p, max: p }
+    }
+
+    /// The box around `points`, or `None` if there are no points.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Vector<N>>,
+    {
+        let mut points = points.into_iter();
+        let mut bounds = BoundingBox::new(points.next()?);
+
+        for p in points {
+            bounds.extend(p);
+        }
+
+        Some(bounds)
+    }
+
+    /// Grow the box to include `p`.
+    pub fn extend(&mut self, p: Vector<N>) {
+        self.min = self.min.zip_with(p, min);
+        self.max = self.max.zip_with(p, max);
+    }
+
+    /// The box grown by `n` in every direction.
+    pub fn expand(self, n: isize) -> Self {
+        BoundingBox {
+            min: self.min.map(|c| c - n),
+            max: self.max.map(|c| c + n),
+        }
+    }
+
+    pub fn contains(&self, p: Vector<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= p[i] && p[i] <= self.max[i])
+    }
+
+    /// The number of points along each axis.
+    pub fn size(&self) -> Vector<N> {
+        (self.max - self.min).map(|c| c + 1)
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> usize {
+        self.size().0.iter().map(|&c| c as usize).product()
+    }
+
+    /// Every point in the box, in the order given by `Vector`'s `Ord`.
+    pub fn points(&self) -> impl Iterator<Item = Vector<N>> {
+        let BoundingBox { min, max } = *self;
+        let mut next = if (0..N).all(|i| min[i] <= max[i]) {
+            Some(min)
+        } else {
+            None
+        };
+
+        std::iter::from_fn(move || {
+            let current = next?;
+
+            let mut p = current;
+            next = (0..N).find(|&i| p[i] < max[i]).map(|i| {
+                p[i] += 1;
+                for j in 0..i {
+                    p[j] = min[j];
+                }
+                p
+            });
+
+            Some(current)
+        })
+    }
+}
+
+impl BoundingBox<2> {
+    pub fn width(&self) -> usize {
+        (self.max.x() - self.min.x() + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y() - self.min.y() + 1) as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vector::{Point, Point3};
+
+    #[test]
+    fn test_bounds() {
+        let bounds =
+            BoundingBox::from_points(vec![Point::new(2, -1), Point::new(-3, 4), Point::new(0, 0)])
+                .unwrap();
+
+        assert_eq!(bounds.min, Point::new(-3, -1));
+        assert_eq!(bounds.max, Point::new(2, 4));
+        assert_eq!((bounds.width(), bounds.height()), (6, 6));
+        assert_eq!(bounds.volume(), 36);
+        assert!(bounds.contains(Point::new(-3, 4)));
+        assert!(!bounds.contains(Point::new(-3, 5)));
+        assert_eq!(BoundingBox::<2>::from_points(vec![]), None);
+    }
+
+    #[test]
+    fn test_points() {
+        let bounds = BoundingBox::from_points(vec![Point::new(0, 0), Point::new(1, 1)]).unwrap();
+
+        assert_eq!(
+            bounds.points().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1)
+            ]
+        );
+
+        let bounds = BoundingBox::new(Point3::new(0, 0, 0)).expand(1);
+        assert_eq!(bounds.points().count(), 27);
+        assert!(bounds.points().all(|p| p.chebyshev() <= 1));
+    }
+}
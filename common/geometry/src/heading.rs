@@ -0,0 +1,80 @@
+use crate::vector::{Point, DOWN, LEFT, RIGHT, UP};
+
+/// A compass heading, with north pointing up the grid.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    /// Every heading, clockwise from north.
+    pub const ALL: [Heading; 4] = [Heading::North, Heading::East, Heading::South, Heading::West];
+
+    /// The heading for `N`, `E`, `S` or `W`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(Heading::North),
+            'E' => Some(Heading::East),
+            'S' => Some(Heading::South),
+            'W' => Some(Heading::West),
+            _ => None,
+        }
+    }
+
+    /// The heading of a unit vector.
+    pub fn from_vector(v: Point) -> Option<Self> {
+        Heading::ALL.iter().copied().find(|h| h.vector() == v)
+    }
+
+    /// The unit vector pointing in this direction.
+    pub fn vector(self) -> Point {
+        match self {
+            Heading::North => UP,
+            Heading::East => RIGHT,
+            Heading::South => DOWN,
+            Heading::West => LEFT,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn(-90)
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn(90)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn(180)
+    }
+
+    /// Turn clockwise by `degrees`, which must be a multiple of 90.
+    pub fn turn(self, degrees: isize) -> Self {
+        assert_eq!(degrees % 90, 0, "cannot turn by {} degrees", degrees);
+
+        let index = self as isize + degrees / 90;
+        Heading::ALL[index.rem_euclid(4) as usize]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_turn() {
+        assert_eq!(Heading::North.turn_left(), Heading::West);
+        assert_eq!(Heading::West.turn_right(), Heading::North);
+        assert_eq!(Heading::East.reverse(), Heading::West);
+        assert_eq!(Heading::South.turn(270), Heading::East);
+        assert_eq!(Heading::South.turn(-450), Heading::East);
+
+        for h in &Heading::ALL {
+            assert_eq!(h.turn_right().vector(), h.vector().rotate_right());
+            assert_eq!(Heading::from_vector(h.vector()), Some(*h));
+        }
+    }
+}
@@ -0,0 +1,483 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use aoc_error::ParseError;
+
+use crate::bounds::BoundingBox;
+use crate::vector::{Point, Point3};
+
+/// A hexagon in a grid of hexagons, in axial coordinates.
+///
+/// The third cube coordinate is `s = -q - r`. Which way the axes point on the
+/// page depends on the `Orientation` of the grid.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HexCoord {
+    pub q: isize,
+    pub r: isize,
+}
+
+/// Which way up the hexagons in a grid are.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    /// Hexagons with a corner at the top, in rows. `q` increases to the east
+    /// and `r` increases to the south east.
+    Pointy,
+
+    /// Hexagons with a flat top, in columns. `q` increases to the south east
+    /// and `r` increases to the south.
+    Flat,
+}
+
+impl HexCoord {
+    pub const ORIGIN: HexCoord = HexCoord::new(0, 0);
+
+    /// The directions to the six neighbours of a hexagon, clockwise.
+    ///
+    /// These are east, south east, south west, west, north west and north
+    /// east for pointy hexagons, or south east, south, south west, north west,
+    /// north and north east for flat hexagons.
+    pub const DIRECTIONS: [HexCoord; 6] = [
+        HexCoord::new(1, 0),
+        HexCoord::new(0, 1),
+        HexCoord::new(-1, 1),
+        HexCoord::new(-1, 0),
+        HexCoord::new(0, -1),
+        HexCoord::new(1, -1),
+    ];
+
+    pub const fn new(q: isize, r: isize) -> Self {
+        HexCoord { q, r }
+    }
+
+    pub fn s(self) -> isize {
+        -self.q - self.r
+    }
+
+    /// The hexagon with the cube coordinates `(q, r, s)`, which must sum to
+    /// zero.
+    pub fn from_cube(cube: Point3) -> Self {
+        assert_eq!(
+            cube.x() + cube.y() + cube.z(),
+            0,
+            "{:?} is not a hexagon",
+            cube
+        );
+
+        HexCoord::new(cube.x(), cube.y())
+    }
+
+    pub fn cube(self) -> Point3 {
+        Point3::new(self.q, self.r, self.s())
+    }
+
+    pub fn from_axial(p: Point) -> Self {
+        HexCoord::new(p.x(), p.y())
+    }
+
+    pub fn axial(self) -> Point {
+        Point::new(self.q, self.r)
+    }
+
+    /// The hexagon at a column and row in a grid where every odd row (for
+    /// pointy hexagons) or odd column (for flat hexagons) is shifted forwards
+    /// by half a hexagon.
+    pub fn from_offset(p: Point, orientation: Orientation) -> Self {
+        match orientation {
+            Orientation::Pointy => HexCoord::new(p.x() - (p.y() - (p.y() & 1)) / 2, p.y()),
+            Orientation::Flat => HexCoord::new(p.x(), p.y() - (p.x() - (p.x() & 1)) / 2),
+        }
+    }
+
+    /// The column and row of the hexagon. See `from_offset`.
+    pub fn offset(self, orientation: Orientation) -> Point {
+        let HexCoord { q, r } = self;
+
+        match orientation {
+            Orientation::Pointy => Point::new(q + (r - (r & 1)) / 2, r),
+            Orientation::Flat => Point::new(q, r + (q - (q & 1)) / 2),
+        }
+    }
+
+    /// The number of steps from the origin to the hexagon.
+    pub fn length(self) -> isize {
+        self.cube().manhattan() / 2
+    }
+
+    pub fn distance(self, other: Self) -> isize {
+        (self - other).length()
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = HexCoord> {
+        HexCoord::DIRECTIONS.iter().map(move |d| self + *d)
+    }
+
+    /// The hexagons `radius` steps away from `self`, clockwise.
+    pub fn ring(self, radius: isize) -> Vec<HexCoord> {
+        if radius == 0 {
+            return vec![self];
+        }
+
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut hex = self + HexCoord::DIRECTIONS[4] * radius;
+
+        for &d in &HexCoord::DIRECTIONS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex += d;
+            }
+        }
+
+        ring
+    }
+
+    /// The hexagons at most `radius` steps away from `self`, ring by ring
+    /// outwards.
+    pub fn spiral(self, radius: isize) -> Vec<HexCoord> {
+        (0..=radius).flat_map(|i| self.ring(i)).collect()
+    }
+
+    /// The hexagons along the straight line from `self` to `other`, including
+    /// both.
+    pub fn line_to(self, other: Self) -> Vec<HexCoord> {
+        let n = self.distance(other);
+
+        // Nudge the line so that it never passes exactly between two
+        // hexagons.
+        let (a, b) = (self.cube(), other.cube());
+        let lerp =
+            |i: usize, t: f64| a[i] as f64 + (b[i] - a[i]) as f64 * t + [1e-6, 2e-6, -3e-6][i];
+
+        (0..=n)
+            .map(|step| {
+                let t = if n == 0 { 0.0 } else { step as f64 / n as f64 };
+                HexCoord::round(lerp(0, t), lerp(1, t), lerp(2, t))
+            })
+            .collect()
+    }
+
+    /// The hexagon containing the fractional cube coordinates.
+    fn round(q: f64, r: f64, s: f64) -> Self {
+        let (rq, rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+
+        // Fix the coordinate that was rounded the furthest.
+        if dq > dr && dq > ds {
+            HexCoord::new((-rr - rs) as isize, rr as isize)
+        } else if dr > ds {
+            HexCoord::new(rq as isize, (-rq - rs) as isize)
+        } else {
+            HexCoord::new(rq as isize, rr as isize)
+        }
+    }
+
+    /// Rotate a sixth of a turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        HexCoord::new(-self.r, -self.s())
+    }
+
+    /// Rotate a sixth of a turn anticlockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        HexCoord::new(-self.s(), -self.q)
+    }
+
+    /// Rotate clockwise around the origin by `sixths` sixths of a turn.
+    pub fn rotate(self, sixths: isize) -> Self {
+        (0..sixths.rem_euclid(6)).fold(self, |hex, _| hex.rotate_right())
+    }
+
+    /// Reflect across the line through the origin where `q` is constant.
+    pub fn reflect_q(self) -> Self {
+        HexCoord::new(self.q, self.s())
+    }
+
+    /// Reflect across the line through the origin where `r` is constant.
+    pub fn reflect_r(self) -> Self {
+        HexCoord::new(self.s(), self.r)
+    }
+
+    /// Reflect across the line through the origin where `s` is constant.
+    pub fn reflect_s(self) -> Self {
+        HexCoord::new(self.r, self.q)
+    }
+}
+
+impl Orientation {
+    /// The names of the directions in `HexCoord::DIRECTIONS`.
+    pub fn direction_names(self) -> [&'static str; 6] {
+        match self {
+            Orientation::Pointy => ["e", "se", "sw", "w", "nw", "ne"],
+            Orientation::Flat => ["se", "s", "sw", "nw", "n", "ne"],
+        }
+    }
+
+    /// Parse a path of direction names without any separators, e.g.,
+    /// `esenee`, into the direction of each step.
+    pub fn parse_path(self, input: &str) -> Result<Vec<HexCoord>, ParseError> {
+        self.parse_path_in(input, input)
+    }
+
+    /// Parse a path on each line of `input`.
+    pub fn parse_paths(self, input: &str) -> Result<Vec<Vec<HexCoord>>, ParseError> {
+        input
+            .lines()
+            .map(|line| self.parse_path_in(input, line))
+            .collect()
+    }
+
+    /// Parse the path in `span`, which is a slice of `input`.
+    fn parse_path_in(self, input: &str, span: &str) -> Result<Vec<HexCoord>, ParseError> {
+        let names = self.direction_names();
+        let mut path = Vec::new();
+        let mut rest = span;
+
+        while !rest.is_empty() {
+            // Prefer the longest name, e.g., `se` over `s`.
+            let (name, d) = names
+                .iter()
+                .zip(HexCoord::DIRECTIONS.iter())
+                .filter(|(name, _)| rest.starts_with(*name))
+                .max_by_key(|(name, _)| name.len())
+                .ok_or_else(|| ParseError::at_span(input, rest, "expected a direction"))?;
+
+            path.push(*d);
+            rest = &rest[name.len()..];
+        }
+
+        Ok(path)
+    }
+
+    /// Where to draw a hexagon with one character per hexagon, so that
+    /// neighbouring hexagons are next to each other on the page.
+    pub fn layout(self, hex: HexCoord) -> Point {
+        match self {
+            Orientation::Pointy => Point::new(2 * hex.q + hex.r, hex.r),
+            Orientation::Flat => Point::new(hex.q, 2 * hex.r + hex.q),
+        }
+    }
+
+    /// Draw the hexagons as `cell` of each, laid out by `layout`.
+    pub fn render<I, F>(self, hexes: I, mut cell: F) -> String
+    where
+        I: IntoIterator<Item = HexCoord>,
+        F: FnMut(HexCoord) -> char,
+    {
+        let cells = hexes
+            .into_iter()
+            .map(|hex| (self.layout(hex), cell(hex)))
+            .collect::<Vec<_>>();
+
+        let bounds = match BoundingBox::from_points(cells.iter().map(|(p, _)| *p)) {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+
+        let mut lines = vec![vec![' '; bounds.width()]; bounds.height()];
+        for (p, c) in cells {
+            let p = p - bounds.min;
+            lines[p.y() as usize][p.x() as usize] = c;
+        }
+
+        lines
+            .into_iter()
+            .map(|line| {
+                let mut line = line.into_iter().collect::<String>();
+                line.truncate(line.trim_end().len());
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
+impl Add for HexCoord {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        HexCoord::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for HexCoord {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for HexCoord {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        HexCoord::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl SubAssign for HexCoord {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for HexCoord {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        HexCoord::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<isize> for HexCoord {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        HexCoord::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+/// Hexagons are ordered by `r` and then `q`, i.e., in reading order for pointy
+/// hexagons.
+impl Ord for HexCoord {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        (self.r, self.q).cmp(&(rhs.r, rhs.q))
+    }
+}
+
+impl PartialOrd for HexCoord {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_error::Location;
+
+    use super::*;
+
+    #[test]
+    fn test_coordinates() {
+        let hex = HexCoord::new(3, -1);
+
+        assert_eq!(hex.s(), -2);
+        assert_eq!(HexCoord::from_cube(hex.cube()), hex);
+        assert_eq!(HexCoord::from_axial(hex.axial()), hex);
+
+        for &orientation in &[Orientation::Pointy, Orientation::Flat] {
+            for hex in HexCoord::ORIGIN.spiral(3) {
+                assert_eq!(
+                    HexCoord::from_offset(hex.offset(orientation), orientation),
+                    hex
+                );
+            }
+        }
+
+        assert_eq!(
+            HexCoord::new(-1, 1).offset(Orientation::Pointy),
+            Point::new(-1, 1)
+        );
+        assert_eq!(
+            HexCoord::new(1, 0).offset(Orientation::Flat),
+            Point::new(1, 0)
+        );
+        assert_eq!(
+            HexCoord::new(1, 1).offset(Orientation::Flat),
+            Point::new(1, 1)
+        );
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(HexCoord::new(3, -1).length(), 3);
+        assert_eq!(HexCoord::new(1, 1).distance(HexCoord::new(-1, -1)), 4);
+
+        for &d in &HexCoord::DIRECTIONS {
+            assert_eq!(d.length(), 1);
+        }
+    }
+
+    #[test]
+    fn test_rings() {
+        let centre = HexCoord::new(2, -5);
+
+        assert_eq!(centre.ring(0), vec![centre]);
+        assert_eq!(centre.ring(1).len(), 6);
+        assert!(centre.ring(3).iter().all(|hex| hex.distance(centre) == 3));
+        assert_eq!(centre.ring(2)[0], centre + HexCoord::new(0, -2));
+
+        let spiral = centre.spiral(2);
+        assert_eq!(spiral.len(), 19);
+        assert_eq!(spiral[1], centre.ring(1)[0]);
+    }
+
+    #[test]
+    fn test_line() {
+        let a = HexCoord::new(0, 0);
+        let b = HexCoord::new(3, -1);
+        let line = a.line_to(b);
+
+        assert_eq!(line.len(), 4);
+        assert_eq!((line[0], line[3]), (a, b));
+        assert!(line.windows(2).all(|w| w[0].distance(w[1]) == 1));
+        assert_eq!(a.line_to(a), vec![a]);
+    }
+
+    #[test]
+    fn test_symmetry() {
+        let hex = HexCoord::new(2, -3);
+
+        assert_eq!(HexCoord::new(1, 0).rotate_right(), HexCoord::new(0, 1));
+        assert_eq!(hex.rotate_left().rotate_right(), hex);
+        assert_eq!(hex.rotate(6), hex);
+        assert_eq!(hex.rotate(-1), hex.rotate_left());
+        assert_eq!(hex.rotate(3), -hex);
+
+        assert_eq!(hex.reflect_q(), HexCoord::new(2, 1));
+        assert_eq!(hex.reflect_r(), HexCoord::new(1, -3));
+        assert_eq!(hex.reflect_s(), HexCoord::new(-3, 2));
+        assert_eq!(hex.reflect_s().reflect_s(), hex);
+    }
+
+    #[test]
+    fn test_parse_path() {
+        let path = Orientation::Pointy.parse_path("nwwswee").unwrap();
+        assert_eq!(
+            path.iter().fold(HexCoord::ORIGIN, |hex, d| hex + *d),
+            HexCoord::ORIGIN
+        );
+
+        let path = Orientation::Flat.parse_path("sesn").unwrap();
+        assert_eq!(
+            path,
+            vec![
+                HexCoord::new(1, 0),
+                HexCoord::new(0, 1),
+                HexCoord::new(0, -1)
+            ]
+        );
+
+        let err = Orientation::Pointy.parse_path("esx").unwrap_err();
+        assert_eq!(err.message(), "expected a direction");
+        assert_eq!(err.location(), Some(Location { line: 1, column: 2 }));
+
+        let paths = Orientation::Pointy.parse_paths("e\nnwse\n").unwrap();
+        assert_eq!(paths.len(), 2);
+
+        let err = Orientation::Pointy.parse_paths("e\nnwn\n").unwrap_err();
+        assert_eq!(err.location(), Some(Location { line: 2, column: 3 }));
+    }
+
+    #[test]
+    fn test_render() {
+        let hexes = HexCoord::ORIGIN.spiral(1);
+        let mark = |hex: HexCoord| if hex == HexCoord::ORIGIN { 'o' } else { '#' };
+
+        assert_eq!(
+            Orientation::Pointy.render(hexes.clone(), mark),
+            " # #\n# o #\n # #\n"
+        );
+        assert_eq!(
+            Orientation::Flat.render(hexes, mark),
+            " #\n# #\n o\n# #\n #\n"
+        );
+        assert_eq!(Orientation::Flat.render(vec![], mark), "");
+    }
+}
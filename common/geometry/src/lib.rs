@@ -0,0 +1,9 @@
+mod bounds;
+mod heading;
+mod hex;
+mod vector;
+
+pub use crate::bounds::BoundingBox;
+pub use crate::heading::Heading;
+pub use crate::hex::{HexCoord, Orientation};
+pub use crate::vector::{Point, Point3, Vector, ADJACENT, DOWN, LEFT, ORTHOGONAL, RIGHT, UP};
@@ -0,0 +1,277 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A vector with `N` integer coordinates.
+///
+/// The coordinates of two- and three-dimensional vectors can be read with
+/// `v.x()`, `v.y()` and `v.z()`, and any coordinate can be written as `v[axis]`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Vector<const N: usize>(pub [isize; N]);
+
+/// A position in a grid, with `y` increasing downwards.
+pub type Point = Vector<2>;
+
+pub type Point3 = Vector<3>;
+
+pub const UP: Point = Point::new(0, -1);
+pub const DOWN: Point = Point::new(0, 1);
+pub const LEFT: Point = Point::new(-1, 0);
+pub const RIGHT: Point = Point::new(1, 0);
+
+/// The directions to the four orthogonal neighbours of a point.
+pub const ORTHOGONAL: [Point; 4] = [UP, DOWN, LEFT, RIGHT];
+
+/// The directions to all eight neighbours of a point, in reading order.
+pub const ADJACENT: [Point; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+];
+
+impl<const N: usize> Vector<N> {
+    pub const ZERO: Self = Vector([0; N]);
+
+    /// The sum of the absolute values of the coordinates.
+    pub fn manhattan(self) -> isize {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    pub fn manhattan_to(self, other: Self) -> isize {
+        (self - other).manhattan()
+    }
+
+    /// The largest absolute value of any coordinate.
+    pub fn chebyshev(self) -> isize {
+        self.0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    pub fn chebyshev_to(self, other: Self) -> isize {
+        (self - other).chebyshev()
+    }
+
+    /// The vector with each coordinate replaced by its sign.
+    pub fn signum(self) -> Self {
+        self.map(isize::signum)
+    }
+
+    pub fn map<F>(mut self, f: F) -> Self
+    where
+        F: Fn(isize) -> isize,
+    {
+        for c in &mut self.0 {
+            *c = f(*c);
+        }
+
+        self
+    }
+
+    /// Combine the coordinates of two vectors pairwise.
+    pub fn zip_with<F>(mut self, other: Self, f: F) -> Self
+    where
+        F: Fn(isize, isize) -> isize,
+    {
+        for (c, d) in self.0.iter_mut().zip(&other.0) {
+            *c = f(*c, *d);
+        }
+
+        self
+    }
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vector([x, y])
+    }
+
+    pub const fn x(self) -> isize {
+        self.0[0]
+    }
+
+    pub const fn y(self) -> isize {
+        self.0[1]
+    }
+
+    /// Rotate a quarter turn anticlockwise, e.g., `RIGHT` becomes `UP`.
+    pub fn rotate_left(self) -> Self {
+        Point::new(self.y(), -self.x())
+    }
+
+    /// Rotate a quarter turn clockwise, e.g., `RIGHT` becomes `DOWN`.
+    pub fn rotate_right(self) -> Self {
+        Point::new(-self.y(), self.x())
+    }
+
+    /// Rotate clockwise by `degrees`, which must be a multiple of 90.
+    pub fn rotate(self, degrees: isize) -> Self {
+        assert_eq!(degrees % 90, 0, "cannot rotate by {} degrees", degrees);
+
+        match degrees.rem_euclid(360) {
+            0 => self,
+            90 => self.rotate_right(),
+            180 => -self,
+            _ => self.rotate_left(),
+        }
+    }
+}
+
+impl Point3 {
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Vector([x, y, z])
+    }
+
+    pub const fn x(self) -> isize {
+        self.0[0]
+    }
+
+    pub const fn y(self) -> isize {
+        self.0[1]
+    }
+
+    pub const fn z(self) -> isize {
+        self.0[2]
+    }
+}
+
+impl<const N: usize> Default for Vector<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> From<[isize; N]> for Vector<N> {
+    fn from(coords: [isize; N]) -> Self {
+        Vector(coords)
+    }
+}
+
+impl<const N: usize> Index<usize> for Vector<N> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &isize {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut isize {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Vector<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize> AddAssign for Vector<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> Sub for Vector<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize> SubAssign for Vector<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> Neg for Vector<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|c| -c)
+    }
+}
+
+impl<const N: usize> Mul<isize> for Vector<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<const N: usize> MulAssign<isize> for Vector<N> {
+    fn mul_assign(&mut self, rhs: isize) {
+        *self = *self * rhs;
+    }
+}
+
+/// Vectors are ordered by their last coordinate first, so points are in
+/// reading order, i.e., top to bottom and then left to right.
+impl<const N: usize> Ord for Vector<N> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0.iter().rev().cmp(rhs.0.iter().rev())
+    }
+}
+
+impl<const N: usize> PartialOrd for Vector<N> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_coordinates() {
+        let mut p = Point::new(3, -4);
+        p[0] += 1;
+
+        assert_eq!(p, Point::new(4, -4));
+        assert_eq!(p.y(), p[1]);
+
+        let mut q = Point3::new(1, 2, 3);
+        q[2] = -q.z();
+
+        assert_eq!(q, Vector([1, 2, -3]));
+    }
+
+    #[test]
+    fn test_distances() {
+        let p = Point3::new(1, -5, 2);
+
+        assert_eq!(p.manhattan(), 8);
+        assert_eq!(p.chebyshev(), 5);
+        assert_eq!(p.manhattan_to(Point3::new(1, 1, 1)), 7);
+        assert_eq!(p.signum(), Point3::new(1, -1, 1));
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(RIGHT.rotate_right(), DOWN);
+        assert_eq!(RIGHT.rotate_left(), UP);
+        assert_eq!(Point::new(10, -4).rotate(90), Point::new(4, 10));
+        assert_eq!(Point::new(10, -4).rotate(-90), Point::new(-4, -10));
+        assert_eq!(Point::new(10, -4).rotate(540), Point::new(-10, 4));
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut points = vec![Point::new(0, 1), Point::new(1, 0), Point::new(0, 0)];
+        points.sort();
+
+        assert_eq!(
+            points,
+            vec![Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)]
+        );
+    }
+}
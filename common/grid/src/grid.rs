@@ -0,0 +1,315 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use aoc_error::ParseError;
+use aoc_geometry::{Point, ADJACENT, ORTHOGONAL};
+
+use crate::view::View;
+
+/// A rectangular grid of cells.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid where every cell is `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid from its cells in reading order.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height);
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a grid from a map with one character per cell.
+    ///
+    /// `cell` maps each character to a cell, or to `None` if the character is
+    /// not allowed in the map. Every line of the map must be the same length.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = match input.lines().next() {
+            Some(line) => line.chars().count(),
+            None => return Err(ParseError::new("input is empty")),
+        };
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for line in input.lines() {
+            let len = line.chars().count();
+            if len != width {
+                return Err(ParseError::at_span(
+                    input,
+                    line,
+                    format!("expected line of length {}, got {}", width, len),
+                ));
+            }
+
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(ParseError::at_span(
+                            input,
+                            &line[i..],
+                            format!("unexpected `{}'", c),
+                        ))
+                    }
+                }
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        0 <= p.x() && (p.x() as usize) < self.width && 0 <= p.y() && (p.y() as usize) < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if self.in_bounds(p) {
+            Some(&self.cells[self.offset(p)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.in_bounds(p) {
+            let offset = self.offset(p);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `p` when the grid repeats forever in every direction.
+    pub fn get_wrapping(&self, p: Point) -> &T {
+        let x = p.x().rem_euclid(self.width as isize);
+        let y = p.y().rem_euclid(self.height as isize);
+
+        &self[Point::new(x, y)]
+    }
+
+    /// Every point in the grid, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+    }
+
+    /// Every point in the grid and its cell, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Every cell in the grid, in reading order.
+    pub fn cells(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// The orthogonal neighbours of `p` that are in the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .iter()
+            .map(move |d| p + *d)
+            .filter(move |q| self.in_bounds(*q))
+    }
+
+    /// The orthogonal and diagonal neighbours of `p` that are in the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT
+            .iter()
+            .map(move |d| p + *d)
+            .filter(move |q| self.in_bounds(*q))
+    }
+
+    /// The points in the grid seen looking from `from` (but not including it)
+    /// towards `direction`.
+    pub fn ray(&self, from: Point, direction: Point) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            at: from,
+            direction,
+        }
+    }
+
+    /// The `width` by `height` part of the grid with its top left corner at
+    /// `origin`.
+    pub fn view(&self, origin: Point, width: usize, height: usize) -> View<'_, T> {
+        View::new(self, origin, width, height)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Display the grid by drawing each cell as a single character.
+    pub fn display_with<'g, F>(&'g self, f: F) -> impl fmt::Display + 'g
+    where
+        F: Fn(&T) -> char + 'g,
+    {
+        self.view(Point::new(0, 0), self.width, self.height)
+            .display_with(f)
+    }
+
+    fn offset(&self, p: Point) -> usize {
+        p.y() as usize * self.width + p.x() as usize
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        assert!(self.in_bounds(p), "{:?} is not in the grid", p);
+
+        &self.cells[self.offset(p)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        assert!(self.in_bounds(p), "{:?} is not in the grid", p);
+
+        let offset = self.offset(p);
+        &mut self.cells[offset]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.view(Point::new(0, 0), self.width, self.height), f)
+    }
+}
+
+/// An iterator over the points seen looking in one direction.
+///
+/// See `Grid::ray`.
+pub struct Ray<'g, T> {
+    grid: &'g Grid<T>,
+    at: Point,
+    direction: Point,
+}
+
+impl<'g, T> Iterator for Ray<'g, T> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.at + self.direction;
+
+        if self.grid.in_bounds(next) {
+            self.at = next;
+            Some(next)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_error::Location;
+
+    use super::*;
+    use aoc_geometry::{DOWN, RIGHT};
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.#\n..#\n", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert!(grid[Point::new(2, 1)]);
+        assert!(!grid[Point::new(0, 1)]);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(
+            grid.display_with(|b| if *b { '#' } else { '.' })
+                .to_string(),
+            "#.#\n..#\n"
+        );
+
+        let err = Grid::parse("#.#\n.x#\n", |c| if c == 'x' { None } else { Some(c) }).unwrap_err();
+        assert_eq!(err.message(), "unexpected `x'");
+        assert_eq!(err.location(), Some(Location { line: 2, column: 2 }));
+
+        let err = Grid::parse("#.#\n.#\n", Some).unwrap_err();
+        assert_eq!(err.message(), "expected line of length 3, got 2");
+        assert_eq!(err.location(), Some(Location { line: 2, column: 1 }));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(0, 1)).count(), 5);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::new(4, 3, 0);
+
+        assert_eq!(
+            grid.ray(Point::new(0, 0), RIGHT + DOWN).collect::<Vec<_>>(),
+            vec![Point::new(1, 1), Point::new(2, 2)]
+        );
+        assert_eq!(grid.ray(Point::new(3, 0), RIGHT).count(), 0);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::parse("ab\ncd\n", Some).unwrap();
+
+        assert_eq!(*grid.get_wrapping(Point::new(2, 0)), 'a');
+        assert_eq!(*grid.get_wrapping(Point::new(-1, 3)), 'd');
+    }
+}
@@ -0,0 +1,7 @@
+mod grid;
+mod view;
+
+pub use aoc_geometry::{Point, ADJACENT, DOWN, LEFT, ORTHOGONAL, RIGHT, UP};
+
+pub use crate::grid::{Grid, Ray};
+pub use crate::view::View;
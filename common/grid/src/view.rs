@@ -0,0 +1,148 @@
+use std::fmt;
+use std::ops::Index;
+
+use aoc_geometry::Point;
+
+use crate::grid::Grid;
+
+/// A rectangular part of a `Grid`.
+///
+/// Points in a view are relative to its top left corner.
+pub struct View<'g, T> {
+    grid: &'g Grid<T>,
+    origin: Point,
+    width: usize,
+    height: usize,
+}
+
+impl<'g, T> View<'g, T> {
+    pub(crate) fn new(grid: &'g Grid<T>, origin: Point, width: usize, height: usize) -> Self {
+        let corner = origin + Point::new(width as isize - 1, height as isize - 1);
+        assert!(
+            width == 0 || height == 0 || (grid.in_bounds(origin) && grid.in_bounds(corner)),
+            "view does not fit in the grid"
+        );
+
+        View {
+            grid,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        0 <= p.x() && (p.x() as usize) < self.width && 0 <= p.y() && (p.y() as usize) < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&'g T> {
+        if self.in_bounds(p) {
+            self.grid.get(self.origin + p)
+        } else {
+            None
+        }
+    }
+
+    /// Every point in the view, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+    }
+
+    /// Every point in the view and its cell, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'g T)> + '_ {
+        self.points().map(move |p| (p, &self.grid[self.origin + p]))
+    }
+
+    /// Copy the view into a grid of its own.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_cells(
+            self.width,
+            self.height,
+            self.iter().map(|(_, cell)| cell.clone()).collect(),
+        )
+    }
+
+    /// Display the view by drawing each cell as a single character.
+    pub fn display_with<F>(self, f: F) -> impl fmt::Display + 'g
+    where
+        F: Fn(&T) -> char + 'g,
+    {
+        DisplayWith { view: self, f }
+    }
+
+    fn fmt_cells<F>(&self, f: &mut fmt::Formatter, mut cell: F) -> fmt::Result
+    where
+        F: FnMut(&mut fmt::Formatter, &T) -> fmt::Result,
+    {
+        for (p, value) in self.iter() {
+            cell(f, value)?;
+
+            if p.x() as usize == self.width - 1 {
+                f.write_str("\n")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'g, T> Index<Point> for View<'g, T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        assert!(self.in_bounds(p), "{:?} is not in the view", p);
+
+        &self.grid[self.origin + p]
+    }
+}
+
+impl<'g, T: fmt::Display> fmt::Display for View<'g, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_cells(f, |f, value| fmt::Display::fmt(value, f))
+    }
+}
+
+struct DisplayWith<'g, T, F> {
+    view: View<'g, T>,
+    f: F,
+}
+
+impl<'g, T, F> fmt::Display for DisplayWith<'g, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.view
+            .fmt_cells(f, |f, value| fmt::Write::write_char(f, (self.f)(value)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_view() {
+        let grid = Grid::parse("abcd\nefgh\nijkl\n", Some).unwrap();
+        let view = grid.view(Point::new(1, 1), 2, 2);
+
+        assert_eq!(view[Point::new(0, 0)], 'f');
+        assert_eq!(view.get(Point::new(2, 0)), None);
+        assert_eq!(view.to_string(), "fg\njk\n");
+        assert_eq!(view.to_grid(), Grid::parse("fg\njk\n", Some).unwrap());
+    }
+}
@@ -0,0 +1,205 @@
+//! Number theory over the integers modulo `m`.
+//!
+//! Residues are `u64`s and every operation widens to 128 bits internally, so
+//! any modulus that fits in a `u64` is safe from overflow.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// The greatest common divisor `g` of `a` and `b` along with `x` and `y` such
+/// that `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+        (g, y, x - a.div_euclid(b) * y)
+    }
+}
+
+/// `a * b (mod m)`.
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// `base ^ exp (mod m)`.
+pub fn pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut base = base % m;
+    let mut result = 1 % m;
+
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(result, base, m);
+        }
+
+        base = mul_mod(base, base, m);
+        exp /= 2;
+    }
+
+    result
+}
+
+/// The `x` such that `a * x = 1 (mod m)`, or `None` if `a` and `m` are not
+/// coprime.
+pub fn inverse_mod(a: u64, m: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(a as i128, m as i128);
+
+    if g == 1 {
+        Some(x.rem_euclid(m as i128) as u64)
+    } else {
+        None
+    }
+}
+
+/// Solve the system of congruences `x = a (mod m)` for every `(a, m)`.
+///
+/// The moduli do not have to be coprime. Returns the smallest non-negative
+/// solution and the lcm of the moduli, which all other solutions differ from
+/// it by a multiple of, or `None` if the congruences contradict each other, a
+/// modulus is zero or the lcm does not fit in a `u64`.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let mut x = 0;
+    let mut modulus = 1;
+
+    for &(a, m) in congruences {
+        if m == 0 {
+            return None;
+        }
+
+        let (g, p, _) = extended_gcd(modulus as i128, m as i128);
+        let diff = a as i128 - x as i128;
+
+        if diff % g != 0 {
+            return None;
+        }
+
+        // `x + modulus * k` solves both congruences, where
+        // `modulus * k = diff (mod m)`.
+        let step = m as i128 / g;
+        let k = mul_mod(
+            (diff / g).rem_euclid(step) as u64,
+            p.rem_euclid(step) as u64,
+            step as u64,
+        );
+
+        // Both moduli fit in a `u64`, so their product fits in a `u128`, and
+        // `k < step`, so `x + modulus * k` fits if the new modulus does.
+        let next_modulus = u64::try_from(modulus as u128 * step as u128).ok()?;
+        x = ((x as u128 + modulus as u128 * k as u128) % next_modulus as u128) as u64;
+        modulus = next_modulus;
+    }
+
+    Some((x, modulus))
+}
+
+/// The smallest `x` such that `base ^ x = target (mod m)`.
+///
+/// Uses the baby-step giant-step algorithm, so it takes about `sqrt(m)` time
+/// and space. `base` must be coprime to `m`; returns `None` if it is not, if
+/// `m` is zero or if there is no such `x`.
+pub fn discrete_log(base: u64, target: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+
+    let target = target % m;
+
+    // The number of baby steps, i.e., `ceil(sqrt(m))`.
+    let mut n = (m as f64).sqrt() as u64;
+    while n * n < m {
+        n += 1;
+    }
+
+    let mut baby_steps = HashMap::new();
+    let mut power = 1 % m;
+    for j in 0..n {
+        baby_steps.entry(power).or_insert(j);
+        power = mul_mod(power, base, m);
+    }
+
+    // Each giant step divides by `base ^ n`.
+    let giant_step = inverse_mod(power, m)?;
+    let mut gamma = target;
+    for i in 0..n {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(i * n + j);
+        }
+
+        gamma = mul_mod(gamma, giant_step, m);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mul_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+        assert_eq!(pow_mod(4, 13, 497), 445);
+        assert_eq!(pow_mod(7, 0, 1), 0);
+
+        assert_eq!(inverse_mod(3, 11), Some(4));
+        assert_eq!(inverse_mod(7, 40), Some(23));
+        assert_eq!(inverse_mod(6, 40), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 4), (0, 0)]), None);
+
+        // The lcm of consecutive moduli is their product.
+        assert_eq!(
+            crt(&[(0, 1 << 32), (0, (1 << 32) - 1)]),
+            Some((0, u64::MAX - ((1 << 32) - 1)))
+        );
+        assert_eq!(crt(&[(1, u64::MAX), (2, u64::MAX - 1)]), None);
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(2, 1, 11), Some(0));
+        assert_eq!(discrete_log(2, 0, 11), None);
+        assert_eq!(discrete_log(2, 3, 8), None);
+        assert_eq!(discrete_log(2, 3, 0), None);
+
+        for x in 0..30 {
+            let target = pow_mod(3, x, 31);
+            assert_eq!(pow_mod(3, discrete_log(3, target, 31).unwrap(), 31), target);
+        }
+    }
+}
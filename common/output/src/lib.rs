@@ -0,0 +1,209 @@
+use std::env;
+use std::fmt::Display;
+use std::process::exit;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+/// How solutions report their answers.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// Human readable output, e.g., `part 1: 1234`.
+    #[default]
+    Text,
+
+    /// One JSON object per line for each part.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format `{}'", s)),
+        }
+    }
+}
+
+impl Format {
+    /// Read `--format <text|json>` from the command line.
+    ///
+    /// This exits the process if there are any other arguments.
+    pub fn from_args() -> Self {
+        let (format, rest) = args();
+
+        if let Some(arg) = rest.first() {
+            usage(&format!("unexpected argument `{}'", arg));
+        }
+
+        format
+    }
+}
+
+/// Split the command line into the output format and the remaining arguments.
+///
+/// This exits the process if the format is invalid.
+pub fn args() -> (Format, Vec<String>) {
+    parse_args(env::args().skip(1)).unwrap_or_else(|e| usage(&e))
+}
+
+fn usage(error: &str) -> ! {
+    eprintln!("error: {}", error);
+    eprintln!("usage: [--format <text|json>]");
+    exit(2);
+}
+
+fn parse_args<I>(mut args: I) -> Result<(Format, Vec<String>), String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut format = Format::default();
+    let mut rest = vec![];
+
+    while let Some(arg) = args.next() {
+        let value = if arg == "--format" {
+            args.next()
+                .ok_or_else(|| "--format requires a value".to_string())?
+        } else if let Some(value) = arg.strip_prefix("--format=") {
+            value.into()
+        } else {
+            rest.push(arg);
+            continue;
+        };
+
+        format = value.parse()?;
+    }
+
+    Ok((format, rest))
+}
+
+/// Reports the answers of a single day.
+///
+/// Each part is timed from the previous report, or from when the `Output` was
+/// created for the first part.
+pub struct Output {
+    year: u16,
+    day: u8,
+    format: Format,
+    last: Instant,
+}
+
+impl Output {
+    /// Create an output with the format given on the command line.
+    pub fn new(year: u16, day: u8) -> Self {
+        Output::with_format(year, day, Format::from_args())
+    }
+
+    pub fn with_format(year: u16, day: u8, format: Format) -> Self {
+        Output {
+            year,
+            day,
+            format,
+            last: Instant::now(),
+        }
+    }
+
+    /// Report the answer to a part.
+    pub fn part<T: Display>(&mut self, part: u8, answer: T) {
+        self.report(part, Some(answer.to_string()), None);
+    }
+
+    /// Report a part whose answer has to be read off of a rendered image.
+    pub fn image(&mut self, part: u8, image: &str) {
+        self.report(part, None, Some(image));
+    }
+
+    fn report(&mut self, part: u8, answer: Option<String>, image: Option<&str>) {
+        let elapsed = self.last.elapsed();
+        println!("{}", self.render(part, answer, image, elapsed));
+        self.last = Instant::now();
+    }
+
+    fn render(
+        &self,
+        part: u8,
+        answer: Option<String>,
+        image: Option<&str>,
+        elapsed: Duration,
+    ) -> String {
+        match self.format {
+            Format::Text => {
+                let mut s = format!("part {}:", part);
+                if let Some(answer) = answer {
+                    s.push(' ');
+                    s.push_str(&answer);
+                }
+                if let Some(image) = image {
+                    s.push('\n');
+                    s.push_str(image.trim_end_matches('\n'));
+                }
+                s
+            }
+
+            Format::Json => json!({
+                "year": self.year,
+                "day": self.day,
+                "part": part,
+                "answer": answer,
+                "elapsed_ms": elapsed.as_secs_f64() * 1000.0,
+                "image": image,
+            })
+            .to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(parse(&[]), Ok((Format::Text, vec![])));
+        assert_eq!(parse(&["--format", "json"]), Ok((Format::Json, vec![])));
+        assert_eq!(parse(&["--format=text"]), Ok((Format::Text, vec![])));
+        assert_eq!(
+            parse(&["389125467", "--format", "json"]),
+            Ok((Format::Json, vec!["389125467".into()]))
+        );
+        assert_eq!(
+            parse(&["--format", "xml"]),
+            Err("unknown format `xml'".into())
+        );
+        assert_eq!(
+            parse(&["--format"]),
+            Err("--format requires a value".into())
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let elapsed = Duration::from_micros(1500);
+
+        let output = Output::with_format(2019, 8, Format::Text);
+        assert_eq!(
+            output.render(1, Some("1234".into()), None, elapsed),
+            "part 1: 1234"
+        );
+        assert_eq!(
+            output.render(2, None, Some("#.\n.#\n"), elapsed),
+            "part 2:\n#.\n.#"
+        );
+
+        let output = Output::with_format(2019, 8, Format::Json);
+        assert_eq!(
+            output.render(1, Some("1234".into()), None, elapsed),
+            r#"{"answer":"1234","day":8,"elapsed_ms":1.5,"image":null,"part":1,"year":2019}"#
+        );
+        assert_eq!(
+            output.render(2, None, Some("#.\n.#\n"), elapsed),
+            r##"{"answer":null,"day":8,"elapsed_ms":1.5,"image":"#.\n.#\n","part":2,"year":2019}"##
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use combine::error::StreamError;
+use combine::parser::char::{char, digit, newline, spaces};
+use combine::parser::choice::optional;
+use combine::parser::combinator::{attempt, look_ahead};
+use combine::parser::range::recognize;
+use combine::parser::repeat::{sep_by1, sep_end_by1, skip_many, skip_many1};
+use combine::parser::token::{any, eof, one_of, satisfy};
+use combine::stream::{easy, StreamErrorFor};
+use combine::{EasyParser, ParseError, Parser, RangeStream, Stream};
+
+/// Run `parser` over all of `input`, allowing trailing whitespace.
+pub fn parse_all<'a, P>(parser: P, input: &'a str) -> Result<P::Output, aoc_error::ParseError>
+where
+    P: Parser<easy::Stream<&'a str>>,
+{
+    parser
+        .skip(spaces())
+        .skip(eof())
+        .easy_parse(input)
+        .map(|(output, _)| output)
+        .map_err(|e| aoc_error::ParseError::from_combine(input, e))
+}
+
+/// Run `parser` over each line of `input`.
+pub fn parse_lines<'a, P>(
+    mut parser: P,
+    input: &'a str,
+) -> Result<Vec<P::Output>, aoc_error::ParseError>
+where
+    P: Parser<easy::Stream<&'a str>>,
+{
+    input
+        .lines()
+        .map(|line| {
+            parser
+                .by_ref()
+                .skip(eof())
+                .easy_parse(line)
+                .map(|(output, _)| output)
+                .map_err(|e| aoc_error::ParseError::from_combine(input, e))
+        })
+        .collect()
+}
+
+/// Parse each value in a list separated by `sep`, e.g., `1,-2,3`, allowing
+/// spaces around each value and trailing whitespace.
+pub fn parse_separated<T>(input: &str, sep: char) -> Result<Vec<T>, aoc_error::ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .trim_end()
+        .split(sep)
+        .map(|value| aoc_error::parse_at(input, value.trim()))
+        .collect()
+}
+
+/// Split `input` into blocks of lines separated by blank lines.
+pub fn split_blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+/// An unsigned integer.
+pub fn unsigned<'a, T, I>() -> impl Parser<I, Output = T> + 'a
+where
+    T: FromStr + 'a,
+    T::Err: Display,
+    I: RangeStream<Token = char, Range = &'a str> + 'a,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+{
+    recognize(skip_many1(digit()))
+        .and_then(|s: &str| s.parse().map_err(StreamErrorFor::<I>::message_format))
+        .expected("integer")
+}
+
+/// An integer with an optional leading `+` or `-`.
+pub fn signed<'a, T, I>() -> impl Parser<I, Output = T> + 'a
+where
+    T: FromStr + 'a,
+    T::Err: Display,
+    I: RangeStream<Token = char, Range = &'a str> + 'a,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+{
+    recognize((optional(one_of("+-".chars())), skip_many1(digit())))
+        .and_then(|s: &str| s.parse().map_err(StreamErrorFor::<I>::message_format))
+        .expected("integer")
+}
+
+/// Zero or more spaces or tabs, but not newlines.
+pub fn inline_spaces<I>() -> impl Parser<I, Output = ()>
+where
+    I: Stream<Token = char>,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+{
+    skip_many(satisfy(|c| c == ' ' || c == '\t'))
+}
+
+/// `first` and `second` separated by `sep`, which may be surrounded by spaces.
+pub fn separated_pair<I, A, B>(
+    first: A,
+    sep: char,
+    second: B,
+) -> impl Parser<I, Output = (A::Output, B::Output)>
+where
+    I: Stream<Token = char>,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+    A: Parser<I>,
+    B: Parser<I>,
+{
+    (
+        first.skip(inline_spaces()),
+        char(sep).with(inline_spaces()).with(second),
+    )
+}
+
+/// A pair of signed integers separated by a comma, e.g., `3, -4`.
+pub fn coordinates<'a, T, I>() -> impl Parser<I, Output = (T, T)> + 'a
+where
+    T: FromStr + 'a,
+    T::Err: Display,
+    I: RangeStream<Token = char, Range = &'a str> + 'a,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+{
+    separated_pair(signed(), ',', signed())
+}
+
+/// One or more lines, each parsed by `line`.
+pub fn lines<I, P>(line: P) -> impl Parser<I, Output = Vec<P::Output>>
+where
+    I: Stream<Token = char>,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+    P: Parser<I>,
+{
+    sep_end_by1(line, newline())
+}
+
+/// One or more blocks separated by blank lines.
+///
+/// Each block must consume its own trailing newline, e.g., with [`lines`].
+pub fn blocks<I, P>(block: P) -> impl Parser<I, Output = Vec<P::Output>>
+where
+    I: Stream<Token = char>,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+    P: Parser<I>,
+{
+    // A blank line at the end of the input does not start another block.
+    sep_by1(block, attempt(newline().skip(look_ahead(any()))))
+}
+
+/// A section of the form `header:\nbody`, e.g., `your ticket:` followed by a ticket.
+pub fn section<I, H, B>(header: H, body: B) -> impl Parser<I, Output = (H::Output, B::Output)>
+where
+    I: Stream<Token = char>,
+    I::Error: ParseError<I::Token, I::Range, I::Position>,
+    H: Parser<I>,
+    B: Parser<I>,
+{
+    (header.skip(char(':')).skip(newline()), body)
+}
+
+#[cfg(test)]
+mod test {
+    use combine::parser::char::string;
+    use combine::parser::range::range;
+
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(parse_all(unsigned::<u32, _>(), "1234\n"), Ok(1234));
+        assert_eq!(parse_all(signed::<i32, _>(), "-12"), Ok(-12));
+        assert_eq!(parse_all(signed::<i32, _>(), "+12"), Ok(12));
+
+        let e = parse_all(unsigned::<u8, _>(), "256").unwrap_err();
+        assert_eq!(e.message(), "number too large to fit in target type");
+
+        let e = parse_all(unsigned::<u32, _>(), "-1").unwrap_err();
+        assert_eq!(e.message(), "unexpected `-', expected integer");
+    }
+
+    #[test]
+    fn test_coordinates() {
+        assert_eq!(
+            parse_lines(coordinates::<i32, _>(), "1, 2\n-3,4\n5 ,  -6"),
+            Ok(vec![(1, 2), (-3, 4), (5, -6)])
+        );
+
+        let e = parse_lines(coordinates::<i32, _>(), "1, 2\n3; 4\n").unwrap_err();
+        let location = e.location().unwrap();
+        assert_eq!((location.line, location.column), (2, 2));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "Player 1:\n9\n2\n\nPlayer 2:\n5\n8\n\n";
+
+        let player = || {
+            section(
+                range("Player ").with(unsigned::<u32, _>()),
+                lines(unsigned::<u32, _>()),
+            )
+        };
+
+        assert_eq!(
+            parse_all(blocks(player()), input),
+            Ok(vec![(1, vec![9, 2]), (2, vec![5, 8])])
+        );
+
+        let e = parse_all(blocks(player()), "Player 1:\n9\n\nPlayer 2\n5\n").unwrap_err();
+        let location = e.location().unwrap();
+        assert_eq!((location.line, location.column), (4, 9));
+
+        let ticket = section(
+            string("your ticket"),
+            separated_pair(signed::<i32, _>(), ',', signed::<i32, _>()),
+        );
+        assert_eq!(
+            parse_all(ticket, "your ticket:\n7,1"),
+            Ok(("your ticket", (7, 1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_separated() {
+        assert_eq!(parse_separated("1,-2, 3\n", ','), Ok(vec![1, -2, 3]));
+
+        let e = parse_separated::<i32>("1,2\n,x", ',').unwrap_err();
+        let location = e.location().unwrap();
+        assert_eq!((location.line, location.column), (2, 2));
+        assert_eq!(
+            e.message(),
+            "could not parse `x': invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_split_blocks() {
+        assert_eq!(
+            split_blocks("a\nb\n\nc\n\n\nd\n").collect::<Vec<_>>(),
+            &["a\nb", "c", "d"]
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! A circular linked list of small integers, stored in a pair of arrays.
+//!
+//! Each value is also the index of its links, so finding a value in the ring
+//! takes constant time, as does inserting, removing or moving a run of values
+//! next to it. Links are `u32`s to keep very large rings small.
+
+use std::iter::FusedIterator;
+
+/// The link of a value that is not in the ring.
+const ABSENT: u32 = u32::MAX;
+
+/// A circular doubly linked list where each value in `0..capacity` appears at
+/// most once.
+#[derive(Clone, Debug)]
+pub struct Ring {
+    next: Vec<u32>,
+    prev: Vec<u32>,
+    len: usize,
+}
+
+impl Ring {
+    /// An empty ring that can hold the values in `0..capacity`.
+    ///
+    /// Panics if `capacity` does not fit in a `u32`.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity < ABSENT as usize,
+            "capacity {} is too large",
+            capacity
+        );
+
+        Ring {
+            next: vec![ABSENT; capacity],
+            prev: vec![ABSENT; capacity],
+            len: 0,
+        }
+    }
+
+    /// A ring of `values` in order, where the last value is followed by the
+    /// first.
+    ///
+    /// Panics if any value is repeated or not less than `capacity`.
+    pub fn from_values<I>(capacity: usize, values: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        let mut ring = Ring::new(capacity);
+        let mut values = values.into_iter();
+
+        if let Some(first) = values.next() {
+            ring.insert_first(first);
+
+            let mut last = first;
+            for value in values {
+                ring.insert_after(last, value);
+                last = value;
+            }
+        }
+
+        ring
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// One more than the largest value the ring can hold.
+    pub fn capacity(&self) -> usize {
+        self.next.len()
+    }
+
+    pub fn contains(&self, value: u32) -> bool {
+        self.next.get(value as usize).is_some_and(|&n| n != ABSENT)
+    }
+
+    /// The value after `value`, which must be in the ring.
+    pub fn next(&self, value: u32) -> u32 {
+        self.next[value as usize]
+    }
+
+    /// The value before `value`, which must be in the ring.
+    pub fn prev(&self, value: u32) -> u32 {
+        self.prev[value as usize]
+    }
+
+    /// The value `n` places after `value`, or before it if `n` is negative.
+    pub fn nth(&self, value: u32, n: isize) -> u32 {
+        let links = if n < 0 { &self.prev } else { &self.next };
+
+        (0..n.unsigned_abs()).fold(value, |v, _| links[v as usize])
+    }
+
+    /// Start an empty ring with `value`.
+    ///
+    /// Panics if the ring is not empty.
+    pub fn insert_first(&mut self, value: u32) {
+        assert!(self.is_empty(), "the ring is not empty");
+
+        self.next[value as usize] = value;
+        self.prev[value as usize] = value;
+        self.len = 1;
+    }
+
+    /// Insert `value` just after `at`.
+    ///
+    /// Panics if `at` is not in the ring or `value` already is.
+    pub fn insert_after(&mut self, at: u32, value: u32) {
+        assert!(self.contains(at), "{} is not in the ring", at);
+        assert!(!self.contains(value), "{} is already in the ring", value);
+
+        let next = self.next(at);
+        self.link(at, value);
+        self.link(value, next);
+        self.len += 1;
+    }
+
+    /// Remove `value` from the ring.
+    ///
+    /// Panics if `value` is not in the ring.
+    pub fn remove(&mut self, value: u32) {
+        assert!(self.contains(value), "{} is not in the ring", value);
+
+        self.link(self.prev(value), self.next(value));
+        self.next[value as usize] = ABSENT;
+        self.prev[value as usize] = ABSENT;
+        self.len -= 1;
+    }
+
+    /// Remove and return the value just after `at`.
+    pub fn remove_after(&mut self, at: u32) -> u32 {
+        let value = self.next(at);
+        self.remove(value);
+        value
+    }
+
+    /// Move the values from `first` up to and including `last` so that they
+    /// come just after `dest`, keeping their order.
+    ///
+    /// `dest` must not be one of the values being moved, and the values being
+    /// moved must not be the whole ring.
+    pub fn splice_after(&mut self, first: u32, last: u32, dest: u32) {
+        let before = self.prev(first);
+        let after = self.next(last);
+        self.link(before, after);
+
+        let next = self.next(dest);
+        self.link(dest, first);
+        self.link(last, next);
+    }
+
+    /// The values in the ring, starting at `start` and going once around.
+    pub fn iter_from(&self, start: u32) -> Iter<'_> {
+        Iter {
+            ring: self,
+            next: Some(start).filter(|&v| self.contains(v)),
+            start,
+        }
+    }
+
+    fn link(&mut self, from: u32, to: u32) {
+        self.next[from as usize] = to;
+        self.prev[to as usize] = from;
+    }
+}
+
+pub struct Iter<'a> {
+    ring: &'a Ring,
+    next: Option<u32>,
+    start: u32,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let value = self.next?;
+        self.next = Some(self.ring.next(value)).filter(|&v| v != self.start);
+        Some(value)
+    }
+}
+
+impl FusedIterator for Iter<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn values(ring: &Ring, start: u32) -> Vec<u32> {
+        ring.iter_from(start).collect()
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut ring = Ring::from_values(10, vec![0]);
+        assert_eq!(values(&ring, 0), vec![0]);
+        assert_eq!(ring.next(0), 0);
+
+        ring.insert_after(0, 4);
+        ring.insert_after(0, 2);
+        ring.insert_after(4, 7);
+        assert_eq!(values(&ring, 0), vec![0, 2, 4, 7]);
+        assert_eq!(values(&ring, 4), vec![4, 7, 0, 2]);
+        assert_eq!(ring.nth(2, 3), 0);
+        assert_eq!(ring.nth(2, -2), 7);
+        assert_eq!(ring.len(), 4);
+
+        assert_eq!(ring.remove_after(7), 0);
+        ring.remove(4);
+        assert!(!ring.contains(4));
+        assert!(!ring.contains(10));
+        assert_eq!(values(&ring, 7), vec![7, 2]);
+        assert_eq!(values(&ring, 0), vec![]);
+
+        ring.remove(2);
+        ring.remove(7);
+        assert!(ring.is_empty());
+        ring.insert_first(9);
+        assert_eq!(values(&ring, 9), vec![9]);
+    }
+
+    #[test]
+    fn test_splice() {
+        let mut ring = Ring::from_values(10, vec![3, 8, 9, 1, 2, 5, 4, 6, 7]);
+
+        ring.splice_after(8, 1, 2);
+        assert_eq!(values(&ring, 3), vec![3, 2, 8, 9, 1, 5, 4, 6, 7]);
+
+        ring.splice_after(5, 6, 7);
+        assert_eq!(values(&ring, 3), vec![3, 2, 8, 9, 1, 7, 5, 4, 6]);
+
+        ring.splice_after(2, 8, 3);
+        assert_eq!(values(&ring, 3), vec![3, 2, 8, 9, 1, 7, 5, 4, 6]);
+        assert_eq!(ring.len(), 9);
+        assert_eq!(values(&ring, 6).len(), 9);
+    }
+}
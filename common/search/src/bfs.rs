@@ -0,0 +1,114 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::iter::once;
+
+use crate::{reconstruct, Path};
+
+/// The shortest path from `start` to a state satisfying `goal`, where every
+/// step costs one.
+pub fn bfs<N, F, I, G>(start: N, neighbours: F, goal: G) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    bfs_from(once(start), neighbours, goal)
+}
+
+/// The shortest path from any of `starts` to a state satisfying `goal`.
+pub fn bfs_from<N, S, F, I, G>(starts: S, mut neighbours: F, mut goal: G) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::new();
+    let mut open = VecDeque::new();
+
+    for start in starts {
+        if parents.insert(start.clone(), None).is_none() {
+            open.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, cost)) = open.pop_front() {
+        if goal(&node) {
+            return Some(reconstruct(&parents, node, cost));
+        }
+
+        for next in neighbours(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                open.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// The fewest steps from `start` to every reachable state.
+pub fn distances<N, F, I>(start: N, mut neighbours: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut open = VecDeque::new();
+
+    distances.insert(start.clone(), 0);
+    open.push_back((start, 0));
+
+    while let Some((node, cost)) = open.pop_front() {
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), cost + 1);
+                open.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Moves on a line of `len` cells, skipping the walls.
+    fn line(len: i32, walls: &'static [i32]) -> impl FnMut(&i32) -> Vec<i32> {
+        move |&n| {
+            vec![n - 1, n + 1]
+                .into_iter()
+                .filter(|m| 0 <= *m && *m < len && !walls.contains(m))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(2, line(10, &[]), |n| *n == 5).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, vec![2, 3, 4, 5]);
+        assert_eq!(*path.goal(), 5);
+
+        assert_eq!(bfs(2, line(10, &[4]), |n| *n == 5), None);
+
+        let path = bfs_from(vec![0, 9], line(10, &[]), |n| *n == 7).unwrap();
+        assert_eq!(path.nodes, vec![9, 8, 7]);
+    }
+
+    #[test]
+    fn test_distances() {
+        let distances = distances(3, line(10, &[6]));
+
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&0], 3);
+        assert_eq!(distances[&5], 2);
+        assert!(!distances.contains_key(&7));
+    }
+}
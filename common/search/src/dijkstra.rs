@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::{reconstruct, Path};
+
+/// The cheapest path from `start` to a state satisfying `goal`.
+///
+/// `neighbours` gives each next state along with the cost of stepping to it.
+/// Costs must not be negative.
+pub fn dijkstra<N, C, F, I, G>(start: N, neighbours: F, goal: G) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+/// The cheapest path from `start` to a state satisfying `goal`, guided by
+/// `heuristic`.
+///
+/// The heuristic must never overestimate the cost of reaching a goal.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut goal: G,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut open = BinaryHeap::new();
+
+    costs.insert(start.clone(), C::default());
+    parents.insert(start.clone(), None);
+    open.push(Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    });
+
+    while let Some(Entry { cost, node, .. }) = open.pop() {
+        if costs[&node] < cost {
+            // We already found a cheaper way here.
+            continue;
+        }
+
+        if goal(&node) {
+            return Some(reconstruct(&parents, node, cost));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+
+            if costs.get(&next).is_none_or(|c| next_cost < *c) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), Some(node.clone()));
+                open.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// A state waiting to be expanded.
+///
+/// The heap pops the entry with the lowest priority first, breaking ties in
+/// favour of the state furthest along its path.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        rhs.priority
+            .cmp(&self.priority)
+            .then(self.cost.cmp(&rhs.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmp(rhs) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Walk one step for 3 or jump two steps for 4.
+    fn moves(&n: &u32) -> Vec<(u32, u32)> {
+        vec![(n + 1, 3), (n + 2, 4)]
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(0, moves, |n| *n == 5).unwrap();
+        assert_eq!(path.cost, 11);
+        assert_eq!(path.nodes.len(), 4);
+
+        assert_eq!(dijkstra(0, |_| Vec::<(u32, u32)>::new(), |n| *n == 1), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let mut expanded = 0;
+        let path = astar(
+            0,
+            |n| {
+                expanded += 1;
+                moves(n)
+            },
+            |n| (20 - n.min(&20)) * 2,
+            |n| *n == 20,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 40);
+        assert_eq!(path.nodes, (0..=20).step_by(2).collect::<Vec<_>>());
+        assert!(expanded <= 10);
+    }
+}
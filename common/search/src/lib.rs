@@ -0,0 +1,42 @@
+//! Searches over state spaces given by a neighbour function.
+//!
+//! States only need to be `Clone`, `Eq` and `Hash`; the searches keep track of
+//! which states they have seen and how they got there.
+
+mod bfs;
+mod dijkstra;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub use crate::bfs::{bfs, bfs_from, distances};
+pub use crate::dijkstra::{astar, dijkstra};
+
+/// The states visited on the way from a start to a goal, including both.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+impl<N, C> Path<N, C> {
+    /// The final state of the path.
+    pub fn goal(&self) -> &N {
+        self.nodes.last().unwrap()
+    }
+}
+
+/// Follow the parent links back from `goal` to a start state.
+fn reconstruct<N, C>(parents: &HashMap<N, Option<N>>, goal: N, cost: C) -> Path<N, C>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut nodes = vec![goal];
+
+    while let Some(Some(parent)) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+
+    nodes.reverse();
+    Path { cost, nodes }
+}
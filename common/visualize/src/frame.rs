@@ -0,0 +1,171 @@
+use std::fmt;
+
+/// A 24-bit colour.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(255, 64, 64);
+    pub const GREEN: Rgb = Rgb(64, 200, 64);
+    pub const BLUE: Rgb = Rgb(64, 128, 255);
+    pub const YELLOW: Rgb = Rgb(255, 220, 64);
+
+    /// The perceived brightness of the colour, for greyscale output.
+    pub fn luma(self) -> u8 {
+        let Rgb(r, g, b) = self;
+        ((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8
+    }
+}
+
+/// A single cell of a frame.
+///
+/// Terminals draw the glyph in the colour, while images only use the colour.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cell {
+    pub glyph: char,
+    pub colour: Rgb,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell {
+            glyph: ' ',
+            colour: Rgb::BLACK,
+        }
+    }
+}
+
+/// The dots of each digit, from top to bottom, with the leftmost dot in the
+/// highest bit.
+const DIGITS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b001, 0b001, 0b001],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+/// One step of a simulation, drawn as a grid of cells.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Frame {
+    /// Create a blank frame.
+    pub fn new(width: usize, height: usize) -> Self {
+        Frame {
+            width,
+            height,
+            cells: vec![Cell::default(); width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        assert!(x < self.width && y < self.height);
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, glyph: char, colour: Rgb) {
+        assert!(x < self.width && y < self.height);
+        self.cells[y * self.width + x] = Cell { glyph, colour };
+    }
+
+    /// Draw the digits of `n` in a 3x5 font, one cell per dot and a blank
+    /// column between digits, with its top left corner at `(x, y)`.
+    ///
+    /// Anything that falls outside of the frame is not drawn.
+    pub fn draw_number(&mut self, x: usize, y: usize, n: u64, colour: Rgb) {
+        for (i, digit) in n.to_string().bytes().enumerate() {
+            let glyph = DIGITS[(digit - b'0') as usize];
+
+            for (dy, row) in glyph.iter().enumerate() {
+                for dx in 0..3 {
+                    let (x, y) = (x + 4 * i + dx, y + dy);
+
+                    if row & (0b100 >> dx) != 0 && x < self.width && y < self.height {
+                        self.set(x, y, digit as char, colour);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The rows of the frame, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> + '_ {
+        // `chunks` panics on a zero chunk size.
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+/// The glyphs of the frame, without colour.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell.glyph)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        let mut frame = Frame::new(3, 2);
+        frame.set(0, 0, '#', Rgb::WHITE);
+        frame.set(2, 1, '>', Rgb::RED);
+
+        assert_eq!(frame.to_string(), "#  \n  >\n");
+        assert_eq!(
+            frame.get(2, 1),
+            Cell {
+                glyph: '>',
+                colour: Rgb::RED
+            }
+        );
+        assert_eq!(frame.get(1, 1), Cell::default());
+    }
+
+    #[test]
+    fn test_draw_number() {
+        // The 0 does not fit.
+        let mut frame = Frame::new(8, 6);
+        frame.draw_number(1, 1, 170, Rgb::WHITE);
+
+        assert_eq!(
+            frame.to_string(),
+            "        \n  1  777\n 11    7\n  1    7\n  1    7\n 111   7\n"
+        );
+    }
+
+    #[test]
+    fn test_luma() {
+        assert_eq!(Rgb::BLACK.luma(), 0);
+        assert_eq!(Rgb::WHITE.luma(), 255);
+        assert_eq!(Rgb(255, 0, 0).luma(), 76);
+    }
+}
@@ -0,0 +1,139 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::{Frame, Visualizer};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImageFormat {
+    /// Colour images.
+    Ppm,
+
+    /// Greyscale images.
+    Pgm,
+
+    /// Black and white images, where dark cells are black.
+    Pbm,
+}
+
+impl ImageFormat {
+    /// The format with the file extension `ext`, if there is one.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "ppm" => Some(ImageFormat::Ppm),
+            "pgm" => Some(ImageFormat::Pgm),
+            "pbm" => Some(ImageFormat::Pbm),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Pgm => "pgm",
+            ImageFormat::Pbm => "pbm",
+        }
+    }
+}
+
+/// Writes each frame to a numbered image in a directory, one pixel per cell.
+pub struct Images {
+    dir: PathBuf,
+    format: ImageFormat,
+    count: usize,
+}
+
+impl Images {
+    /// Write images into `dir`, creating it if it does not exist.
+    pub fn new<P: Into<PathBuf>>(dir: P, format: ImageFormat) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Images {
+            dir,
+            format,
+            count: 0,
+        })
+    }
+}
+
+impl Visualizer for Images {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let path = self
+            .dir
+            .join(format!("{:05}.{}", self.count, self.format.extension()));
+        self.count += 1;
+
+        let mut f = io::BufWriter::new(fs::File::create(path)?);
+        f.write_all(&encode(frame, self.format))?;
+        f.flush()
+    }
+}
+
+/// Encode a frame as a binary PPM, PGM or PBM image.
+pub fn encode(frame: &Frame, format: ImageFormat) -> Vec<u8> {
+    if format == ImageFormat::Pbm {
+        return encode_pbm(frame);
+    }
+
+    let magic = match format {
+        ImageFormat::Ppm => "P6",
+        _ => "P5",
+    };
+
+    let mut image = format!("{}\n{} {}\n255\n", magic, frame.width(), frame.height()).into_bytes();
+
+    for cell in frame.rows().flatten() {
+        match format {
+            ImageFormat::Ppm => image.extend([cell.colour.0, cell.colour.1, cell.colour.2]),
+            _ => image.push(cell.colour.luma()),
+        }
+    }
+
+    image
+}
+
+/// Each row of a PBM image is packed into bytes, most significant bit first,
+/// with a set bit for black.
+fn encode_pbm(frame: &Frame) -> Vec<u8> {
+    let mut image = format!("P4\n{} {}\n", frame.width(), frame.height()).into_bytes();
+
+    for row in frame.rows().take(frame.height()) {
+        for cells in row.chunks(8) {
+            let byte = cells
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| cell.colour.luma() < 128)
+                .fold(0, |byte, (i, _)| byte | 0x80 >> i);
+
+            image.push(byte);
+        }
+    }
+
+    image
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Rgb;
+
+    #[test]
+    fn test_encode() {
+        let mut frame = Frame::new(2, 1);
+        frame.set(1, 0, '#', Rgb::WHITE);
+
+        assert_eq!(
+            encode(&frame, ImageFormat::Ppm),
+            b"P6\n2 1\n255\n\x00\x00\x00\xff\xff\xff"
+        );
+        assert_eq!(encode(&frame, ImageFormat::Pgm), b"P5\n2 1\n255\n\x00\xff");
+        assert_eq!(encode(&frame, ImageFormat::Pbm), b"P4\n2 1\n\x80");
+
+        let frame = Frame::new(9, 2);
+        assert_eq!(
+            encode(&frame, ImageFormat::Pbm),
+            b"P4\n9 2\n\xff\x80\xff\x80"
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::io;
+use std::path::PathBuf;
+use std::process::exit;
+use std::time::Duration;
+
+mod frame;
+mod images;
+mod terminal;
+
+pub use crate::frame::{Cell, Frame, Rgb};
+pub use crate::images::{encode, ImageFormat, Images};
+pub use crate::terminal::Terminal;
+
+/// The delay between frames in the terminal if none is given.
+const DEFAULT_DELAY: Duration = Duration::from_millis(50);
+
+/// Somewhere for a simulation to send its frames.
+pub trait Visualizer {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Whether frames are being recorded at all.
+    ///
+    /// Simulations can use this to avoid drawing frames that would be thrown
+    /// away.
+    fn enabled(&self) -> bool {
+        true
+    }
+}
+
+impl dyn Visualizer + '_ {
+    /// Draw and send a frame, but only if the visualizer is enabled.
+    pub fn emit<F>(&mut self, draw: F) -> io::Result<()>
+    where
+        F: FnOnce() -> Frame,
+    {
+        if self.enabled() {
+            self.frame(&draw())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A visualizer that discards every frame.
+pub struct Disabled;
+
+impl Visualizer for Disabled {
+    fn frame(&mut self, _: &Frame) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn enabled(&self) -> bool {
+        false
+    }
+}
+
+/// A visualizer backend, as given on the command line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Backend {
+    None,
+    Terminal(Duration),
+    Images(PathBuf, ImageFormat),
+}
+
+impl Backend {
+    /// Parse `terminal`, `terminal:<delay ms>`, `ppm:<dir>`, `pgm:<dir>` or
+    /// `pbm:<dir>`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let (kind, arg) = match s.find(':') {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+
+        if let Some(format) = ImageFormat::from_extension(kind) {
+            return match arg {
+                Some(dir) if !dir.is_empty() => Ok(Backend::Images(dir.into(), format)),
+                _ => Err(format!("`{}' requires a directory", kind)),
+            };
+        }
+
+        match (kind, arg) {
+            ("none", None) => Ok(Backend::None),
+            ("terminal", None) => Ok(Backend::Terminal(DEFAULT_DELAY)),
+            ("terminal", Some(ms)) => ms
+                .parse()
+                .map(|ms| Backend::Terminal(Duration::from_millis(ms)))
+                .map_err(|_| format!("invalid delay `{}'", ms)),
+            _ => Err(format!("unknown visualizer `{}'", s)),
+        }
+    }
+
+    pub fn open(self) -> io::Result<Box<dyn Visualizer>> {
+        Ok(match self {
+            Backend::None => Box::new(Disabled),
+            Backend::Terminal(delay) => Box::new(Terminal::new(delay)),
+            Backend::Images(dir, format) => Box::new(Images::new(dir, format)?),
+        })
+    }
+}
+
+/// Open the visualizer given by `--visualize` in `args`.
+///
+/// This exits the process if the backend is invalid or cannot be opened, or if
+/// there are any other arguments.
+pub fn from_args(args: Vec<String>) -> Box<dyn Visualizer> {
+    let backend = match parse_args(args.into_iter()) {
+        Ok((backend, rest)) => match rest.first() {
+            Some(arg) => usage(&format!("unexpected argument `{}'", arg)),
+            None => backend,
+        },
+        Err(e) => usage(&e),
+    };
+
+    backend.open().unwrap_or_else(|e| {
+        eprintln!("error: could not open visualizer: {}", e);
+        exit(1);
+    })
+}
+
+fn usage(error: &str) -> ! {
+    eprintln!("error: {}", error);
+    eprintln!("usage: [--format <text|json>] [--visualize <terminal[:<ms>]|ppm:<dir>|pgm:<dir>|pbm:<dir>>]");
+    exit(2);
+}
+
+fn parse_args<I>(mut args: I) -> Result<(Backend, Vec<String>), String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut backend = Backend::None;
+    let mut rest = vec![];
+
+    while let Some(arg) = args.next() {
+        let value = if arg == "--visualize" {
+            args.next()
+                .ok_or_else(|| "--visualize requires a value".to_string())?
+        } else if let Some(value) = arg.strip_prefix("--visualize=") {
+            value.into()
+        } else {
+            rest.push(arg);
+            continue;
+        };
+
+        backend = Backend::parse(&value)?;
+    }
+
+    Ok((backend, rest))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(parse(&[]), Ok((Backend::None, vec![])));
+        assert_eq!(
+            parse(&["--visualize", "terminal"]),
+            Ok((Backend::Terminal(DEFAULT_DELAY), vec![]))
+        );
+        assert_eq!(
+            parse(&["--visualize=terminal:100", "42"]),
+            Ok((
+                Backend::Terminal(Duration::from_millis(100)),
+                vec!["42".into()]
+            ))
+        );
+        assert_eq!(
+            parse(&["--visualize", "pgm:frames/a:b"]),
+            Ok((
+                Backend::Images("frames/a:b".into(), ImageFormat::Pgm),
+                vec![]
+            ))
+        );
+        assert_eq!(
+            parse(&["--visualize", "pbm:out"]),
+            Ok((Backend::Images("out".into(), ImageFormat::Pbm), vec![]))
+        );
+        assert_eq!(
+            parse(&["--visualize", "ppm"]),
+            Err("`ppm' requires a directory".into())
+        );
+        assert_eq!(
+            parse(&["--visualize", "gif:out"]),
+            Err("unknown visualizer `gif:out'".into())
+        );
+        assert_eq!(
+            parse(&["--visualize"]),
+            Err("--visualize requires a value".into())
+        );
+    }
+
+    #[test]
+    fn test_disabled() {
+        let mut visualizer: Box<dyn Visualizer> = Box::new(Disabled);
+        visualizer
+            .emit(|| panic!("disabled visualizers do not draw"))
+            .unwrap();
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::{Frame, Rgb, Visualizer};
+
+/// Animates frames in the terminal by redrawing over the previous frame.
+///
+/// Frames are drawn on stderr so that they do not get mixed up with the
+/// answers on stdout.
+pub struct Terminal<W = io::Stderr> {
+    out: W,
+    delay: Duration,
+    started: bool,
+}
+
+impl Terminal {
+    pub fn new(delay: Duration) -> Self {
+        Terminal::with_writer(io::stderr(), delay)
+    }
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn with_writer(out: W, delay: Duration) -> Self {
+        Terminal {
+            out,
+            delay,
+            started: false,
+        }
+    }
+
+    fn render(&self, frame: &Frame) -> String {
+        let mut s = String::new();
+
+        // Clear the screen once, and then only move the cursor home so that
+        // frames are drawn over each other without flickering.
+        if !self.started {
+            s.push_str("\x1b[2J");
+        }
+        s.push_str("\x1b[H");
+
+        for row in frame.rows() {
+            let mut colour = None;
+            for cell in row {
+                if colour != Some(cell.colour) {
+                    let Rgb(r, g, b) = cell.colour;
+                    s.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                    colour = Some(cell.colour);
+                }
+                s.push(cell.glyph);
+            }
+            s.push_str("\x1b[0m\x1b[K\n");
+        }
+
+        // Clear whatever is left of a previous, taller frame.
+        s.push_str("\x1b[J");
+        s
+    }
+}
+
+impl<W: Write> Visualizer for Terminal<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let rendered = self.render(frame);
+        self.started = true;
+
+        self.out.write_all(rendered.as_bytes())?;
+        self.out.flush()?;
+
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_terminal() {
+        let mut frame = Frame::new(2, 1);
+        frame.set(0, 0, '#', Rgb::WHITE);
+        frame.set(1, 0, '#', Rgb::WHITE);
+
+        let mut terminal = Terminal::with_writer(vec![], Duration::from_millis(0));
+        terminal.frame(&frame).unwrap();
+        terminal.frame(&frame).unwrap();
+
+        let row = "\x1b[38;2;255;255;255m##\x1b[0m\x1b[K\n";
+        assert_eq!(
+            String::from_utf8(terminal.out).unwrap(),
+            format!("\x1b[2J\x1b[H{}\x1b[J\x1b[H{}\x1b[J", row, row)
+        );
+    }
+}